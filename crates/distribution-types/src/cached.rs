@@ -4,7 +4,7 @@ use anyhow::{anyhow, Result};
 
 use distribution_filename::WheelFilename;
 use pep508_rs::VerbatimUrl;
-use pypi_types::{HashDigest, ParsedDirectoryUrl};
+use pypi_types::{ArchiveInfo, DirectUrl, HashDigest, ParsedDirectoryUrl};
 use uv_normalize::PackageName;
 
 use crate::{
@@ -133,6 +133,23 @@ impl CachedDist {
         }
     }
 
+    /// Return the [`DirectUrl`] to record in the distribution's `direct_url.json`, if any.
+    ///
+    /// Archives include any hashes that were computed for them, per PEP 610.
+    pub fn direct_url(&self) -> Result<Option<DirectUrl>> {
+        let Self::Url(dist) = self else {
+            return Ok(None);
+        };
+        let Some(parsed_url) = self.parsed_url()? else {
+            return Ok(None);
+        };
+        let mut direct_url = DirectUrl::try_from(&parsed_url)?;
+        if let DirectUrl::ArchiveUrl { archive_info, .. } = &mut direct_url {
+            *archive_info = ArchiveInfo::from_hashes(&dist.hashes);
+        }
+        Ok(Some(direct_url))
+    }
+
     /// Returns the [`WheelFilename`] of the distribution.
     pub fn filename(&self) -> &WheelFilename {
         match self {
@@ -372,6 +372,11 @@ impl<'a> IndexLocations {
         self.flat_index.iter()
     }
 
+    /// Return `true` if index lookups are disabled (i.e., `--no-index` was provided).
+    pub fn no_index(&'a self) -> bool {
+        self.no_index
+    }
+
     /// Clone the index locations into a [`IndexUrls`] instance.
     pub fn index_urls(&'a self) -> IndexUrls {
         IndexUrls {
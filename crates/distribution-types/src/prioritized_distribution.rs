@@ -303,6 +303,20 @@ impl PrioritizedDist {
         }
     }
 
+    /// Return the source distribution, if it's compatible, regardless of whether a compatible
+    /// wheel is also available.
+    pub fn get_source(&self) -> Option<CompatibleDist> {
+        match &self.0.source {
+            Some((sdist, SourceDistCompatibility::Compatible(_))) => {
+                Some(CompatibleDist::SourceDist {
+                    sdist,
+                    prioritized: self,
+                })
+            }
+            _ => None,
+        }
+    }
+
     /// Return the incompatibility for the best source distribution, if any.
     pub fn incompatible_source(&self) -> Option<&IncompatibleSource> {
         self.0
@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use pypi_types::{Requirement, RequirementSource};
 use uv_normalize::{ExtraName, GroupName, PackageName};
 
-use crate::{BuiltDist, Diagnostic, Dist, Name, ResolvedDist, SourceDist};
+use crate::{BuiltDist, Diagnostic, DirectorySourceDist, Dist, Name, ResolvedDist, SourceDist};
 
 /// A set of packages pinned at specific versions.
 #[derive(Debug, Default, Clone)]
@@ -57,6 +57,34 @@ impl Resolution {
         self.packages.values().map(Requirement::from)
     }
 
+    /// Convert any editable distributions in this resolution into non-editable distributions.
+    #[must_use]
+    pub fn into_non_editable(self) -> Self {
+        let packages =
+            self.packages
+                .into_iter()
+                .map(|(name, dist)| {
+                    let dist =
+                        match dist {
+                            ResolvedDist::Installable(Dist::Source(SourceDist::Directory(
+                                dist,
+                            ))) if dist.editable => ResolvedDist::Installable(Dist::Source(
+                                SourceDist::Directory(DirectorySourceDist {
+                                    editable: false,
+                                    ..dist
+                                }),
+                            )),
+                            dist => dist,
+                        };
+                    (name, dist)
+                })
+                .collect();
+        Self {
+            packages,
+            diagnostics: self.diagnostics,
+        }
+    }
+
     /// Return the [`ResolutionDiagnostic`]s that were produced during resolution.
     pub fn diagnostics(&self) -> &[ResolutionDiagnostic] {
         &self.diagnostics
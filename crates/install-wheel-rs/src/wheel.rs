@@ -127,13 +127,25 @@ fn copy_and_hash(reader: &mut impl Read, writer: &mut impl Write) -> io::Result<
 /// Like pip, if a shebang is non-simple (too long or contains spaces), we use `/bin/sh` as the
 /// executable.
 ///
+/// If the environment is relocatable, the executable is expected to be relative to the scripts
+/// directory, and is resolved against the script's own location at runtime via `/bin/sh`.
+///
 /// See: <https://github.com/pypa/pip/blob/0ad4c94be74cc24874c6feb5bb3c2152c398a18e/src/pip/_vendor/distlib/scripts.py#L136-L165>
-fn format_shebang(executable: impl AsRef<Path>, os_name: &str) -> String {
+fn format_shebang(executable: impl AsRef<Path>, os_name: &str, relocatable: bool) -> String {
     // Convert the executable to a simplified path.
     let executable = executable.as_ref().simplified_display().to_string();
 
     // Validate the shebang.
     if os_name == "posix" {
+        // A relative shebang would be resolved against the working directory rather than the
+        // script, so always wrap it in `/bin/sh` and resolve it relative to the script itself.
+        if relocatable {
+            let executable = format!("'{}'", executable.replace('\'', r#"'"'"'"#));
+            return format!(
+                "#!/bin/sh\n'''exec' \"$(dirname -- \"$(realpath -- \"$0\")\")\"/{executable} \"$0\" \"$@\"\n' '''"
+            );
+        }
+
         // The length of the full line: the shebang, plus the leading `#` and `!`, and a trailing
         // newline.
         let shebang_length = 2 + executable.len() + 1;
@@ -264,9 +276,22 @@ fn entrypoint_path(entrypoint: &Script, layout: &Layout) -> PathBuf {
     }
 }
 
+/// Returns the executable to reference from a script's shebang.
+///
+/// In a relocatable environment on Unix, the executable is made relative to the scripts directory.
+fn shebang_executable(executable: &Path, layout: &Layout, relocatable: bool) -> PathBuf {
+    if relocatable && layout.os_name == "posix" {
+        if let Some(relative) = pathdiff::diff_paths(executable, &layout.scheme.scripts) {
+            return relative;
+        }
+    }
+    executable.to_path_buf()
+}
+
 /// Create the wrapper scripts in the bin folder of the venv for launching console scripts.
 pub(crate) fn write_script_entrypoints(
     layout: &Layout,
+    relocatable: bool,
     site_packages: &Path,
     entrypoints: &[Script],
     record: &mut Vec<RecordEntry>,
@@ -290,7 +315,11 @@ pub(crate) fn write_script_entrypoints(
         let launcher_executable = get_script_executable(&layout.sys_executable, is_gui);
         let launcher_python_script = get_script_launcher(
             entrypoint,
-            &format_shebang(&launcher_executable, &layout.os_name),
+            &format_shebang(
+                shebang_executable(&launcher_executable, layout, relocatable),
+                &layout.os_name,
+                relocatable,
+            ),
         );
 
         // If necessary, wrap the launcher script in a Windows launcher binary.
@@ -432,6 +461,7 @@ pub(crate) fn move_folder_recorded(
 /// Has to deal with both binaries files (just move) and scripts (rewrite the shebang if applicable)
 fn install_script(
     layout: &Layout,
+    relocatable: bool,
     site_packages: &Path,
     record: &mut [RecordEntry],
     file: &DirEntry,
@@ -471,9 +501,13 @@ fn install_script(
     let mut start = vec![0; placeholder_python.len()];
     script.read_exact(&mut start)?;
     let size_and_encoded_hash = if start == placeholder_python {
-        let start = format_shebang(&layout.sys_executable, &layout.os_name)
-            .as_bytes()
-            .to_vec();
+        let start = format_shebang(
+            shebang_executable(&layout.sys_executable, layout, relocatable),
+            &layout.os_name,
+            relocatable,
+        )
+        .as_bytes()
+        .to_vec();
         let mut target = File::create(&script_absolute)?;
         let size_and_encoded_hash = copy_and_hash(&mut start.chain(script), &mut target)?;
         fs::remove_file(&path)?;
@@ -521,6 +555,7 @@ fn install_script(
 #[instrument(skip_all)]
 pub(crate) fn install_data(
     layout: &Layout,
+    relocatable: bool,
     site_packages: &Path,
     data_dir: &Path,
     dist_name: &str,
@@ -564,7 +599,7 @@ pub(crate) fn install_data(
                         initialized = true;
                     }
 
-                    install_script(layout, site_packages, record, &file)?;
+                    install_script(layout, relocatable, site_packages, record, &file)?;
                 }
             }
             Some("headers") => {
@@ -853,13 +888,16 @@ mod test {
         // By default, use a simple shebang.
         let executable = Path::new("/usr/bin/python3");
         let os_name = "posix";
-        assert_eq!(format_shebang(executable, os_name), "#!/usr/bin/python3");
+        assert_eq!(
+            format_shebang(executable, os_name, false),
+            "#!/usr/bin/python3"
+        );
 
         // If the path contains spaces, we should use the `exec` trick.
         let executable = Path::new("/usr/bin/path to python3");
         let os_name = "posix";
         assert_eq!(
-            format_shebang(executable, os_name),
+            format_shebang(executable, os_name, false),
             "#!/bin/sh\n'''exec' '/usr/bin/path to python3' \"$0\" \"$@\"\n' '''"
         );
 
@@ -867,19 +905,35 @@ mod test {
         let executable = Path::new("/usr/bin/path to python3");
         let os_name = "nt";
         assert_eq!(
-            format_shebang(executable, os_name),
+            format_shebang(executable, os_name, false),
             "#!/usr/bin/path to python3"
         );
 
         // Quotes, however, are ok.
         let executable = Path::new("/usr/bin/'python3'");
         let os_name = "posix";
-        assert_eq!(format_shebang(executable, os_name), "#!/usr/bin/'python3'");
+        assert_eq!(
+            format_shebang(executable, os_name, false),
+            "#!/usr/bin/'python3'"
+        );
 
         // If the path is too long, we should not use the `exec` trick.
         let executable = Path::new("/usr/bin/path/to/a/very/long/executable/executable/executable/executable/executable/executable/executable/executable/name/python3");
         let os_name = "posix";
-        assert_eq!(format_shebang(executable, os_name), "#!/bin/sh\n'''exec' '/usr/bin/path/to/a/very/long/executable/executable/executable/executable/executable/executable/executable/executable/name/python3' \"$0\" \"$@\"\n' '''");
+        assert_eq!(format_shebang(executable, os_name, false), "#!/bin/sh\n'''exec' '/usr/bin/path/to/a/very/long/executable/executable/executable/executable/executable/executable/executable/executable/name/python3' \"$0\" \"$@\"\n' '''");
+
+        // In a relocatable environment, resolve the executable relative to the script.
+        let executable = Path::new("python");
+        let os_name = "posix";
+        assert_eq!(
+            format_shebang(executable, os_name, true),
+            "#!/bin/sh\n'''exec' \"$(dirname -- \"$(realpath -- \"$0\")\")\"/'python' \"$0\" \"$@\"\n' '''"
+        );
+
+        // Except on Windows, where the launcher is responsible for locating the interpreter.
+        let executable = Path::new("python");
+        let os_name = "nt";
+        assert_eq!(format_shebang(executable, os_name, true), "#!python");
     }
 
     #[test]
@@ -80,6 +80,18 @@ impl<K: Eq + Hash, V: Clone, H: BuildHasher + Clone> OnceMap<K, V, H> {
         futures::executor::block_on(self.wait(key))
     }
 
+    /// Return the keys of any jobs that were registered, but haven't completed yet.
+    pub fn pending(&self) -> Vec<K>
+    where
+        K: Clone,
+    {
+        self.items
+            .iter()
+            .filter(|entry| matches!(entry.value(), Value::Waiting(_)))
+            .map(|entry| entry.key().clone())
+            .collect()
+    }
+
     /// Return the result of a previous job, if any.
     pub fn get<Q: ?Sized + Hash + Eq>(&self, key: &Q) -> Option<V>
     where
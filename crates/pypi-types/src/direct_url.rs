@@ -4,6 +4,8 @@ use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+use crate::{HashAlgorithm, HashDigest};
+
 /// Metadata for a distribution that was installed via a direct URL.
 ///
 /// See: <https://packaging.python.org/en/latest/specifications/direct-url-data-structure/>
@@ -57,6 +59,25 @@ pub struct ArchiveInfo {
     pub hashes: Option<HashMap<String, String>>,
 }
 
+impl ArchiveInfo {
+    /// Construct an [`ArchiveInfo`] from the hashes computed for an archive.
+    pub fn from_hashes(hashes: &[HashDigest]) -> Self {
+        // Populate the deprecated `hash` field too, preferring SHA-256, for older consumers.
+        let hash = hashes
+            .iter()
+            .find(|digest| digest.algorithm == HashAlgorithm::Sha256)
+            .or_else(|| hashes.first())
+            .map(|digest| format!("{}={}", digest.algorithm, digest.digest));
+        let hashes = (!hashes.is_empty()).then(|| {
+            hashes
+                .iter()
+                .map(|digest| (digest.algorithm.to_string(), digest.digest.to_string()))
+                .collect()
+        });
+        Self { hash, hashes }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct VcsInfo {
@@ -435,8 +435,9 @@ impl SourceBuild {
                 uv_virtualenv::Prompt::None,
                 false,
                 false,
+                false,
             )?,
-            BuildIsolation::Shared(venv) => venv.clone(),
+            BuildIsolation::Shared(venv) | BuildIsolation::SharedPackage(venv, _) => venv.clone(),
         };
 
         // Setup the build environment. If build isolation is disabled, we assume the build
@@ -526,6 +527,28 @@ impl SourceBuild {
         })
     }
 
+    /// Resolve the build requirements of the source tree at the given path, without installing
+    /// them.
+    ///
+    /// Used to surface unavailable build dependencies before any builds are started.
+    pub async fn resolve_build_requirements(
+        source_tree: &Path,
+        setup_py: SetupPyStrategy,
+        build_context: &impl BuildContext,
+    ) -> Result<Resolution, Error> {
+        let default_backend: Pep517Backend = DEFAULT_BACKEND.clone();
+        let (pep517_backend, _) =
+            Self::extract_pep517_backend(source_tree, setup_py, &default_backend)
+                .map_err(|err| *err)?;
+        Self::get_resolved_requirements(
+            build_context,
+            SourceBuildContext::default(),
+            &default_backend,
+            pep517_backend.as_ref(),
+        )
+        .await
+    }
+
     async fn get_resolved_requirements(
         build_context: &impl BuildContext,
         source_build_context: SourceBuildContext,
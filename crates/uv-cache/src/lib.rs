@@ -19,7 +19,7 @@ use uv_normalize::PackageName;
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
-use crate::removal::{rm_rf, Removal};
+pub use crate::removal::{rm_rf, Removal};
 pub use crate::timestamp::Timestamp;
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
@@ -635,6 +635,11 @@ pub enum CacheBucket {
     Archive,
     /// Ephemeral virtual environments used to execute PEP 517 builds and other operations.
     Environments,
+    /// Remote requirements files (e.g., constraints passed via `-c https://...`), which are reused
+    /// for a short period before being re-downloaded.
+    ///
+    /// Cache structure: `requirements-v0/<digest(url)>.txt`
+    Requirements,
 }
 
 impl CacheBucket {
@@ -648,6 +653,7 @@ impl CacheBucket {
             Self::Wheels => "wheels-v1",
             Self::Archive => "archive-v0",
             Self::Environments => "environments-v0",
+            Self::Requirements => "requirements-v0",
         }
     }
 
@@ -761,6 +767,9 @@ impl CacheBucket {
             Self::Environments => {
                 // Nothing to do.
             }
+            Self::Requirements => {
+                // Nothing to do.
+            }
         }
         Ok(summary)
     }
@@ -776,6 +785,7 @@ impl CacheBucket {
             Self::Simple,
             Self::Archive,
             Self::Environments,
+            Self::Requirements,
         ]
         .iter()
         .copied()
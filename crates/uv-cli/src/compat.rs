@@ -321,6 +321,9 @@ pub struct VenvCompatArgs {
 
     #[clap(long, hide = true)]
     no_wheel: bool,
+
+    #[clap(long, hide = true, conflicts_with = "seed")]
+    without_pip: bool,
 }
 
 impl CompatArgs for VenvCompatArgs {
@@ -356,6 +359,10 @@ impl CompatArgs for VenvCompatArgs {
             warn_user!("virtualenv's `--no-wheel` has no effect (uv omits `wheel` by default).");
         }
 
+        if self.without_pip {
+            warn_user!("venv's `--without-pip` has no effect (uv omits `pip` by default).");
+        }
+
         Ok(())
     }
 }
@@ -371,6 +378,12 @@ pub struct PipInstallCompatArgs {
 
     #[clap(long, hide = false)]
     user: bool,
+
+    #[clap(long, hide = true)]
+    src: Option<String>,
+
+    #[clap(long, hide = true)]
+    no_warn_script_location: bool,
 }
 
 impl CompatArgs for PipInstallCompatArgs {
@@ -390,6 +403,18 @@ impl CompatArgs for PipInstallCompatArgs {
             ));
         }
 
+        if self.src.is_some() {
+            warn_user!(
+                "pip's `--src` has no effect (uv only supports editable installs from local directories, which are used in-place)."
+            );
+        }
+
+        if self.no_warn_script_location {
+            warn_user!(
+                "pip's `--no-warn-script-location` has no effect (uv doesn't warn when scripts are installed outside the `PATH`)."
+            );
+        }
+
         Ok(())
     }
 }
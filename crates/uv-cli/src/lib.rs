@@ -7,9 +7,11 @@ use anyhow::{anyhow, Result};
 use clap::{Args, Parser, Subcommand};
 
 use distribution_types::{FlatIndexLocation, IndexUrl};
+use pep508_rs::MarkerTree;
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ConfigSettingEntry, IndexStrategy, KeyringProviderType, PackageNameSpecifier, TargetTriple,
+    ConfigSettingEntry, EntrypointLinkMode, IndexStrategy, KeyringProviderType,
+    PackageNameSpecifier, TargetTriple,
 };
 use uv_normalize::{ExtraName, PackageName};
 use uv_resolver::{AnnotationStyle, ExcludeNewer, PreReleaseMode, ResolutionMode};
@@ -27,6 +29,52 @@ pub enum VersionFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DiffFormat {
+    /// Display the changes as a unified diff.
+    #[default]
+    Diff,
+    /// Display the full contents of the modified file.
+    Full,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum GraphFormat {
+    /// Display the graph as an indented, human-readable tree.
+    #[default]
+    Text,
+    /// Display the graph in the Graphviz DOT format.
+    Dot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum License {
+    /// The MIT License.
+    #[value(name = "MIT")]
+    Mit,
+    /// The Apache License, Version 2.0.
+    #[value(name = "Apache-2.0")]
+    Apache2,
+    /// The GNU General Public License, Version 3 only (also accepted as the deprecated `GPL-3.0`).
+    #[value(name = "GPL-3.0-only", alias = "GPL-3.0")]
+    Gpl3,
+    /// The BSD 2-Clause License.
+    #[value(name = "BSD-2-Clause")]
+    Bsd2Clause,
+}
+
+impl License {
+    /// Returns the SPDX identifier for the license.
+    pub fn spdx_id(self) -> &'static str {
+        match self {
+            Self::Mit => "MIT",
+            Self::Apache2 => "Apache-2.0",
+            Self::Gpl3 => "GPL-3.0-only",
+            Self::Bsd2Clause => "BSD-2-Clause",
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -39,6 +87,25 @@ pub enum ListFormat {
     Json,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum LockAnnotationStyle {
+    /// Render the annotations on a single, comma-separated line.
+    Line,
+    /// Render each annotation on its own line.
+    Split,
+    /// Remove the annotations from the lockfile (equivalent to `--no-annotate`).
+    None,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DryRunFormat {
+    /// Display the installation plan in a human-readable format.
+    #[default]
+    Text,
+    /// Display the installation plan as a machine-readable JSON array of operations.
+    Json,
+}
+
 fn extra_name_with_clap_error(arg: &str) -> Result<ExtraName> {
     ExtraName::from_str(arg).map_err(|_err| {
         anyhow!(
@@ -63,6 +130,10 @@ pub struct Cli {
     pub cache_args: CacheArgs,
 
     /// The path to a `uv.toml` file to use for configuration.
+    ///
+    /// When omitted, configuration is discovered and merged in the following order of precedence:
+    /// the project's `uv.toml` (or `pyproject.toml`), the project's `.uvrc`, the user-level
+    /// `uv.toml`, and a `.uvrc` in the home directory.
     #[arg(global = true, long, env = "UV_CONFIG_FILE")]
     pub config_file: Option<PathBuf>,
 }
@@ -119,7 +190,7 @@ pub struct GlobalArgs {
     pub no_offline: bool,
 
     /// Whether to use system or uv-managed Python toolchains.
-    #[arg(global = true, long)]
+    #[arg(global = true, long, alias = "python-preference")]
     pub toolchain_preference: Option<ToolchainPreference>,
 
     /// Whether to enable experimental, preview features.
@@ -173,6 +244,9 @@ pub enum Commands {
     /// Manage Python projects.
     #[command(flatten)]
     Project(ProjectCommand),
+    /// Manage Python workspaces.
+    #[command(hide = true)]
+    Workspace(WorkspaceNamespace),
     /// Create a virtual environment.
     #[command(alias = "virtualenv", alias = "v")]
     Venv(VenvArgs),
@@ -264,6 +338,9 @@ pub enum PipCommand {
 
 #[derive(Subcommand)]
 pub enum ProjectCommand {
+    /// Create a new project.
+    #[clap(hide = true)]
+    Init(InitArgs),
     /// Run a command in the project environment.
     #[clap(hide = true)]
     Run(RunArgs),
@@ -328,6 +405,42 @@ fn parse_file_path(input: &str) -> Result<PathBuf, String> {
     }
 }
 
+/// Parse a wheel platform tag (e.g., `manylinux2014_x86_64`) into a [`TargetTriple`].
+fn parse_platform_tag(input: &str) -> Result<TargetTriple, String> {
+    TargetTriple::from_platform_tag(input)
+        .ok_or_else(|| format!("unsupported platform tag: `{input}`"))
+}
+
+/// Parse a package name to build without isolation in `uv add`, which records each package in
+/// `tool.uv.no-build-isolation-package` and so can't represent `:all:` or `:none:`.
+fn parse_no_build_isolation_package(input: &str) -> Result<PackageName, String> {
+    match input {
+        ":all:" | ":none:" => Err(format!(
+            "`{input}` is not supported by `uv add`; provide the packages to build without isolation by name"
+        )),
+        _ => PackageName::from_str(input).map_err(|err| err.to_string()),
+    }
+}
+
+/// Parse a `KEY=VALUE` environment variable assignment.
+fn parse_env_var(input: &str) -> Result<(String, String), String> {
+    match input.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("expected `KEY=VALUE`, found: `{input}`")),
+    }
+}
+
+/// Parse a SOCKS5 proxy URL (e.g., `socks5://localhost:1080`).
+fn parse_socks_proxy(input: &str) -> Result<url::Url, String> {
+    let url = url::Url::from_str(input).map_err(|err| err.to_string())?;
+    match url.scheme() {
+        "socks5" | "socks5h" => Ok(url),
+        scheme => Err(format!(
+            "expected a `socks5://` or `socks5h://` URL, found scheme: `{scheme}`"
+        )),
+    }
+}
+
 /// Parse a string into a [`PathBuf`], mapping the empty string to `None`.
 fn parse_maybe_file_path(input: &str) -> Result<Maybe<PathBuf>, String> {
     if input.is_empty() {
@@ -487,10 +600,17 @@ pub struct PipCompileArgs {
 
     /// Use legacy `setuptools` behavior when building source distributions without a
     /// `pyproject.toml`.
-    #[arg(long, overrides_with("no_legacy_setup_py"))]
+    ///
+    /// Equivalent to pip's `--no-use-pep517`, which is accepted as an alias.
+    #[arg(long, alias = "no-use-pep517", overrides_with("no_legacy_setup_py"))]
     pub legacy_setup_py: bool,
 
-    #[arg(long, overrides_with("legacy_setup_py"), hide = true)]
+    #[arg(
+        long,
+        alias = "use-pep517",
+        overrides_with("legacy_setup_py"),
+        hide = true
+    )]
     pub no_legacy_setup_py: bool,
 
     /// Disable isolation when building source distributions.
@@ -757,10 +877,17 @@ pub struct PipSyncArgs {
 
     /// Use legacy `setuptools` behavior when building source distributions without a
     /// `pyproject.toml`.
-    #[arg(long, overrides_with("no_legacy_setup_py"))]
+    ///
+    /// Equivalent to pip's `--no-use-pep517`, which is accepted as an alias.
+    #[arg(long, alias = "no-use-pep517", overrides_with("no_legacy_setup_py"))]
     pub legacy_setup_py: bool,
 
-    #[arg(long, overrides_with("legacy_setup_py"), hide = true)]
+    #[arg(
+        long,
+        alias = "use-pep517",
+        overrides_with("legacy_setup_py"),
+        hide = true
+    )]
     pub no_legacy_setup_py: bool,
 
     /// Disable isolation when building source distributions.
@@ -892,6 +1019,22 @@ pub struct PipInstallArgs {
     #[arg(long, short, env = "UV_CONSTRAINT", value_delimiter = ' ', value_parser = parse_maybe_file_path)]
     pub constraint: Vec<Maybe<PathBuf>>,
 
+    /// Constrain the version of a package, e.g., `--constraint-package "anyio<4"`; may be
+    /// provided more than once.
+    ///
+    /// Each value is treated as if it were a line in a constraints file passed via `--constraint`,
+    /// and is combined with any file-based constraints.
+    #[arg(long, value_name = "REQUIREMENT")]
+    pub constraint_package: Vec<String>,
+
+    /// Constrain versions using a named group of constraints from the `tool.uv.constraint-groups`
+    /// table in `pyproject.toml`; may be provided more than once.
+    ///
+    /// The group's constraints are combined with any constraints provided via `--constraint` or
+    /// `--constraint-package`.
+    #[arg(long, value_name = "GROUP")]
+    pub constraint_group: Vec<String>,
+
     /// Override versions using the given requirements files.
     ///
     /// Overrides files are `requirements.txt`-like files that force a specific version of a
@@ -904,13 +1047,51 @@ pub struct PipInstallArgs {
     #[arg(long, value_parser = parse_file_path)]
     pub r#override: Vec<PathBuf>,
 
+    /// Exclude a package from the resolution, as if no package depended on it; may be provided
+    /// more than once.
+    ///
+    /// Any requirement on the package, whether direct or transitive, is ignored, and the package
+    /// is not installed. Useful for skipping a broken or unwanted transitive dependency without
+    /// modifying the project's requirements.
+    #[arg(long, value_name = "PACKAGE")]
+    pub exclude: Vec<PackageName>,
+
+    /// Discard the extras from the requirements, installing only the base packages.
+    ///
+    /// For example, `flask[async]` is treated as `flask`. Extras requested by the dependencies of
+    /// the requirements are unaffected.
+    #[arg(long)]
+    pub strip_extras: bool,
+
+    /// Only install the requirements if the given marker expression evaluates to true for the
+    /// target environment, e.g., `--marker "sys_platform == 'linux'"`.
+    ///
+    /// The marker is combined with any markers on the requirements themselves. Constraints and
+    /// overrides are unaffected.
+    #[arg(long, value_name = "MARKER")]
+    pub marker: Option<MarkerTree>,
+
+    /// Override a marker variable when evaluating markers during resolution, as `KEY=VALUE` (e.g.,
+    /// `--marker-env sys_platform=linux`); may be provided more than once.
+    ///
+    /// Only marker evaluation is affected: wheels are still selected for the target interpreter
+    /// and platform, and the `Requires-Python` of each package is still checked against the
+    /// target Python version.
+    ///
+    /// Requires `--dry-run`, as the resolution may not be installable into the target
+    /// environment.
+    #[arg(long, value_name = "KEY=VALUE", value_parser = parse_env_var, requires = "dry_run")]
+    pub marker_env: Vec<(String, String)>,
+
     /// Include optional dependencies from the extra group name; may be provided more than once.
-    /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
+    /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources, and to local
+    /// project directories (e.g., `.`).
     #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
     pub extra: Option<Vec<ExtraName>>,
 
     /// Include all optional dependencies.
-    /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
+    /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources, and to local
+    /// project directories (e.g., `.`).
     #[arg(long, conflicts_with = "extra", overrides_with = "no_all_extras")]
     pub all_extras: bool,
 
@@ -1022,10 +1203,17 @@ pub struct PipInstallArgs {
 
     /// Use legacy `setuptools` behavior when building source distributions without a
     /// `pyproject.toml`.
-    #[arg(long, overrides_with("no_legacy_setup_py"))]
+    ///
+    /// Equivalent to pip's `--no-use-pep517`, which is accepted as an alias.
+    #[arg(long, alias = "no-use-pep517", overrides_with("no_legacy_setup_py"))]
     pub legacy_setup_py: bool,
 
-    #[arg(long, overrides_with("legacy_setup_py"), hide = true)]
+    #[arg(
+        long,
+        alias = "use-pep517",
+        overrides_with("legacy_setup_py"),
+        hide = true
+    )]
     pub no_legacy_setup_py: bool,
 
     /// Disable isolation when building source distributions.
@@ -1087,6 +1275,21 @@ pub struct PipInstallArgs {
     #[arg(long, conflicts_with = "no_build")]
     pub only_binary: Option<Vec<PackageNameSpecifier>>,
 
+    /// Prefer older versions with pre-built wheels over newer versions that would need to be built
+    /// from source.
+    ///
+    /// Source distributions are still used for packages that don't publish a compatible wheel for
+    /// any version in the allowed range.
+    #[arg(long, conflicts_with = "no_build")]
+    pub prefer_binary: bool,
+
+    /// Prefer source distributions over pre-built wheels, building from source when both are
+    /// available for the selected version.
+    ///
+    /// Wheels are still used for packages that don't publish a compatible source distribution.
+    #[arg(long, conflicts_with_all = ["no_build", "only_binary", "prefer_binary"])]
+    pub prefer_sdist: bool,
+
     /// The minimum Python version that should be supported by the requirements (e.g.,
     /// `3.7` or `3.7.9`).
     ///
@@ -1109,6 +1312,14 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
 
+    /// The platform for which requirements should be installed, as a wheel platform tag (e.g.,
+    /// `manylinux2014_x86_64`, `macosx_11_0_arm64`, or `win_amd64`).
+    ///
+    /// Equivalent to `--python-platform` with the corresponding target triple, and provided for
+    /// compatibility with `pip install --platform`.
+    #[arg(long, value_parser = parse_platform_tag, conflicts_with = "python_platform")]
+    pub platform: Option<TargetTriple>,
+
     /// Validate the virtual environment after completing the installation, to detect packages with
     /// missing dependencies or other issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -1122,6 +1333,175 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// The format in which to print the plan of a dry run.
+    ///
+    /// With `json`, the plan is written to stdout as an array of operations, each with an `op`
+    /// (`install`, `upgrade`, or `remove`), the package `name`, the `from_version` and
+    /// `to_version`, and the `wheel_url` of the distribution to download, if any.
+    #[arg(long, value_enum, default_value_t = DryRunFormat::default(), requires = "dry_run")]
+    pub format: DryRunFormat,
+
+    /// Write a log of the installation to the given file, in JSON Lines format.
+    ///
+    /// Each line records a single installed, upgraded, downgraded, reinstalled, or removed
+    /// distribution. If the file already exists, new records are appended to it.
+    #[arg(long)]
+    pub log: Option<PathBuf>,
+
+    /// Write the SHA-256 hash of every file installed by the resolved packages to the given file.
+    ///
+    /// The paths are relative to the `site-packages` directory, as in each package's `RECORD`.
+    /// If the file has a `.csv` extension, it's written as CSV, with `path` and `sha256` columns;
+    /// otherwise, it's written as a JSON object mapping each path to its hash.
+    #[arg(long, value_name = "FILE", conflicts_with = "dry_run")]
+    pub generate_checksums: Option<PathBuf>,
+
+    /// Resolve the build dependencies of any local source trees before installing, to fail early
+    /// if any are unavailable.
+    ///
+    /// Build dependencies are resolved but not installed; if resolution fails, no packages are
+    /// built or installed.
+    ///
+    /// Only local source trees (e.g., `-e .`) that are built in isolation are checked. Source
+    /// distributions from a registry, URL, Git repository, or local archive are not checked, since
+    /// their build dependencies can't be read without fetching and unpacking them.
+    #[arg(long)]
+    pub check_build_dependencies: bool,
+
+    /// Ignore any packages that are already installed in the environment when resolving.
+    ///
+    /// By default, uv prefers the versions of any packages that are already installed, such as
+    /// those pre-installed in the system Python of a base image. With this flag, the resolution
+    /// is computed as if the environment were empty, such that it depends only on the given
+    /// requirements. Installed packages are still reused if they match the resolution.
+    #[arg(long)]
+    pub isolated_resolution: bool,
+
+    /// Install packages even if their requirements conflict (unsafe).
+    ///
+    /// When the requirements can't be satisfied, any packages with conflicting requirements are
+    /// resolved to the newest available version, and a warning is shown for each. Every
+    /// requirement on such a package is discarded, including those given directly and any
+    /// constraints (e.g., from `--constraint`), not only the conflicting ones. The resulting
+    /// environment may be broken.
+    #[arg(long)]
+    pub ignore_conflicts: bool,
+
+    /// Suppress warnings about conflicting or missing dependencies.
+    ///
+    /// Applies to the warnings shown for packages whose requirements are ignored with
+    /// `--ignore-conflicts`, and to the environment validation performed with `--strict`. The
+    /// warnings are still emitted to the log at the `WARN` level.
+    #[arg(long)]
+    pub no_warn_conflicts: bool,
+
+    /// After installing, report the packages that were added, removed, upgraded, or downgraded,
+    /// as compared to the environment prior to the installation.
+    #[arg(long, conflicts_with = "dry_run")]
+    pub report_diff: bool,
+
+    /// After installing, also list the packages that were already installed at the required
+    /// version, and so were left unchanged.
+    #[arg(long, conflicts_with = "dry_run")]
+    pub report_unchanged: bool,
+
+    /// After installing, list the packages that were already installed at the required version,
+    /// in the style of pip (e.g., `Requirement already satisfied: idna==3.6`).
+    #[arg(long, conflicts_with_all = ["dry_run", "report_unchanged"])]
+    pub report_skipped: bool,
+
+    /// Skip any requirements that are locked in the project's `uv.lock` and already installed at
+    /// the locked version.
+    ///
+    /// Skipped requirements are neither resolved, downloaded, nor verified. Requirements on
+    /// packages that are absent from the lockfile, or locked at a version that doesn't satisfy the
+    /// requirement, are installed as usual.
+    #[arg(long)]
+    pub skip_locked: bool,
+
+    /// Keep the installed packages at their installed versions, and only install the packages that
+    /// are missing.
+    ///
+    /// Packages that were installed from a registry are pinned to their installed versions, such
+    /// that requirements that can't be satisfied without changing an installed package fail to
+    /// resolve. Packages that were installed from a URL or a local path, along with any packages
+    /// passed to `--upgrade-package`, are not pinned.
+    #[arg(long, conflicts_with = "upgrade")]
+    pub keep_installed: bool,
+
+    /// Reinstall the dependencies of the requested packages, but not the requested packages
+    /// themselves.
+    ///
+    /// The requested packages are left at their installed versions, if they satisfy the
+    /// requirements. Useful for rebuilding native extensions after a system upgrade changes the
+    /// shared libraries they link against.
+    #[arg(long, conflicts_with = "reinstall")]
+    pub force_reinstall_deps: bool,
+
+    /// The number of times to retry a request after a transient failure, such as a network timeout
+    /// or a server error.
+    ///
+    /// Retries are performed with exponential backoff.
+    #[arg(long, default_value_t = 3)]
+    pub retries: u32,
+
+    /// The base interval between retries, in milliseconds.
+    ///
+    /// The interval doubles after each failed attempt.
+    #[arg(long, value_name = "MS")]
+    pub retry_delay: Option<u64>,
+
+    /// The number of times to retry a request that fails with one of the `--retry-status-codes`,
+    /// separately from the `--retries` for requests that fail without a response (e.g., due to a
+    /// connection error).
+    ///
+    /// Defaults to the value of `--retries`.
+    #[arg(long)]
+    pub max_retries: Option<u32>,
+
+    /// The HTTP status codes for which a request is retried, as a comma-separated list.
+    ///
+    /// By default, requests are retried on any server error, along with `408` and `429`. If either
+    /// `--retry-status-codes` or `--max-retries` is provided, this defaults to
+    /// `429,500,502,503,504`.
+    #[arg(long, value_delimiter = ',', value_parser = clap::value_parser!(u16).range(100..600))]
+    pub retry_status_codes: Option<Vec<u16>>,
+
+    /// The maximum time to wait when establishing a connection, in milliseconds.
+    ///
+    /// By default, establishing a connection is only bounded by the `--read-timeout`.
+    #[arg(long, value_name = "MS")]
+    pub connect_timeout: Option<u64>,
+
+    /// The maximum time to wait between bytes received from the server, in milliseconds.
+    ///
+    /// Defaults to the value of `UV_HTTP_TIMEOUT` (in seconds), or 30 seconds.
+    #[arg(long, value_name = "MS")]
+    pub read_timeout: Option<u64>,
+
+    /// Route all network requests through the given SOCKS5 proxy, e.g.,
+    /// `socks5://localhost:1080`.
+    ///
+    /// Use the `socks5h://` scheme to resolve hostnames through the proxy, rather than locally.
+    #[arg(long, value_name = "URL", value_parser = parse_socks_proxy)]
+    pub socks_proxy: Option<url::Url>,
+
+    /// The path to a PEM file containing additional CA certificates to trust, e.g., a corporate
+    /// certificate authority.
+    ///
+    /// The certificates are trusted in addition to the default root certificates. If the file
+    /// can't be read, or doesn't contain any certificates, the installation fails.
+    #[arg(long, env = "UV_SSL_CA_BUNDLE", value_name = "PATH", value_parser = parse_file_path)]
+    pub ssl_ca_bundle: Option<PathBuf>,
+
+    /// The maximum time to spend resolving dependencies, in seconds.
+    ///
+    /// If the resolution doesn't complete in time, the installation is aborted with an error
+    /// before any changes are made to the environment. Useful for preventing CI jobs from hanging
+    /// on pathological dependency graphs.
+    #[arg(long, value_name = "SECS")]
+    pub resolver_timeout: Option<u64>,
+
     #[command(flatten)]
     pub compat_args: compat::PipInstallCompatArgs,
 }
@@ -1206,6 +1586,14 @@ pub struct PipUninstallArgs {
     #[arg(long, conflicts_with = "target")]
     pub prefix: Option<PathBuf>,
 
+    /// Uninstall packages without prompting for confirmation.
+    ///
+    /// By default, `uv` prompts for confirmation before removing each package when running in an
+    /// interactive terminal. When the output is not a terminal, packages are removed without
+    /// prompting.
+    #[arg(long, short)]
+    pub yes: bool,
+
     #[command(flatten)]
     pub compat_args: compat::PipGlobalCompatArgs,
 }
@@ -1380,6 +1768,10 @@ pub struct PipShowArgs {
     #[arg(long, overrides_with("strict"), hide = true)]
     pub no_strict: bool,
 
+    /// Display the package information as a JSON array, with one object per package.
+    #[arg(long)]
+    pub json: bool,
+
     /// The Python interpreter for which packages should be listed.
     ///
     /// By default, `uv` lists packages in the currently activated virtual environment, or a virtual
@@ -1539,6 +1931,28 @@ pub struct VenvArgs {
     #[clap(long)]
     pub allow_existing: bool,
 
+    /// Make the virtual environment relocatable.
+    ///
+    /// A relocatable virtual environment can be moved to a different directory. The `activate`
+    /// (Bash and Zsh), `activate.fish`, and `activate.bat` scripts determine the location of the
+    /// environment from their own path, rather than from the path at which it was created. On
+    /// Unix, scripts installed into the environment locate its interpreter relative to their own
+    /// path, too.
+    ///
+    /// The environment remains linked to the same base Python interpreter, which must be present
+    /// at the same path after the environment is moved.
+    #[arg(long)]
+    pub relocatable: bool,
+
+    /// Print the path to the Python interpreter that would be used for the virtual environment,
+    /// without creating the environment.
+    ///
+    /// The interpreter is discovered in the same way as when creating the environment, respecting
+    /// `--python` and the toolchain preference, and may be downloaded if no installed interpreter
+    /// satisfies the request.
+    #[arg(long, conflicts_with = "seed")]
+    pub python_path: bool,
+
     /// The path to the virtual environment to create.
     #[arg(default_value = ".venv")]
     pub name: PathBuf,
@@ -1631,6 +2045,74 @@ impl ExternalCommand {
     }
 }
 
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct InitArgs {
+    /// The path to use for the project.
+    ///
+    /// Defaults to the current working directory.
+    pub path: Option<String>,
+
+    /// The name of the project.
+    ///
+    /// Defaults to the name of the directory.
+    #[arg(long)]
+    pub name: Option<PackageName>,
+
+    /// Create a `.gitignore` file in the project.
+    #[arg(long, overrides_with("no_gitignore"), hide = true)]
+    pub gitignore: bool,
+
+    /// Do not create a `.gitignore` file in the project.
+    #[arg(long, overrides_with("gitignore"))]
+    pub no_gitignore: bool,
+
+    /// The license to use for the project, as an SPDX identifier (e.g., `MIT`).
+    ///
+    /// Adds a `license` field to the `pyproject.toml` and writes a `LICENSE` file, pre-filled with
+    /// the current year and a placeholder author.
+    #[arg(long, value_enum, ignore_case = true)]
+    pub license: Option<License>,
+
+    /// Create a virtual workspace root, rather than a project.
+    ///
+    /// The generated `pyproject.toml` contains a `[tool.uv.workspace]` table, but no `[project]`
+    /// table, and no source files are created.
+    #[arg(long, conflicts_with_all = ["name", "license"])]
+    pub workspace_root: bool,
+
+    /// Place the package's source in `src/<name>/` (the default).
+    #[arg(long, conflicts_with_all = ["flat_layout", "workspace_root"])]
+    pub src_layout: bool,
+
+    /// Place the package's source in `<name>/`, at the root of the project, rather than under
+    /// `src/`.
+    #[arg(long, conflicts_with_all = ["src_layout", "workspace_root"])]
+    pub flat_layout: bool,
+
+    /// Mark the project as private, such that it's rejected by package indexes like PyPI.
+    ///
+    /// Adds the `Private :: Do Not Upload` classifier to the `pyproject.toml`.
+    #[arg(long, conflicts_with = "workspace_root")]
+    pub private: bool,
+
+    /// Add the given package as a dependency of the new project, e.g., `--dependency requests`;
+    /// may be provided more than once.
+    ///
+    /// The dependencies are added as with `uv add`, after which the project is locked and synced.
+    #[arg(long, value_name = "REQUIREMENT", conflicts_with = "workspace_root")]
+    pub dependency: Vec<String>,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct RunArgs {
@@ -1657,12 +2139,39 @@ pub struct RunArgs {
 
     /// The command to run.
     #[command(subcommand)]
-    pub command: ExternalCommand,
+    pub command: Option<ExternalCommand>,
+
+    /// List the scripts defined in the project's `project.scripts` table, along with their
+    /// targets, rather than running a command.
+    ///
+    /// Cannot be combined with a command.
+    #[arg(long)]
+    pub list: bool,
 
     /// Run with the given packages installed.
     #[arg(long)]
     pub with: Vec<String>,
 
+    /// Set an environment variable for the command, as `KEY=VALUE`; may be provided more than
+    /// once.
+    ///
+    /// Variables set with `--env` take precedence over those inherited from the parent process,
+    /// including the `PATH` and `PYTHONPATH` constructed for the run environment.
+    #[arg(long, value_parser = parse_env_var)]
+    pub env: Vec<(String, String)>,
+
+    /// Run the command with a minimal environment, rather than inheriting the environment of the
+    /// parent process.
+    ///
+    /// Only `PATH`, `HOME`, `USER`, and `TMPDIR` (along with a few variables required by Windows)
+    /// are passed through, similar to `env -i`. Variables set with `--env` are still applied.
+    #[arg(long, overrides_with("inherit_env"))]
+    pub clean_env: bool,
+
+    /// Run the command with the full environment of the parent process (the default).
+    #[arg(long, overrides_with("clean_env"))]
+    pub inherit_env: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -1676,6 +2185,14 @@ pub struct RunArgs {
     #[arg(long, conflicts_with = "isolated")]
     pub package: Option<PackageName>,
 
+    /// Avoid discovering the project or workspace.
+    ///
+    /// The project environment is neither locked, synced, nor used. Instead, the command is run
+    /// with the active virtual environment (via `VIRTUAL_ENV`), if any, or a Python interpreter
+    /// found on the system.
+    #[arg(long, conflicts_with = "package")]
+    pub no_project: bool,
+
     /// The Python interpreter to use to build the run environment.
     ///
     /// By default, `uv` uses the virtual environment in the current working directory or any parent
@@ -1694,9 +2211,16 @@ pub struct RunArgs {
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct SyncArgs {
-    /// Include optional dependencies from the extra group name; may be provided more than once.
+    /// Include optional dependencies from the extra group name; may be provided more than once,
+    /// or as a comma-separated list (e.g., `--extras lint,test`).
     /// Only applies to `pyproject.toml`, `setup.py`, and `setup.cfg` sources.
-    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    #[arg(
+        long,
+        alias = "extras",
+        value_delimiter = ',',
+        conflicts_with = "all_extras",
+        value_parser = extra_name_with_clap_error
+    )]
     pub extra: Option<Vec<ExtraName>>,
 
     /// Include all optional dependencies.
@@ -1720,6 +2244,28 @@ pub struct SyncArgs {
     #[arg(long)]
     pub no_clean: bool,
 
+    /// Check whether the environment is in sync with the lockfile, without modifying it.
+    ///
+    /// Reports any packages that would be installed, upgraded, or removed, and exits with a
+    /// non-zero status if the environment would be modified. The environment is never created or
+    /// replaced; if it's missing or incompatible with the project, that is reported instead.
+    #[arg(long)]
+    pub check: bool,
+
+    /// Install workspace members as regular packages, rather than as editable installs.
+    ///
+    /// The resulting environment doesn't depend on the source tree being present, as is often
+    /// desirable in production deployments.
+    #[arg(long)]
+    pub no_editable: bool,
+
+    /// Sync a specific package in the workspace.
+    ///
+    /// Only the given workspace member and its dependencies (including any workspace members it
+    /// depends on) will be installed; unrelated workspace members will be omitted.
+    #[arg(long, alias = "member", conflicts_with = "isolated")]
+    pub package: Option<PackageName>,
+
     #[command(flatten)]
     pub installer: InstallerArgs,
 
@@ -1756,6 +2302,48 @@ pub struct LockArgs {
     #[command(flatten)]
     pub refresh: RefreshArgs,
 
+    /// Annotate the lockfile with comments that indicate the distributions that depend on each
+    /// locked distribution, using the given style.
+    ///
+    /// The style is recorded in the lockfile, such that subsequent updates to the lockfile (e.g.,
+    /// via `uv add`) retain the annotations. Use `none` to remove the annotations.
+    #[arg(long, value_enum)]
+    pub annotation_style: Option<LockAnnotationStyle>,
+
+    /// Remove the annotations from the lockfile.
+    #[arg(long, conflicts_with = "annotation_style")]
+    pub no_annotate: bool,
+
+    /// Collapse the optional dependencies of each locked distribution into its base dependencies.
+    ///
+    /// The resulting lockfile does not distinguish between extras, such that every extra is
+    /// always installed. Useful for deployment environments in which all extras are required.
+    ///
+    /// The setting is recorded in the lockfile, such that subsequent updates to the lockfile (e.g.,
+    /// via `uv add`) continue to strip extras.
+    #[arg(long, overrides_with("no_strip_extras"))]
+    pub strip_extras: bool,
+
+    /// Record the optional dependencies of each locked distribution separately, reverting a
+    /// previous `--strip-extras`.
+    #[arg(long, overrides_with("strip_extras"))]
+    pub no_strip_extras: bool,
+
+    /// Check whether the lockfile is up-to-date, without updating it.
+    ///
+    /// Exits with a non-zero status if the lockfile is missing or would be changed by `uv lock`.
+    /// Useful in continuous integration to ensure that the lockfile has been committed.
+    #[arg(long)]
+    pub check: bool,
+
+    /// Write the lockfile to the given path, rather than `uv.lock` in the workspace root.
+    ///
+    /// If the file already exists, the versions it contains are preferred when resolving, as with
+    /// `uv.lock`. Useful for maintaining multiple lockfiles for the same workspace, e.g., for
+    /// different deployment targets.
+    #[arg(long, short, value_parser = parse_file_path)]
+    pub output_file: Option<PathBuf>,
+
     /// The Python interpreter to use to build the run environment.
     ///
     /// By default, `uv` uses the virtual environment in the current working directory or any parent
@@ -1782,6 +2370,14 @@ pub struct AddArgs {
     #[arg(long, conflicts_with("optional"))]
     pub dev: bool,
 
+    /// Add the requirements as development scripts.
+    ///
+    /// Development scripts are written to `tool.uv.dev-scripts`, rather than
+    /// `project.dependencies`, and are installed alongside the development dependencies so that
+    /// their entry points are available via `uv run`.
+    #[arg(long, conflicts_with_all = ["dev", "optional"])]
+    pub script: bool,
+
     /// Add the requirements to the specified optional dependency group.
     #[arg(long, conflicts_with("dev"))]
     pub optional: Option<ExtraName>,
@@ -1805,13 +2401,66 @@ pub struct AddArgs {
     pub tag: Option<String>,
 
     /// Branch to use when adding from git.
+    ///
+    /// When `--rev`, `--tag`, or `--branch` is provided, requirements may be given as GitHub
+    /// shorthand (e.g., `astral-sh/uv`) in lieu of a Git URL.
     #[arg(long)]
     pub branch: Option<String>,
 
+    /// Add the packages from the given index, e.g., a private registry.
+    ///
+    /// Adds an `{ index = "<url>" }` entry for each package to `tool.uv.sources`, and adds the index
+    /// to `tool.uv.extra-index-url`, such that it's included whenever the project is resolved. The
+    /// index is searched alongside the project's other indexes, according to the index strategy.
+    /// Only applies to requirements that are resolved from a registry, rather than from a URL,
+    /// path, or Git repository.
+    #[arg(long, value_name = "URL", conflicts_with = "raw_sources")]
+    pub index: Option<IndexUrl>,
+
     /// Extras to activate for the dependency; may be provided more than once.
     #[arg(long)]
     pub extra: Option<Vec<ExtraName>>,
 
+    /// Restrict the dependency to the given Python versions (e.g., `>=3.12`).
+    ///
+    /// Adds a `python_version` marker to the requirement, combined with any marker already present
+    /// on the requirement. Only major and minor versions (e.g., `3.12`, but not `3.12.1`) can be
+    /// used.
+    #[arg(long, value_name = "SPECIFIER")]
+    pub python_version: Option<String>,
+
+    /// Disable isolation when building source distributions for the given packages.
+    ///
+    /// The packages will be built in the project environment, rather than in an isolated
+    /// environment with their declared build dependencies. This is useful for packages that
+    /// assume their build dependencies (e.g., NumPy) are already installed.
+    ///
+    /// Multiple packages may be provided. Unlike `uv pip install`, `:all:` and `:none:` are not
+    /// supported.
+    ///
+    /// The given packages are recorded in `tool.uv.no-build-isolation-package`, such that
+    /// subsequent commands (e.g., `uv sync`) also build them without isolation.
+    #[arg(long, value_parser = parse_no_build_isolation_package)]
+    pub no_build_isolation: Option<Vec<PackageName>>,
+
+    /// Keep the versions of all packages in the existing lockfile fixed.
+    ///
+    /// Only packages that are not yet in the lockfile will be resolved freely. If the new
+    /// requirements are incompatible with the locked versions, the command will fail, rather than
+    /// upgrading or downgrading any locked packages.
+    #[arg(long)]
+    pub reuse_pinned: bool,
+
+    /// Perform a dry run, i.e., resolve the project with the new requirements and print the
+    /// changes to the `pyproject.toml`, without writing them, or updating the lockfile or the
+    /// environment.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// The format in which to print the changes to the `pyproject.toml` in a dry run.
+    #[arg(long, value_enum, default_value_t = DiffFormat::default(), requires = "dry_run")]
+    pub format: DiffFormat,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -1852,6 +2501,10 @@ pub struct RemoveArgs {
     #[arg(long, conflicts_with("optional"))]
     pub dev: bool,
 
+    /// Remove the requirements from development scripts.
+    #[arg(long, conflicts_with_all = ["dev", "optional"])]
+    pub script: bool,
+
     /// Remove the requirements from the specified optional dependency group.
     #[arg(long, conflicts_with("dev"))]
     pub optional: Option<ExtraName>,
@@ -1889,8 +2542,14 @@ pub enum ToolCommand {
     Run(ToolRunArgs),
     /// Install a tool
     Install(ToolInstallArgs),
+    /// Upgrade a tool, or all tools.
+    Upgrade(ToolUpgradeArgs),
+    /// Reinstall a tool, without upgrading it.
+    Reinstall(ToolReinstallArgs),
     /// List installed tools.
     List(ToolListArgs),
+    /// Show details about an installed tool.
+    Inspect(ToolInspectArgs),
     /// Uninstall a tool.
     Uninstall(ToolUninstallArgs),
     /// Show the tools directory.
@@ -1921,6 +2580,10 @@ pub struct ToolRunArgs {
     #[arg(long)]
     pub with: Vec<String>,
 
+    /// Include all requirements listed in the given `requirements.txt` files.
+    #[arg(long, value_parser = parse_file_path)]
+    pub with_requirements: Vec<PathBuf>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -1976,12 +2639,26 @@ pub struct ToolInstallArgs {
     #[arg(long)]
     pub force: bool,
 
+    /// The method to use when installing entry points into the executable directory.
+    ///
+    /// Defaults to `symlink` on Unix and `copy` on Windows. Hard links avoid duplicating the
+    /// entry points on disk, but require the executable directory to be on the same filesystem as
+    /// the tool environment.
+    ///
+    /// Named `--entrypoint-link-mode` to avoid confusion with `--link-mode`, which controls how
+    /// packages are installed into the tool environment.
+    #[arg(long, value_enum)]
+    pub entrypoint_link_mode: Option<EntrypointLinkMode>,
+
     /// The Python interpreter to use to build the tool environment.
     ///
     /// By default, uv will search for a Python executable in the `PATH`. uv ignores virtual
     /// environments while looking for interpreter for tools. The `--python` option allows
     /// you to specify a different interpreter.
     ///
+    /// The request is recorded in the tool's receipt, such that `uv tool upgrade` continues to
+    /// use a matching interpreter, even if the default Python on the system changes.
+    ///
     /// Supported formats:
     /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
     ///   `python3.10` on Linux and macOS.
@@ -1993,12 +2670,78 @@ pub struct ToolInstallArgs {
 
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
-pub struct ToolListArgs;
+pub struct ToolReinstallArgs {
+    /// The name of the tool to reinstall.
+    ///
+    /// The tool is reinstalled from the requirements recorded when it was installed, with every
+    /// package re-downloaded and held at its currently installed version. Useful for repairing a
+    /// corrupted installation.
+    pub name: String,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildArgs,
+}
 
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
-pub struct ToolUninstallArgs {
+pub struct ToolUpgradeArgs {
+    /// The name of the tool to upgrade.
+    #[arg(required_unless_present = "all")]
+    pub name: Option<String>,
+
+    /// Upgrade all tools.
+    ///
+    /// Each tool is upgraded in turn; if any tool fails to upgrade, the remaining tools are still
+    /// upgraded, and the command exits with a non-zero status.
+    #[arg(long, conflicts_with = "name")]
+    pub all: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ToolListArgs {
+    /// Display the version of each installed tool.
+    #[arg(long)]
+    pub versions: bool,
+
+    /// Display the installed tools, along with their versions and entry points, as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ToolInspectArgs {
+    /// The name of the tool to inspect.
     pub name: String,
+
+    /// Display the details of the tool as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ToolUninstallArgs {
+    /// The name of the tool to uninstall.
+    #[arg(required_unless_present = "all")]
+    pub name: Option<String>,
+
+    /// Uninstall all tools.
+    #[arg(long, conflicts_with = "name")]
+    pub all: bool,
 }
 
 #[derive(Args)]
@@ -2020,6 +2763,12 @@ pub enum ToolchainCommand {
     #[command(disable_version_flag = true)]
     Find(ToolchainFindArgs),
 
+    /// Uninstall managed toolchains.
+    Uninstall(ToolchainUninstallArgs),
+
+    /// Show the toolchain that would be used for the current project.
+    Show(ToolchainShowArgs),
+
     /// Show the toolchains directory.
     Dir,
 }
@@ -2053,6 +2802,38 @@ pub struct ToolchainInstallArgs {
     /// Force the installation of the toolchain, even if it is already installed.
     #[arg(long, short)]
     pub force: bool,
+
+    /// Verify the SHA-256 checksum of each downloaded toolchain against the download manifest.
+    ///
+    /// The installation fails if the checksum does not match, or if no checksum is available.
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Install the toolchain from a local copy of its archive, rather than downloading it.
+    ///
+    /// The filename must match that of one of the available downloads (e.g.,
+    /// `cpython-3.12.3+20240415-x86_64-unknown-linux-gnu-debug-full.tar.zst`), which determines
+    /// the toolchain that is installed. Useful in air-gapped environments.
+    ///
+    /// If the toolchain is already installed, it's left as-is, unless `--force` is provided.
+    #[arg(long, value_name = "PATH", conflicts_with = "targets", value_parser = parse_file_path)]
+    pub from_file: Option<PathBuf>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ToolchainUninstallArgs {
+    /// The toolchains to uninstall.
+    #[arg(required_unless_present = "all")]
+    pub targets: Vec<String>,
+
+    /// Uninstall all managed toolchains.
+    #[arg(long, conflicts_with = "targets")]
+    pub all: bool,
+
+    /// Do not prompt for confirmation before removing toolchains.
+    #[arg(long, short)]
+    pub yes: bool,
 }
 
 #[derive(Args)]
@@ -2062,6 +2843,136 @@ pub struct ToolchainFindArgs {
     pub request: Option<String>,
 }
 
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ToolchainShowArgs {
+    /// The Python interpreter to use for the project.
+    ///
+    /// By default, the interpreter is selected from the `.python-version` file, if present, or
+    /// the project's `requires-python`, preferring the project's virtual environment if it
+    /// satisfies both.
+    ///
+    /// Supported formats:
+    /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
+    ///   `python3.10` on Linux and macOS.
+    /// - `python3.10` or `python.exe` looks for a binary with the given name in `PATH`.
+    /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
+    #[arg(long, short, env = "UV_PYTHON", verbatim_doc_comment)]
+    pub python: Option<String>,
+
+    /// Display the path, version, and managed status of the toolchain as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Args)]
+pub struct WorkspaceNamespace {
+    #[command(subcommand)]
+    pub command: WorkspaceCommand,
+}
+
+#[derive(Subcommand)]
+pub enum WorkspaceCommand {
+    /// Display information about the current workspace.
+    Info(WorkspaceInfoArgs),
+    /// Display the dependencies between the members of the current workspace.
+    Graph(WorkspaceGraphArgs),
+    /// Manage the members of the current workspace.
+    Member(WorkspaceMemberNamespace),
+    /// Publish the members of the current workspace, in dependency order.
+    // Hidden until uploading to a package index is supported; only `--dry-run` is functional.
+    #[command(hide = true)]
+    Publish(WorkspacePublishArgs),
+    /// Remove generated artifacts, like virtual environments and build outputs, from the members
+    /// of the current workspace.
+    Clean(WorkspaceCleanArgs),
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct WorkspaceInfoArgs {
+    /// Display the workspace root, members, Python requirement, and lockfile path as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Args)]
+pub struct WorkspaceGraphArgs {
+    /// The format in which to display the graph.
+    ///
+    /// With `dot`, the graph is emitted as a Graphviz DOT file, which can be rendered with, e.g.,
+    /// `dot -Tsvg`.
+    #[arg(long, value_enum, default_value_t = GraphFormat::default())]
+    pub output_format: GraphFormat,
+}
+
+#[derive(Args)]
+pub struct WorkspacePublishArgs {
+    /// Display the order in which the members would be published, without publishing them.
+    ///
+    /// Members are published after any members they depend on.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args)]
+pub struct WorkspaceCleanArgs {
+    /// Preserve the virtual environments of the workspace and its members.
+    ///
+    /// By default, `.venv` directories are removed along with the `dist`, `build`, `__pycache__`,
+    /// and `*.egg-info` directories.
+    #[arg(long)]
+    pub keep_venv: bool,
+}
+
+#[derive(Args)]
+pub struct WorkspaceMemberNamespace {
+    #[command(subcommand)]
+    pub command: WorkspaceMemberCommand,
+}
+
+#[derive(Subcommand)]
+pub enum WorkspaceMemberCommand {
+    /// Add a project to the workspace members, creating the project if it doesn't exist.
+    Add(WorkspaceMemberAddArgs),
+}
+
+#[derive(Args)]
+pub struct WorkspaceMemberAddArgs {
+    /// The name of the project to add.
+    pub name: PackageName,
+
+    /// The path to the project.
+    ///
+    /// If the path doesn't contain a `pyproject.toml`, a new project is created there, as with
+    /// `uv init`. Defaults to a directory named after the project in the workspace root.
+    #[arg(long)]
+    pub path: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use when locking the workspace.
+    ///
+    /// By default, `uv` uses the virtual environment in the current working directory or any parent
+    /// directory, falling back to searching for a Python executable in `PATH`. The `--python`
+    /// option allows you to specify a different interpreter.
+    ///
+    /// Supported formats:
+    /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
+    ///   `python3.10` on Linux and macOS.
+    /// - `python3.10` or `python.exe` looks for a binary with the given name in `PATH`.
+    /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
+    #[arg(long, short, env = "UV_PYTHON", verbatim_doc_comment)]
+    pub python: Option<String>,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct IndexArgs {
@@ -2162,6 +3073,8 @@ pub struct InstallerArgs {
     pub index_args: IndexArgs,
 
     /// Reinstall all packages, regardless of whether they're already installed.
+    ///
+    /// Applies to every package in the resolution, including dependencies.
     #[arg(long, alias = "force-reinstall", overrides_with("no_reinstall"))]
     pub reinstall: bool,
 
@@ -2318,6 +3231,8 @@ pub struct ResolverInstallerArgs {
     pub upgrade_package: Vec<PackageName>,
 
     /// Reinstall all packages, regardless of whether they're already installed.
+    ///
+    /// Applies to every package in the resolution, including dependencies.
     #[arg(long, alias = "force-reinstall", overrides_with("no_reinstall"))]
     pub reinstall: bool,
 
@@ -2,16 +2,18 @@ use std::error::Error;
 use std::fmt::Debug;
 use std::ops::Deref;
 use std::path::Path;
+use std::time::Duration;
 use std::{env, iter};
 
 use itertools::Itertools;
-use reqwest::{Client, ClientBuilder, Response};
+use reqwest::{Client, ClientBuilder, Proxy, Response};
 use reqwest_middleware::ClientWithMiddleware;
 use reqwest_retry::policies::ExponentialBackoff;
 use reqwest_retry::{
     DefaultRetryableStrategy, RetryTransientMiddleware, Retryable, RetryableStrategy,
 };
 use tracing::debug;
+use url::Url;
 
 use pep508_rs::MarkerEnvironment;
 use platform_tags::Platform;
@@ -23,7 +25,7 @@ use uv_warnings::warn_user_once;
 
 use crate::linehaul::LineHaul;
 use crate::middleware::OfflineMiddleware;
-use crate::tls::read_identity;
+use crate::tls::{read_identity, CaBundle};
 use crate::Connectivity;
 
 /// A builder for an [`BaseClient`].
@@ -32,6 +34,13 @@ pub struct BaseClientBuilder<'a> {
     keyring: KeyringProviderType,
     native_tls: bool,
     retries: u32,
+    retry_delay: Option<Duration>,
+    max_retries: Option<u32>,
+    retry_status_codes: Option<Vec<u16>>,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    socks_proxy: Option<Url>,
+    ssl_ca_bundle: Option<CaBundle>,
     pub connectivity: Connectivity,
     client: Option<Client>,
     markers: Option<&'a MarkerEnvironment>,
@@ -51,6 +60,13 @@ impl BaseClientBuilder<'_> {
             native_tls: false,
             connectivity: Connectivity::Online,
             retries: 3,
+            retry_delay: None,
+            max_retries: None,
+            retry_status_codes: None,
+            connect_timeout: None,
+            read_timeout: None,
+            socks_proxy: None,
+            ssl_ca_bundle: None,
             client: None,
             markers: None,
             platform: None,
@@ -77,6 +93,67 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Set the base interval for the exponential backoff between retries.
+    #[must_use]
+    pub fn retry_delay(mut self, retry_delay: Option<Duration>) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Set the number of times to retry a request that fails with one of the retryable status
+    /// codes, separately from the retries for requests that fail without a response.
+    ///
+    /// Defaults to the value of [`BaseClientBuilder::retries`].
+    #[must_use]
+    pub fn max_retries(mut self, max_retries: Option<u32>) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the HTTP status codes for which requests are retried.
+    ///
+    /// If neither this nor [`BaseClientBuilder::max_retries`] is set, requests are retried on any
+    /// server error, per the [`DefaultRetryableStrategy`]. Otherwise, this defaults to `429`,
+    /// `500`, `502`, `503`, and `504`.
+    #[must_use]
+    pub fn retry_status_codes(mut self, retry_status_codes: Option<Vec<u16>>) -> Self {
+        self.retry_status_codes = retry_status_codes;
+        self
+    }
+
+    /// Set the timeout for establishing a connection.
+    ///
+    /// By default, establishing a connection is only bounded by the read timeout.
+    #[must_use]
+    pub fn connect_timeout(mut self, connect_timeout: Option<Duration>) -> Self {
+        self.connect_timeout = connect_timeout;
+        self
+    }
+
+    /// Set the timeout between bytes received on a connection.
+    ///
+    /// Defaults to the value of `UV_HTTP_TIMEOUT`, or 30 seconds.
+    #[must_use]
+    pub fn read_timeout(mut self, read_timeout: Option<Duration>) -> Self {
+        self.read_timeout = read_timeout;
+        self
+    }
+
+    /// Route all requests through the given SOCKS5 proxy.
+    #[must_use]
+    pub fn socks_proxy(mut self, socks_proxy: Option<Url>) -> Self {
+        self.socks_proxy = socks_proxy;
+        self
+    }
+
+    /// Trust the certificates in the given PEM bundle, in addition to the default root
+    /// certificates.
+    #[must_use]
+    pub fn ssl_ca_bundle(mut self, ssl_ca_bundle: Option<CaBundle>) -> Self {
+        self.ssl_ca_bundle = ssl_ca_bundle;
+        self
+    }
+
     #[must_use]
     pub fn native_tls(mut self, native_tls: bool) -> Self {
         self.native_tls = native_tls;
@@ -120,19 +197,23 @@ impl<'a> BaseClientBuilder<'a> {
         // Timeout options, matching https://doc.rust-lang.org/nightly/cargo/reference/config.html#httptimeout
         // `UV_REQUEST_TIMEOUT` is provided for backwards compatibility with v0.1.6
         let default_timeout = 30;
-        let timeout = env::var("UV_HTTP_TIMEOUT")
-            .or_else(|_| env::var("UV_REQUEST_TIMEOUT"))
-            .or_else(|_| env::var("HTTP_TIMEOUT"))
-            .and_then(|value| {
-                value.parse::<u64>()
-                    .or_else(|_| {
-                        // On parse error, warn and use the default timeout
-                        warn_user_once!("Ignoring invalid value from environment for UV_HTTP_TIMEOUT. Expected integer number of seconds, got \"{value}\".");
-                        Ok(default_timeout)
-                    })
-            })
-            .unwrap_or(default_timeout);
-        debug!("Using request timeout of {timeout}s");
+        // An explicit read timeout takes precedence over the environment.
+        let timeout = self.read_timeout.unwrap_or_else(|| {
+            let timeout = env::var("UV_HTTP_TIMEOUT")
+                .or_else(|_| env::var("UV_REQUEST_TIMEOUT"))
+                .or_else(|_| env::var("HTTP_TIMEOUT"))
+                .and_then(|value| {
+                    value.parse::<u64>()
+                        .or_else(|_| {
+                            // On parse error, warn and use the default timeout
+                            warn_user_once!("Ignoring invalid value from environment for UV_HTTP_TIMEOUT. Expected integer number of seconds, got \"{value}\".");
+                            Ok(default_timeout)
+                        })
+                })
+                .unwrap_or(default_timeout);
+            Duration::from_secs(timeout)
+        });
+        debug!("Using request timeout of {timeout:?}");
 
         // Initialize the base client.
         let client = self.client.clone().unwrap_or_else(|| {
@@ -152,9 +233,17 @@ impl<'a> BaseClientBuilder<'a> {
             let client_core = ClientBuilder::new()
                 .user_agent(user_agent_string)
                 .pool_max_idle_per_host(20)
-                .read_timeout(std::time::Duration::from_secs(timeout))
+                .read_timeout(timeout)
                 .tls_built_in_root_certs(false);
 
+            // Configure the connection timeout.
+            let client_core = if let Some(connect_timeout) = self.connect_timeout {
+                debug!("Using connection timeout of {connect_timeout:?}");
+                client_core.connect_timeout(connect_timeout)
+            } else {
+                client_core
+            };
+
             // Configure TLS.
             let client_core = if self.native_tls || ssl_cert_file_exists {
                 client_core.tls_built_in_native_certs(true)
@@ -162,6 +251,22 @@ impl<'a> BaseClientBuilder<'a> {
                 client_core.tls_built_in_webpki_certs(true)
             };
 
+            // Configure any additional root certificates.
+            let client_core = if let Some(ssl_ca_bundle) = &self.ssl_ca_bundle {
+                debug!(
+                    "Using {} certificate(s) from CA bundle: {}",
+                    ssl_ca_bundle.certificates().len(),
+                    ssl_ca_bundle.path().user_display()
+                );
+                ssl_ca_bundle
+                    .certificates()
+                    .iter()
+                    .cloned()
+                    .fold(client_core, ClientBuilder::add_root_certificate)
+            } else {
+                client_core
+            };
+
             // Configure mTLS.
             let client_core = if let Some(ssl_client_cert) = env::var_os("SSL_CLIENT_CERT") {
                 match read_identity(&ssl_client_cert) {
@@ -175,6 +280,22 @@ impl<'a> BaseClientBuilder<'a> {
                 client_core
             };
 
+            // Configure the SOCKS proxy.
+            let client_core = if let Some(socks_proxy) = &self.socks_proxy {
+                match Proxy::all(socks_proxy.clone()) {
+                    Ok(proxy) => {
+                        debug!("Using SOCKS proxy: {socks_proxy}");
+                        client_core.proxy(proxy)
+                    }
+                    Err(err) => {
+                        warn_user_once!("Ignoring invalid SOCKS proxy `{socks_proxy}`: {err}");
+                        client_core
+                    }
+                }
+            } else {
+                client_core
+            };
+
             client_core.build().expect("Failed to build HTTP client.")
         });
 
@@ -183,14 +304,29 @@ impl<'a> BaseClientBuilder<'a> {
             Connectivity::Online => {
                 let client = reqwest_middleware::ClientBuilder::new(client.clone());
 
-                // Initialize the retry strategy.
-                let retry_policy =
-                    ExponentialBackoff::builder().build_with_max_retries(self.retries);
-                let retry_strategy = RetryTransientMiddleware::new_with_policy_and_strategy(
-                    retry_policy,
-                    LoggingRetryableStrategy,
-                );
-                let client = client.with(retry_strategy);
+                // Initialize the retry strategy. If the retryable status codes were customized,
+                // retry failed responses separately from failed requests, such that each is bounded
+                // by its own number of retries.
+                let client = if self.max_retries.is_none() && self.retry_status_codes.is_none() {
+                    client.with(RetryTransientMiddleware::new_with_policy_and_strategy(
+                        self.retry_policy(self.retries),
+                        LoggingRetryableStrategy::All,
+                    ))
+                } else {
+                    let status_codes = self
+                        .retry_status_codes
+                        .clone()
+                        .unwrap_or_else(|| DEFAULT_RETRY_STATUS_CODES.to_vec());
+                    client
+                        .with(RetryTransientMiddleware::new_with_policy_and_strategy(
+                            self.retry_policy(self.max_retries.unwrap_or(self.retries)),
+                            LoggingRetryableStrategy::StatusCodes(status_codes),
+                        ))
+                        .with(RetryTransientMiddleware::new_with_policy_and_strategy(
+                            self.retry_policy(self.retries),
+                            LoggingRetryableStrategy::Errors,
+                        ))
+                };
 
                 // Initialize the authentication middleware to set headers.
                 let client =
@@ -207,6 +343,27 @@ impl<'a> BaseClientBuilder<'a> {
             connectivity: self.connectivity,
             client,
             timeout,
+            timeout_setting: if self.read_timeout.is_some() {
+                "--read-timeout"
+            } else {
+                "UV_HTTP_TIMEOUT"
+            },
+        }
+    }
+
+    /// The policy for retrying a request up to `retries` times.
+    ///
+    /// If a base delay was provided, the backoff is bounded by the delay before the final attempt,
+    /// such that each retry doubles the delay.
+    fn retry_policy(&self, retries: u32) -> ExponentialBackoff {
+        if let Some(retry_delay) = self.retry_delay {
+            let max_delay =
+                retry_delay.saturating_mul(2u32.saturating_pow(retries.saturating_sub(1)));
+            ExponentialBackoff::builder()
+                .retry_bounds(retry_delay, max_delay)
+                .build_with_max_retries(retries)
+        } else {
+            ExponentialBackoff::builder().build_with_max_retries(retries)
         }
     }
 }
@@ -218,8 +375,10 @@ pub struct BaseClient {
     client: ClientWithMiddleware,
     /// The connectivity mode to use.
     connectivity: Connectivity,
-    /// Configured client timeout, in seconds.
-    timeout: u64,
+    /// Configured client timeout.
+    timeout: Duration,
+    /// The setting from which the client timeout was read.
+    timeout_setting: &'static str,
 }
 
 impl BaseClient {
@@ -228,11 +387,17 @@ impl BaseClient {
         self.client.clone()
     }
 
-    /// The configured client timeout, in seconds.
-    pub fn timeout(&self) -> u64 {
+    /// The configured client timeout.
+    pub fn timeout(&self) -> Duration {
         self.timeout
     }
 
+    /// The setting from which the client timeout was read, i.e., `--read-timeout` or
+    /// `UV_HTTP_TIMEOUT`, to suggest in error messages.
+    pub fn timeout_setting(&self) -> &'static str {
+        self.timeout_setting
+    }
+
     /// The configured connectivity mode.
     pub fn connectivity(&self) -> Connectivity {
         self.connectivity
@@ -249,13 +414,30 @@ impl Deref for BaseClient {
     }
 }
 
+/// The HTTP status codes for which requests are retried by default, if the retryable status codes
+/// are customized.
+const DEFAULT_RETRY_STATUS_CODES: [u16; 5] = [429, 500, 502, 503, 504];
+
 /// The same as [`DefaultRetryableStrategy`], but retry attempts on transient request failures are
 /// logged, so we can tell whether a request was retried before failing or not.
-struct LoggingRetryableStrategy;
+enum LoggingRetryableStrategy {
+    /// Retry both failed requests and server error responses, per [`DefaultRetryableStrategy`].
+    All,
+    /// Only retry requests that failed without a response, e.g., due to a connection error.
+    Errors,
+    /// Only retry responses with one of the given status codes.
+    StatusCodes(Vec<u16>),
+}
 
 impl RetryableStrategy for LoggingRetryableStrategy {
     fn handle(&self, res: &Result<Response, reqwest_middleware::Error>) -> Option<Retryable> {
-        let retryable = DefaultRetryableStrategy.handle(res);
+        let retryable = match (self, res) {
+            (Self::All, _) | (Self::Errors, Err(_)) => DefaultRetryableStrategy.handle(res),
+            (Self::Errors, Ok(_)) | (Self::StatusCodes(_), Err(_)) => None,
+            (Self::StatusCodes(status_codes), Ok(response)) => status_codes
+                .contains(&response.status().as_u16())
+                .then_some(Retryable::Transient),
+        };
         if retryable == Some(Retryable::Transient) {
             match res {
                 Ok(response) => {
@@ -275,3 +457,116 @@ impl RetryableStrategy for LoggingRetryableStrategy {
         retryable
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use reqwest::{Client, Response};
+    use reqwest_retry::{Retryable, RetryableStrategy};
+
+    use std::time::Duration;
+
+    use super::{BaseClientBuilder, LoggingRetryableStrategy};
+
+    /// Build a response with the given status code.
+    fn response(status: u16) -> Result<Response> {
+        Ok(Response::from(
+            http::Response::builder().status(status).body("")?,
+        ))
+    }
+
+    /// Send a request to a port that nothing is listening on, to get a connection error.
+    async fn connection_error() -> Result<reqwest_middleware::Error> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?;
+        drop(listener);
+
+        let err = Client::new()
+            .get(format!("http://{address}"))
+            .send()
+            .await
+            .expect_err("the request should fail to connect");
+        assert!(err.is_connect());
+        Ok(reqwest_middleware::Error::Reqwest(err))
+    }
+
+    #[tokio::test]
+    async fn retry_all() -> Result<()> {
+        let strategy = LoggingRetryableStrategy::All;
+
+        assert_eq!(
+            strategy.handle(&Ok(response(503)?)),
+            Some(Retryable::Transient)
+        );
+        assert_eq!(strategy.handle(&Ok(response(200)?)), None);
+        assert_eq!(
+            strategy.handle(&Err(connection_error().await?)),
+            Some(Retryable::Transient)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn retry_errors() -> Result<()> {
+        let strategy = LoggingRetryableStrategy::Errors;
+
+        // Server errors are returned as-is, rather than retried.
+        assert_eq!(strategy.handle(&Ok(response(503)?)), None);
+        assert_eq!(strategy.handle(&Ok(response(429)?)), None);
+        assert_eq!(strategy.handle(&Ok(response(200)?)), None);
+        assert_eq!(
+            strategy.handle(&Err(connection_error().await?)),
+            Some(Retryable::Transient)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn retry_status_codes() -> Result<()> {
+        let strategy = LoggingRetryableStrategy::StatusCodes(vec![429, 503]);
+
+        assert_eq!(
+            strategy.handle(&Ok(response(429)?)),
+            Some(Retryable::Transient)
+        );
+        assert_eq!(
+            strategy.handle(&Ok(response(503)?)),
+            Some(Retryable::Transient)
+        );
+        // Status codes outside the list aren't retried, even if they'd be retried by default.
+        assert_eq!(strategy.handle(&Ok(response(500)?)), None);
+        assert_eq!(strategy.handle(&Ok(response(200)?)), None);
+        // Neither are requests that failed without a response.
+        assert_eq!(strategy.handle(&Err(connection_error().await?)), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn retry_policy_bounds() {
+        let builder = BaseClientBuilder::new().retry_delay(Some(Duration::from_millis(500)));
+
+        // The delay doubles with each retry, up to the delay before the final attempt.
+        let policy = builder.retry_policy(3);
+        assert_eq!(policy.min_retry_interval, Duration::from_millis(500));
+        assert_eq!(policy.max_retry_interval, Duration::from_secs(2));
+
+        let policy = builder.retry_policy(1);
+        assert_eq!(policy.min_retry_interval, Duration::from_millis(500));
+        assert_eq!(policy.max_retry_interval, Duration::from_millis(500));
+
+        let policy = builder.retry_policy(0);
+        assert_eq!(policy.min_retry_interval, Duration::from_millis(500));
+        assert_eq!(policy.max_retry_interval, Duration::from_millis(500));
+
+        // Large retry counts saturate, rather than overflowing.
+        let policy = builder.retry_policy(64);
+        assert_eq!(policy.min_retry_interval, Duration::from_millis(500));
+        assert_eq!(
+            policy.max_retry_interval,
+            Duration::from_millis(500) * u32::MAX
+        );
+    }
+}
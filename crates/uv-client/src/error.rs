@@ -1,15 +1,18 @@
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
+use std::path::PathBuf;
 
 use async_http_range_reader::AsyncHttpRangeReaderError;
 use async_zip::error::ZipError;
 use url::Url;
 
 use distribution_filename::{WheelFilename, WheelFilenameError};
+use uv_fs::Simplified;
 use uv_normalize::PackageName;
 
 use crate::html;
 use crate::middleware::OfflineError;
+use crate::tls::CertificateError;
 
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
@@ -229,6 +232,9 @@ pub enum ErrorKind {
 
     #[error("Network connectivity is disabled, but the requested data wasn't found in the cache for: `{0}`")]
     Offline(String),
+
+    #[error("Failed to read CA bundle: `{}`", _0.user_display())]
+    CaBundle(PathBuf, #[source] CertificateError),
 }
 
 impl From<reqwest::Error> for ErrorKind {
@@ -8,6 +8,7 @@ pub use registry_client::{
     VersionFiles,
 };
 pub use rkyvutil::OwnedArchive;
+pub use tls::{CaBundle, CertificateError};
 
 mod base_client;
 mod cached_client;
@@ -2,6 +2,7 @@ use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
 use async_http_range_reader::AsyncHttpRangeReader;
 use futures::{FutureExt, TryStreamExt};
@@ -30,7 +31,7 @@ use crate::cached_client::CacheControl;
 use crate::html::SimpleHtml;
 use crate::remote_metadata::wheel_metadata_from_remote_zip;
 use crate::rkyvutil::OwnedArchive;
-use crate::{CachedClient, CachedClientError, Error, ErrorKind};
+use crate::{CaBundle, CachedClient, CachedClientError, Error, ErrorKind};
 
 /// A builder for an [`RegistryClient`].
 #[derive(Debug, Clone)]
@@ -40,6 +41,13 @@ pub struct RegistryClientBuilder<'a> {
     keyring: KeyringProviderType,
     native_tls: bool,
     retries: u32,
+    retry_delay: Option<Duration>,
+    max_retries: Option<u32>,
+    retry_status_codes: Option<Vec<u16>>,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    socks_proxy: Option<Url>,
+    ssl_ca_bundle: Option<CaBundle>,
     connectivity: Connectivity,
     cache: Cache,
     client: Option<Client>,
@@ -57,6 +65,13 @@ impl RegistryClientBuilder<'_> {
             cache,
             connectivity: Connectivity::Online,
             retries: 3,
+            retry_delay: None,
+            max_retries: None,
+            retry_status_codes: None,
+            connect_timeout: None,
+            read_timeout: None,
+            socks_proxy: None,
+            ssl_ca_bundle: None,
             client: None,
             markers: None,
             platform: None,
@@ -95,6 +110,57 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    /// Set the base interval for the exponential backoff between retries.
+    #[must_use]
+    pub fn retry_delay(mut self, retry_delay: Option<Duration>) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Set the number of times to retry a request that fails with one of the retryable status
+    /// codes, separately from the retries for requests that fail without a response.
+    #[must_use]
+    pub fn max_retries(mut self, max_retries: Option<u32>) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the HTTP status codes for which requests are retried.
+    #[must_use]
+    pub fn retry_status_codes(mut self, retry_status_codes: Option<Vec<u16>>) -> Self {
+        self.retry_status_codes = retry_status_codes;
+        self
+    }
+
+    /// Set the timeout for establishing a connection.
+    #[must_use]
+    pub fn connect_timeout(mut self, connect_timeout: Option<Duration>) -> Self {
+        self.connect_timeout = connect_timeout;
+        self
+    }
+
+    /// Set the timeout between bytes received on a connection.
+    #[must_use]
+    pub fn read_timeout(mut self, read_timeout: Option<Duration>) -> Self {
+        self.read_timeout = read_timeout;
+        self
+    }
+
+    /// Route all requests through the given SOCKS5 proxy.
+    #[must_use]
+    pub fn socks_proxy(mut self, socks_proxy: Option<Url>) -> Self {
+        self.socks_proxy = socks_proxy;
+        self
+    }
+
+    /// Trust the certificates in the given PEM bundle, in addition to the default root
+    /// certificates.
+    #[must_use]
+    pub fn ssl_ca_bundle(mut self, ssl_ca_bundle: Option<CaBundle>) -> Self {
+        self.ssl_ca_bundle = ssl_ca_bundle;
+        self
+    }
+
     #[must_use]
     pub fn native_tls(mut self, native_tls: bool) -> Self {
         self.native_tls = native_tls;
@@ -143,12 +209,20 @@ impl<'a> RegistryClientBuilder<'a> {
 
         let client = builder
             .retries(self.retries)
+            .retry_delay(self.retry_delay)
+            .max_retries(self.max_retries)
+            .retry_status_codes(self.retry_status_codes)
+            .connect_timeout(self.connect_timeout)
+            .read_timeout(self.read_timeout)
+            .socks_proxy(self.socks_proxy)
+            .ssl_ca_bundle(self.ssl_ca_bundle)
             .connectivity(self.connectivity)
             .native_tls(self.native_tls)
             .keyring(self.keyring)
             .build();
 
         let timeout = client.timeout();
+        let timeout_setting = client.timeout_setting();
         let connectivity = client.connectivity();
 
         // Wrap in the cache middleware.
@@ -161,6 +235,7 @@ impl<'a> RegistryClientBuilder<'a> {
             connectivity,
             client,
             timeout,
+            timeout_setting,
         }
     }
 }
@@ -178,8 +253,10 @@ pub struct RegistryClient {
     cache: Cache,
     /// The connectivity mode to use.
     connectivity: Connectivity,
-    /// Configured client timeout, in seconds.
-    timeout: u64,
+    /// Configured client timeout.
+    timeout: Duration,
+    /// The setting from which the client timeout was read.
+    timeout_setting: &'static str,
 }
 
 impl RegistryClient {
@@ -198,11 +275,16 @@ impl RegistryClient {
         self.connectivity
     }
 
-    /// Return the timeout this client is configured with, in seconds.
-    pub fn timeout(&self) -> u64 {
+    /// Return the timeout this client is configured with.
+    pub fn timeout(&self) -> Duration {
         self.timeout
     }
 
+    /// Return the setting from which the timeout was read, to suggest in error messages.
+    pub fn timeout_setting(&self) -> &'static str {
+        self.timeout_setting
+    }
+
     /// Fetch a package from the `PyPI` simple API.
     ///
     /// "simple" here refers to [PEP 503 – Simple Repository API](https://peps.python.org/pep-0503/)
@@ -679,7 +761,7 @@ impl RegistryClient {
             std::io::Error::new(
                 std::io::ErrorKind::TimedOut,
                 format!(
-                    "Failed to download distribution due to network timeout. Try increasing UV_HTTP_TIMEOUT (current value: {}s).", self.timeout()
+                    "Failed to download distribution due to network timeout. Try increasing {} (current value: {:?}).", self.timeout_setting(), self.timeout()
                 ),
             )
         } else {
@@ -1,13 +1,56 @@
-use reqwest::Identity;
+use reqwest::{Certificate, Identity};
 use std::ffi::OsStr;
 use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::{Error, ErrorKind};
 
 #[derive(thiserror::Error, Debug)]
-pub(crate) enum CertificateError {
+pub enum CertificateError {
     #[error(transparent)]
     Io(#[from] std::io::Error),
     #[error(transparent)]
     Reqwest(#[from] reqwest::Error),
+    #[error("No certificates found in PEM bundle")]
+    Empty,
+}
+
+/// A PEM bundle of additional CA certificates to trust (e.g., as provided via `--ssl-ca-bundle`).
+#[derive(Debug, Clone)]
+pub struct CaBundle {
+    path: PathBuf,
+    certificates: Vec<Certificate>,
+}
+
+impl CaBundle {
+    /// Read the certificates from the PEM bundle at the given path.
+    ///
+    /// Returns an error if the bundle can't be read, or doesn't contain any certificates.
+    pub fn from_path(path: &Path) -> Result<Self, Error> {
+        let certificates = read_certificates(path)
+            .and_then(|certificates| {
+                if certificates.is_empty() {
+                    Err(CertificateError::Empty)
+                } else {
+                    Ok(certificates)
+                }
+            })
+            .map_err(|err| ErrorKind::CaBundle(path.to_path_buf(), err))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            certificates,
+        })
+    }
+
+    /// The path to the PEM bundle.
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The certificates in the PEM bundle.
+    pub(crate) fn certificates(&self) -> &[Certificate] {
+        &self.certificates
+    }
 }
 
 /// Return the `Identity` from the provided file.
@@ -16,3 +59,10 @@ pub(crate) fn read_identity(ssl_client_cert: &OsStr) -> Result<Identity, Certifi
     fs_err::File::open(ssl_client_cert)?.read_to_end(&mut buf)?;
     Ok(Identity::from_pem(&buf)?)
 }
+
+/// Return the `Certificate`s from the provided PEM bundle.
+fn read_certificates(ssl_ca_bundle: &Path) -> Result<Vec<Certificate>, CertificateError> {
+    let mut buf = Vec::new();
+    fs_err::File::open(ssl_ca_bundle)?.read_to_end(&mut buf)?;
+    Ok(Certificate::from_pem_bundle(&buf)?)
+}
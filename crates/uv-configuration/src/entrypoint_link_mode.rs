@@ -0,0 +1,40 @@
+/// The strategy to use when installing a tool's entry points into the executable directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum EntrypointLinkMode {
+    /// Symbolically link each entry point into the executable directory (the default on Unix).
+    Symlink,
+    /// Copy each entry point into the executable directory (the default on Windows).
+    Copy,
+    /// Hard link each entry point into the executable directory.
+    Hardlink,
+}
+
+impl Default for EntrypointLinkMode {
+    fn default() -> Self {
+        if cfg!(windows) {
+            Self::Copy
+        } else {
+            Self::Symlink
+        }
+    }
+}
+
+impl EntrypointLinkMode {
+    /// Returns the name of the link mode, as used on the command line and in tool receipts.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Symlink => "symlink",
+            Self::Copy => "copy",
+            Self::Hardlink => "hardlink",
+        }
+    }
+}
+
+impl std::fmt::Display for EntrypointLinkMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
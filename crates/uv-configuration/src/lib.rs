@@ -3,6 +3,7 @@ pub use build_options::*;
 pub use concurrency::*;
 pub use config_settings::*;
 pub use constraints::*;
+pub use entrypoint_link_mode::*;
 pub use extras::*;
 pub use name_specifiers::*;
 pub use overrides::*;
@@ -15,6 +16,7 @@ mod build_options;
 mod concurrency;
 mod config_settings;
 mod constraints;
+mod entrypoint_link_mode;
 mod extras;
 mod name_specifiers;
 mod overrides;
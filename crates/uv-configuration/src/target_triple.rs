@@ -84,6 +84,36 @@ pub enum TargetTriple {
 }
 
 impl TargetTriple {
+    /// Return the [`TargetTriple`] corresponding to a wheel platform tag, as accepted by
+    /// `pip install --platform` (e.g., `manylinux2014_x86_64` or `win_amd64`).
+    ///
+    /// Returns `None` if the platform tag has no equivalent target triple.
+    pub fn from_platform_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "win_amd64" => Some(Self::X8664PcWindowsMsvc),
+            "linux_x86_64" => Some(Self::X8664UnknownLinuxGnu),
+            "linux_aarch64" => Some(Self::Aarch64UnknownLinuxGnu),
+            "manylinux2014_x86_64" | "manylinux_2_17_x86_64" => Some(Self::X8664Manylinux217),
+            "manylinux2014_aarch64" | "manylinux_2_17_aarch64" => Some(Self::Aarch64Manylinux217),
+            "manylinux_2_28_x86_64" => Some(Self::X8664Manylinux228),
+            "manylinux_2_28_aarch64" => Some(Self::Aarch64Manylinux228),
+            "musllinux_1_2_x86_64" => Some(Self::X8664UnknownLinuxMusl),
+            "musllinux_1_2_aarch64" => Some(Self::Aarch64UnknownLinuxMusl),
+            _ => {
+                // macOS tags embed the deployment target (e.g., `macosx_11_0_arm64`), which is
+                // instead read from `MACOSX_DEPLOYMENT_TARGET`.
+                let rest = tag.strip_prefix("macosx_")?;
+                if rest.ends_with("_arm64") {
+                    Some(Self::Aarch64AppleDarwin)
+                } else if rest.ends_with("_x86_64") {
+                    Some(Self::X8664AppleDarwin)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
     /// Return the [`Platform`] for the target.
     pub fn platform(self) -> Platform {
         match self {
@@ -110,6 +110,15 @@ impl<'a> BuildDispatch<'a> {
             .collect();
         self
     }
+
+    /// Resolve (but do not install) the build requirements of the source tree at the given path,
+    /// to fail early if any are unavailable.
+    pub async fn check_build_requirements(&self, source_tree: &Path) -> Result<Resolution> {
+        let resolution = SourceBuild::resolve_build_requirements(source_tree, self.setup_py, self)
+            .boxed_local()
+            .await?;
+        Ok(resolution)
+    }
 }
 
 impl<'a> BuildContext for BuildDispatch<'a> {
@@ -329,7 +338,8 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             version_id.to_string(),
             self.setup_py,
             self.config_settings.clone(),
-            self.build_isolation,
+            self.build_isolation
+                .for_package(dist.map(distribution_types::Name::name)),
             build_kind,
             self.build_extra_env_vars.clone(),
             self.concurrency.builds,
@@ -89,7 +89,7 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
             io::Error::new(
                 io::ErrorKind::TimedOut,
                 format!(
-                    "Failed to download distribution due to network timeout. Try increasing UV_HTTP_TIMEOUT (current value: {}s).",  self.client.unmanaged.timeout()
+                    "Failed to download distribution due to network timeout. Try increasing {} (current value: {:?}).",  self.client.unmanaged.timeout_setting(), self.client.unmanaged.timeout()
                 ),
             )
         } else {
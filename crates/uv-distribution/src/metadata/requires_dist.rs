@@ -63,7 +63,8 @@ impl RequiresDist {
         project_workspace: &ProjectWorkspace,
         preview_mode: PreviewMode,
     ) -> Result<Self, MetadataError> {
-        // Collect any `tool.uv.sources` and `tool.uv.dev_dependencies` from `pyproject.toml`.
+        // Collect any `tool.uv.sources`, `tool.uv.dev-dependencies`, and `tool.uv.dev-scripts` from
+        // `pyproject.toml`.
         let empty = BTreeMap::default();
         let sources = project_workspace
             .current_project()
@@ -74,16 +75,24 @@ impl RequiresDist {
             .and_then(|uv| uv.sources.as_ref())
             .unwrap_or(&empty);
 
+        // Development scripts are installed alongside the development dependencies, so that their
+        // entry points are available in the project environment.
         let dev_dependencies = {
-            let dev_dependencies = project_workspace
+            let uv = project_workspace
                 .current_project()
                 .pyproject_toml()
                 .tool
                 .as_ref()
-                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|tool| tool.uv.as_ref());
+            let dev_dependencies = uv
                 .and_then(|uv| uv.dev_dependencies.as_ref())
                 .into_iter()
                 .flatten()
+                .chain(
+                    uv.and_then(|uv| uv.dev_scripts.as_ref())
+                        .into_iter()
+                        .flatten(),
+                )
                 .cloned()
                 .map(|requirement| {
                     let requirement_name = requirement.name.clone();
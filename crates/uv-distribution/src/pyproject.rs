@@ -57,10 +57,22 @@ impl Eq for PyProjectToml {}
 pub struct Project {
     /// The name of the project
     pub name: PackageName,
+    /// The version of the project, if it's not dynamic.
+    ///
+    /// The version is retained as a string, such that a version that isn't PEP 440-compliant
+    /// doesn't prevent the workspace from being read.
+    pub version: Option<String>,
     /// The Python versions this project is compatible with.
     pub requires_python: Option<VersionSpecifiers>,
+    /// The dependencies of the project.
+    pub dependencies: Option<Vec<String>>,
     /// The optional dependencies of the project.
     pub optional_dependencies: Option<BTreeMap<ExtraName, Vec<String>>>,
+    /// The trove classifiers of the project.
+    pub classifiers: Option<Vec<String>>,
+    /// The console scripts of the project, as a map from name to object reference (e.g.,
+    /// `module:function`).
+    pub scripts: Option<BTreeMap<String, String>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -87,6 +99,16 @@ pub struct ToolUv {
         )
     )]
     pub dev_dependencies: Option<Vec<pep508_rs::Requirement<VerbatimParsedUrl>>>,
+    /// Command-line tools used during development (e.g., `ruff`), which are installed alongside
+    /// the development dependencies so that their entry points are available to `uv run`.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<Vec<String>>",
+            description = "PEP 508-style requirements for command-line tools used during development, e.g., `ruff==0.5.0`."
+        )
+    )]
+    pub dev_scripts: Option<Vec<pep508_rs::Requirement<VerbatimParsedUrl>>>,
     #[cfg_attr(
         feature = "schemars",
         schemars(
@@ -95,6 +117,9 @@ pub struct ToolUv {
         )
     )]
     pub override_dependencies: Option<Vec<pep508_rs::Requirement<VerbatimParsedUrl>>>,
+    /// Packages to build without build isolation, in the project environment rather than in an
+    /// isolated environment with their declared build dependencies.
+    pub no_build_isolation_package: Option<Vec<PackageName>>,
 }
 
 #[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
@@ -300,6 +325,8 @@ pub enum DependencyType {
     Production,
     /// A dependency in `tool.uv.dev-dependencies`.
     Dev,
+    /// A dependency in `tool.uv.dev-scripts`.
+    DevScript,
     /// A dependency in `project.optional-dependencies.{0}`.
     Optional(ExtraName),
 }
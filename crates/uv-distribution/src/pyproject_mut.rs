@@ -26,6 +26,12 @@ pub enum Error {
     MalformedDependencies,
     #[error("Sources in `pyproject.toml` are malformed")]
     MalformedSources,
+    #[error("Workspace members in `pyproject.toml` are malformed")]
+    MalformedWorkspace,
+    #[error("`tool.uv.no-build-isolation-package` in `pyproject.toml` is malformed")]
+    MalformedBuildIsolation,
+    #[error("`tool.uv.extra-index-url` in `pyproject.toml` is malformed")]
+    MalformedIndexes,
     #[error("Cannot perform ambiguous update; multiple entries with matching package names.")]
     Ambiguous,
 }
@@ -72,8 +78,27 @@ impl PyProjectTomlMut {
         req: Requirement,
         source: Option<Source>,
     ) -> Result<(), Error> {
-        // Get or create `tool.uv.dev-dependencies`.
-        let dev_dependencies = self
+        self.add_tool_uv_dependency("dev-dependencies", req, source)
+    }
+
+    /// Adds a development script to `tool.uv.dev-scripts`.
+    pub fn add_dev_script(
+        &mut self,
+        req: Requirement,
+        source: Option<Source>,
+    ) -> Result<(), Error> {
+        self.add_tool_uv_dependency("dev-scripts", req, source)
+    }
+
+    /// Adds a dependency to the given array in the `tool.uv` table (e.g., `dev-dependencies`).
+    fn add_tool_uv_dependency(
+        &mut self,
+        key: &str,
+        req: Requirement,
+        source: Option<Source>,
+    ) -> Result<(), Error> {
+        // Get or create `tool.uv.<key>`.
+        let dependencies = self
             .doc
             .entry("tool")
             .or_insert(implicit())
@@ -83,13 +108,13 @@ impl PyProjectTomlMut {
             .or_insert(Item::Table(Table::new()))
             .as_table_mut()
             .ok_or(Error::MalformedSources)?
-            .entry("dev-dependencies")
+            .entry(key)
             .or_insert(Item::Value(Value::Array(Array::new())))
             .as_array_mut()
             .ok_or(Error::MalformedDependencies)?;
 
         let name = req.name.clone();
-        add_dependency(req, dev_dependencies, source.is_some())?;
+        add_dependency(req, dependencies, source.is_some())?;
 
         if let Some(source) = source {
             self.add_source(&name, &source)?;
@@ -98,6 +123,91 @@ impl PyProjectTomlMut {
         Ok(())
     }
 
+    /// Adds a member to `tool.uv.workspace.members`, if it isn't already present.
+    pub fn add_workspace_member(&mut self, path: &str) -> Result<(), Error> {
+        // Get or create `tool.uv.workspace.members`.
+        let members = self
+            .doc
+            .entry("tool")
+            .or_insert(implicit())
+            .as_table_mut()
+            .ok_or(Error::MalformedWorkspace)?
+            .entry("uv")
+            .or_insert(implicit())
+            .as_table_mut()
+            .ok_or(Error::MalformedWorkspace)?
+            .entry("workspace")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_mut()
+            .ok_or(Error::MalformedWorkspace)?
+            .entry("members")
+            .or_insert(Item::Value(Value::Array(Array::new())))
+            .as_array_mut()
+            .ok_or(Error::MalformedWorkspace)?;
+
+        if members.iter().all(|member| member.as_str() != Some(path)) {
+            members.push(path);
+            reformat_array_multiline(members);
+        }
+
+        Ok(())
+    }
+
+    /// Adds a package to `tool.uv.no-build-isolation-package`, if it isn't already present.
+    pub fn add_no_build_isolation_package(&mut self, name: &PackageName) -> Result<(), Error> {
+        // Get or create `tool.uv.no-build-isolation-package`.
+        let packages = self
+            .doc
+            .entry("tool")
+            .or_insert(implicit())
+            .as_table_mut()
+            .ok_or(Error::MalformedBuildIsolation)?
+            .entry("uv")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_mut()
+            .ok_or(Error::MalformedBuildIsolation)?
+            .entry("no-build-isolation-package")
+            .or_insert(Item::Value(Value::Array(Array::new())))
+            .as_array_mut()
+            .ok_or(Error::MalformedBuildIsolation)?;
+
+        if packages
+            .iter()
+            .filter_map(Value::as_str)
+            .filter_map(|package| PackageName::from_str(package).ok())
+            .all(|package| package != *name)
+        {
+            packages.push(name.to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Adds an index to `tool.uv.extra-index-url`, if it isn't already present.
+    pub fn add_extra_index_url(&mut self, url: &str) -> Result<(), Error> {
+        // Get or create `tool.uv.extra-index-url`.
+        let urls = self
+            .doc
+            .entry("tool")
+            .or_insert(implicit())
+            .as_table_mut()
+            .ok_or(Error::MalformedIndexes)?
+            .entry("uv")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_mut()
+            .ok_or(Error::MalformedIndexes)?
+            .entry("extra-index-url")
+            .or_insert(Item::Value(Value::Array(Array::new())))
+            .as_array_mut()
+            .ok_or(Error::MalformedIndexes)?;
+
+        if urls.iter().all(|existing| existing.as_str() != Some(url)) {
+            urls.push(url);
+        }
+
+        Ok(())
+    }
+
     /// Adds a dependency to `project.optional-dependencies`.
     pub fn add_optional_dependency(
         &mut self,
@@ -178,8 +288,23 @@ impl PyProjectTomlMut {
 
     /// Removes all occurrences of development dependencies with the given name.
     pub fn remove_dev_dependency(&mut self, req: &PackageName) -> Result<Vec<Requirement>, Error> {
-        // Try to get `tool.uv.dev-dependencies`.
-        let Some(dev_dependencies) = self
+        self.remove_tool_uv_dependency("dev-dependencies", req)
+    }
+
+    /// Removes all occurrences of development scripts with the given name.
+    pub fn remove_dev_script(&mut self, req: &PackageName) -> Result<Vec<Requirement>, Error> {
+        self.remove_tool_uv_dependency("dev-scripts", req)
+    }
+
+    /// Removes all occurrences of dependencies with the given name from the given array in the
+    /// `tool.uv` table (e.g., `dev-dependencies`).
+    fn remove_tool_uv_dependency(
+        &mut self,
+        key: &str,
+        req: &PackageName,
+    ) -> Result<Vec<Requirement>, Error> {
+        // Try to get `tool.uv.<key>`.
+        let Some(dependencies) = self
             .doc
             .get_mut("tool")
             .map(|tool| tool.as_table_mut().ok_or(Error::MalformedSources))
@@ -187,14 +312,14 @@ impl PyProjectTomlMut {
             .and_then(|tool| tool.get_mut("uv"))
             .map(|tool_uv| tool_uv.as_table_mut().ok_or(Error::MalformedSources))
             .transpose()?
-            .and_then(|tool_uv| tool_uv.get_mut("dev-dependencies"))
+            .and_then(|tool_uv| tool_uv.get_mut(key))
             .map(|dependencies| dependencies.as_array_mut().ok_or(Error::MalformedSources))
             .transpose()?
         else {
             return Ok(Vec::new());
         };
 
-        let requirements = remove_dependency(req, dev_dependencies);
+        let requirements = remove_dependency(req, dependencies);
         self.remove_source(req)?;
 
         Ok(requirements)
@@ -251,8 +376,8 @@ impl PyProjectTomlMut {
     /// Returns all the places in this `pyproject.toml` that contain a dependency with the given
     /// name.
     ///
-    /// This method searches `project.dependencies`, `tool.uv.dev-dependencies`, and
-    /// `tool.uv.optional-dependencies`.
+    /// This method searches `project.dependencies`, `tool.uv.dev-dependencies`,
+    /// `tool.uv.dev-scripts`, and `tool.uv.optional-dependencies`.
     pub fn find_dependency(&self, name: &PackageName) -> Vec<DependencyType> {
         let mut types = Vec::new();
 
@@ -284,18 +409,25 @@ impl PyProjectTomlMut {
             }
         }
 
-        // Check `tool.uv.dev-dependencies`.
-        if let Some(dev_dependencies) = self
+        // Check `tool.uv.dev-dependencies` and `tool.uv.dev-scripts`.
+        if let Some(tool_uv) = self
             .doc
             .get("tool")
             .and_then(Item::as_table)
             .and_then(|tool| tool.get("uv"))
             .and_then(Item::as_table)
-            .and_then(|tool| tool.get("dev-dependencies"))
-            .and_then(Item::as_array)
         {
-            if !find_dependencies(name, dev_dependencies).is_empty() {
-                types.push(DependencyType::Dev);
+            if let Some(dev_dependencies) = tool_uv.get("dev-dependencies").and_then(Item::as_array)
+            {
+                if !find_dependencies(name, dev_dependencies).is_empty() {
+                    types.push(DependencyType::Dev);
+                }
+            }
+
+            if let Some(dev_scripts) = tool_uv.get("dev-scripts").and_then(Item::as_array) {
+                if !find_dependencies(name, dev_scripts).is_empty() {
+                    types.push(DependencyType::DevScript);
+                }
             }
         }
 
@@ -242,6 +242,28 @@ impl Workspace {
             .collect()
     }
 
+    /// Returns the packages to build without build isolation, as declared in the
+    /// `tool.uv.no-build-isolation-package` of any workspace member.
+    pub fn no_build_isolation_packages(&self) -> Vec<PackageName> {
+        let mut packages = self
+            .packages
+            .values()
+            .filter_map(|workspace_package| {
+                workspace_package
+                    .pyproject_toml()
+                    .tool
+                    .as_ref()
+                    .and_then(|tool| tool.uv.as_ref())
+                    .and_then(|uv| uv.no_build_isolation_package.as_ref())
+            })
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>();
+        packages.sort_unstable();
+        packages.dedup();
+        packages
+    }
+
     /// The path to the workspace root, the directory containing the top level `pyproject.toml` with
     /// the `uv.tool.workspace`, or the `pyproject.toml` in an implicit single workspace project.
     pub fn root(&self) -> &PathBuf {
@@ -1037,8 +1059,14 @@ mod tests {
                 "root": "[ROOT]/albatross-in-example/examples/bird-feeder",
                 "project": {
                   "name": "bird-feeder",
+                  "version": "1.0.0",
                   "requires-python": ">=3.12",
-                  "optional-dependencies": null
+                  "dependencies": [
+                    "anyio>=4.3.0,<5"
+                  ],
+                  "optional-dependencies": null,
+                  "classifiers": null,
+                  "scripts": null
                 },
                 "pyproject_toml": "[PYPROJECT_TOML]"
               }
@@ -1072,8 +1100,14 @@ mod tests {
                     "root": "[ROOT]/albatross-project-in-excluded/excluded/bird-feeder",
                     "project": {
                       "name": "bird-feeder",
+                      "version": "1.0.0",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "anyio>=4.3.0,<5"
+                      ],
+                      "optional-dependencies": null,
+                      "classifiers": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   }
@@ -1106,8 +1140,15 @@ mod tests {
                     "root": "[ROOT]/albatross-root-workspace",
                     "project": {
                       "name": "albatross",
+                      "version": "0.1.0",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "bird-feeder",
+                        "tqdm>=4,<5"
+                      ],
+                      "optional-dependencies": null,
+                      "classifiers": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   },
@@ -1115,8 +1156,15 @@ mod tests {
                     "root": "[ROOT]/albatross-root-workspace/packages/bird-feeder",
                     "project": {
                       "name": "bird-feeder",
+                      "version": "1.0.0",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "anyio>=4.3.0,<5",
+                        "seeds"
+                      ],
+                      "optional-dependencies": null,
+                      "classifiers": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   },
@@ -1124,8 +1172,14 @@ mod tests {
                     "root": "[ROOT]/albatross-root-workspace/packages/seeds",
                     "project": {
                       "name": "seeds",
+                      "version": "1.0.0",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "idna==3.6"
+                      ],
+                      "optional-dependencies": null,
+                      "classifiers": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   }
@@ -1164,8 +1218,15 @@ mod tests {
                     "root": "[ROOT]/albatross-virtual-workspace/packages/albatross",
                     "project": {
                       "name": "albatross",
+                      "version": "0.1.0",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "bird-feeder",
+                        "tqdm>=4,<5"
+                      ],
+                      "optional-dependencies": null,
+                      "classifiers": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   },
@@ -1173,8 +1234,15 @@ mod tests {
                     "root": "[ROOT]/albatross-virtual-workspace/packages/bird-feeder",
                     "project": {
                       "name": "bird-feeder",
+                      "version": "1.0.0",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "anyio>=4.3.0,<5",
+                        "seeds"
+                      ],
+                      "optional-dependencies": null,
+                      "classifiers": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   },
@@ -1182,8 +1250,14 @@ mod tests {
                     "root": "[ROOT]/albatross-virtual-workspace/packages/seeds",
                     "project": {
                       "name": "seeds",
+                      "version": "1.0.0",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "idna==3.6"
+                      ],
+                      "optional-dependencies": null,
+                      "classifiers": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   }
@@ -1216,8 +1290,14 @@ mod tests {
                     "root": "[ROOT]/albatross-just-project",
                     "project": {
                       "name": "albatross",
+                      "version": "0.1.0",
                       "requires-python": ">=3.12",
-                      "optional-dependencies": null
+                      "dependencies": [
+                        "tqdm>=4,<5"
+                      ],
+                      "optional-dependencies": null,
+                      "classifiers": null,
+                      "scripts": null
                     },
                     "pyproject_toml": "[PYPROJECT_TOML]"
                   }
@@ -573,6 +573,35 @@ pub(crate) fn fetch(
     }
 }
 
+/// Returns the names of the tags in the remote repository at `url` that match any of the given
+/// `names`, without fetching the repository.
+pub fn remote_tags(url: &Url, names: &[String]) -> Result<Vec<String>> {
+    let output = ProcessBuilder::new("git")
+        .arg("ls-remote")
+        .arg("--tags")
+        .arg(url.as_str())
+        .args(
+            &names
+                .iter()
+                .map(|name| format!("refs/tags/{name}"))
+                .collect::<Vec<_>>(),
+        )
+        .env_remove("GIT_DIR")
+        .exec_with_output()?;
+
+    // Each line is of the form `<sha>\trefs/tags/<name>`; annotated tags are listed again with a
+    // `^{}` suffix.
+    let stdout = String::from_utf8(output.stdout)?;
+    let mut tags = stdout
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .filter_map(|(_, refname)| refname.strip_prefix("refs/tags/"))
+        .map(|name| name.strip_suffix("^{}").unwrap_or(name).to_string())
+        .collect::<Vec<_>>();
+    tags.dedup();
+    Ok(tags)
+}
+
 /// Attempts to use `git` CLI installed on the system to fetch a repository,.
 fn fetch_with_cli(
     repo: &mut GitRepository,
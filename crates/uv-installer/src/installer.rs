@@ -51,18 +51,15 @@ impl<'a> Installer<'a> {
     #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
     pub fn install(self, wheels: &[CachedDist]) -> Result<()> {
         let layout = self.venv.interpreter().layout();
+        let relocatable = self.venv.relocatable();
         tokio::task::block_in_place(|| {
             wheels.par_iter().try_for_each(|wheel| {
                 install_wheel_rs::linker::install_wheel(
                     &layout,
+                    relocatable,
                     wheel.path(),
                     wheel.filename(),
-                    wheel
-                        .parsed_url()?
-                        .as_ref()
-                        .map(pypi_types::DirectUrl::try_from)
-                        .transpose()?
-                        .as_ref(),
+                    wheel.direct_url()?.as_ref(),
                     self.installer_name.as_deref(),
                     self.link_mode,
                 )
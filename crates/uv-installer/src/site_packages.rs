@@ -37,6 +37,16 @@ pub struct SitePackages {
 }
 
 impl SitePackages {
+    /// Create an empty index for the given environment, as if no packages were installed.
+    pub fn empty(venv: &PythonEnvironment) -> SitePackages {
+        Self {
+            venv: venv.clone(),
+            distributions: Vec::new(),
+            by_name: FxHashMap::default(),
+            by_url: FxHashMap::default(),
+        }
+    }
+
     /// Build an index of installed packages from the given Python executable.
     pub fn from_environment(venv: &PythonEnvironment) -> Result<SitePackages> {
         let mut distributions: Vec<Option<InstalledDist>> = Vec::new();
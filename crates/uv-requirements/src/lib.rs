@@ -1,3 +1,4 @@
+pub use crate::confirm::*;
 pub use crate::lookahead::*;
 pub use crate::source_tree::*;
 pub use crate::sources::*;
@@ -2,12 +2,13 @@ use std::path::Path;
 
 use anstream::eprint;
 use anyhow::Result;
+use rustc_hash::FxHashMap;
 
 use requirements_txt::RequirementsTxt;
 use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::Upgrade;
-use uv_distribution::Workspace;
 use uv_git::ResolvedRepositoryReference;
+use uv_normalize::PackageName;
 use uv_resolver::{Lock, Preference, PreferenceError};
 
 #[derive(Debug, Default)]
@@ -16,6 +17,9 @@ pub struct LockedRequirements {
     pub preferences: Vec<Preference>,
     /// The pinned Git SHAs from the lockfile.
     pub git: Vec<ResolvedRepositoryReference>,
+    /// The pinned versions of the registry distributions in the lockfile, omitting any package
+    /// that's locked at more than one version (e.g., across platforms).
+    pub registry: Vec<Preference>,
 }
 
 /// Load the preferred requirements from an existing `requirements.txt`, applying the upgrade strategy.
@@ -63,50 +67,70 @@ pub async fn read_requirements_txt(
     })
 }
 
-/// Load the preferred requirements from an existing lockfile, applying the upgrade strategy.
-pub async fn read_lockfile(workspace: &Workspace, upgrade: &Upgrade) -> Result<LockedRequirements> {
-    // As an optimization, skip reading the lockfile is we're upgrading all packages anyway.
-    if upgrade.is_all() {
-        return Ok(LockedRequirements::default());
-    }
-
-    // If an existing lockfile exists, build up a set of preferences.
-    let lockfile = workspace.root().join("uv.lock");
-    let lock = match fs_err::tokio::read_to_string(&lockfile).await {
+/// Read and parse the existing lockfile, if any.
+///
+/// A lockfile that can't be parsed is ignored, with a warning.
+pub async fn read_lock(lockfile: &Path) -> Result<Option<Lock>> {
+    match fs_err::tokio::read_to_string(lockfile).await {
         Ok(encoded) => match toml::from_str::<Lock>(&encoded) {
-            Ok(lock) => lock,
+            Ok(lock) => Ok(Some(lock)),
             Err(err) => {
                 eprint!("Failed to parse lockfile; ignoring locked requirements: {err}");
-                return Ok(LockedRequirements::default());
+                Ok(None)
             }
         },
-        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-            return Ok(LockedRequirements::default());
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+impl LockedRequirements {
+    /// Load the preferred requirements from an existing lockfile, applying the upgrade strategy.
+    pub fn from_lock(lock: &Lock, upgrade: &Upgrade) -> Self {
+        // As an optimization, skip the lockfile if we're upgrading all packages anyway.
+        if upgrade.is_all() {
+            return Self::default();
         }
-        Err(err) => return Err(err.into()),
-    };
 
-    let mut preferences = Vec::new();
-    let mut git = Vec::new();
-
-    for dist in lock.distributions() {
-        // Skip the distribution if it's not included in the upgrade strategy.
-        if match upgrade {
-            Upgrade::None => false,
-            Upgrade::All => true,
-            Upgrade::Packages(packages) => packages.contains(dist.name()),
-        } {
-            continue;
+        let mut preferences = Vec::new();
+        let mut git = Vec::new();
+        let mut registry = Vec::new();
+
+        // Count the number of distributions for each package, to identify packages that are locked
+        // at more than one version.
+        let mut counts = FxHashMap::<&PackageName, usize>::default();
+        for dist in lock.distributions() {
+            *counts.entry(dist.name()).or_default() += 1;
         }
 
-        // Map each entry in the lockfile to a preference.
-        preferences.push(Preference::from_lock(dist));
+        for dist in lock.distributions() {
+            // Skip the distribution if it's not included in the upgrade strategy.
+            if match upgrade {
+                Upgrade::None => false,
+                Upgrade::All => true,
+                Upgrade::Packages(packages) => packages.contains(dist.name()),
+            } {
+                continue;
+            }
+
+            // Map each entry in the lockfile to a preference.
+            preferences.push(Preference::from_lock(dist));
+
+            // Map each entry in the lockfile to a Git SHA.
+            if let Some(git_ref) = dist.as_git_ref() {
+                git.push(git_ref);
+            }
 
-        // Map each entry in the lockfile to a Git SHA.
-        if let Some(git_ref) = dist.as_git_ref() {
-            git.push(git_ref);
+            // Map each unique registry entry in the lockfile to a pinned version.
+            if dist.is_registry() && counts[dist.name()] == 1 {
+                registry.push(Preference::from_lock(dist));
+            }
         }
-    }
 
-    Ok(LockedRequirements { preferences, git })
+        Self {
+            preferences,
+            git,
+            registry,
+        }
+    }
 }
@@ -22,6 +22,8 @@ pub(crate) struct CandidateSelector {
     resolution_strategy: ResolutionStrategy,
     prerelease_strategy: PreReleaseStrategy,
     index_strategy: IndexStrategy,
+    prefer_binary: bool,
+    prefer_sdist: bool,
 }
 
 impl CandidateSelector {
@@ -45,6 +47,8 @@ impl CandidateSelector {
                 options.dependency_mode,
             ),
             index_strategy: options.index_strategy,
+            prefer_binary: options.prefer_binary,
+            prefer_sdist: options.prefer_sdist,
         }
     }
 
@@ -89,18 +93,21 @@ impl CandidateSelector {
         installed_packages: &'a InstalledPackages,
         exclusions: &'a Exclusions,
     ) -> Option<Candidate<'a>> {
-        if let Some(preferred) = Self::get_preferred(
+        let candidate = Self::get_preferred(
             package_name,
             range,
             version_maps,
             preferences,
             installed_packages,
             exclusions,
-        ) {
-            return Some(preferred);
-        }
+        )
+        .or_else(|| self.select_no_preference(package_name, range, version_maps))?;
 
-        self.select_no_preference(package_name, range, version_maps)
+        if self.prefer_sdist {
+            Some(candidate.prefer_source())
+        } else {
+            Some(candidate)
+        }
     }
 
     /// Get a preferred version if one exists. This is the preference from a lockfile or a locally
@@ -218,6 +225,7 @@ impl CandidateSelector {
         );
         let highest = self.use_highest_version(package_name);
         let allow_prerelease = self.allow_prereleases(package_name);
+        let prefer_binary = self.prefer_binary;
 
         if self.index_strategy == IndexStrategy::UnsafeBestMatch {
             if highest {
@@ -229,6 +237,7 @@ impl CandidateSelector {
                     package_name,
                     range,
                     allow_prerelease,
+                    prefer_binary,
                 )
             } else {
                 Self::select_candidate(
@@ -239,6 +248,7 @@ impl CandidateSelector {
                     package_name,
                     range,
                     allow_prerelease,
+                    prefer_binary,
                 )
             }
         } else {
@@ -249,6 +259,7 @@ impl CandidateSelector {
                         package_name,
                         range,
                         allow_prerelease,
+                        prefer_binary,
                     )
                 })
             } else {
@@ -258,6 +269,7 @@ impl CandidateSelector {
                         package_name,
                         range,
                         allow_prerelease,
+                        prefer_binary,
                     )
                 })
             }
@@ -278,11 +290,16 @@ impl CandidateSelector {
 
     /// Select the first-matching [`Candidate`] from a set of candidate versions and files,
     /// preferring wheels over source distributions.
+    ///
+    /// If `prefer_binary` is set, versions that can only be built from source are skipped in
+    /// favor of the first version with a compatible wheel, falling back to the first source-only
+    /// version if no such wheel exists.
     fn select_candidate<'a>(
         versions: impl Iterator<Item = (&'a Version, VersionMapDistHandle<'a>)>,
         package_name: &'a PackageName,
         range: &Range<Version>,
         allow_prerelease: AllowPreRelease,
+        prefer_binary: bool,
     ) -> Option<Candidate<'a>> {
         #[derive(Debug)]
         enum PreReleaseCandidate<'a> {
@@ -291,6 +308,7 @@ impl CandidateSelector {
         }
 
         let mut prerelease = None;
+        let mut source_only = None;
         let mut steps = 0usize;
         for (version, maybe_dist) in versions {
             steps += 1;
@@ -372,6 +390,31 @@ impl CandidateSelector {
                 continue;
             }
 
+            // If binaries are preferred, skip over versions that would need to be built from
+            // source, retaining the first such version as a fallback.
+            if prefer_binary
+                && matches!(
+                    candidate.dist(),
+                    CandidateDist::Compatible(
+                        CompatibleDist::SourceDist { .. }
+                            | CompatibleDist::IncompatibleWheel { .. }
+                    )
+                )
+            {
+                tracing::trace!(
+                    "skipping source-only candidate for package {:?}: {:?} version",
+                    package_name,
+                    version,
+                );
+                if source_only.is_none() {
+                    source_only = Some(candidate);
+                }
+                continue;
+            }
+
+            return Some(candidate);
+        }
+        if let Some(candidate) = source_only {
             return Some(candidate);
         }
         tracing::trace!(
@@ -437,6 +480,19 @@ impl<'a> Candidate<'a> {
         }
     }
 
+    /// Build the candidate from its source distribution, if one is compatible, rather than from
+    /// a compatible wheel.
+    fn prefer_source(mut self) -> Self {
+        if let CandidateDist::Compatible(CompatibleDist::CompatibleWheel { prioritized, .. }) =
+            self.dist
+        {
+            if let Some(dist) = prioritized.get_source() {
+                self.dist = CandidateDist::Compatible(dist);
+            }
+        }
+        self
+    }
+
     /// Return the name of the package.
     pub(crate) fn name(&self) -> &PackageName {
         self.name
@@ -357,4 +357,43 @@ impl NoSolutionError {
         self.python_requirement = Some(python_requirement.clone());
         self
     }
+
+    /// Return the names of the packages with conflicting requirements, i.e., those that are
+    /// required by more than one package in the derivation tree.
+    pub fn conflicting_packages(&self) -> BTreeSet<PackageName> {
+        let mut dependents = FxHashMap::default();
+        collect_dependents(&self.derivation_tree, &mut dependents);
+        dependents
+            .into_iter()
+            .filter(|(_, dependents)| dependents.len() > 1)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+}
+
+/// Collect the packages that depend on each package in the given [`DerivationTree`], keyed by the
+/// name of the dependency.
+///
+/// The root package of an unnamed resolution is represented by `None`.
+fn collect_dependents<'a>(
+    derivation_tree: &'a DerivationTree<PubGrubPackage, Range<Version>, UnavailableReason>,
+    dependents: &mut FxHashMap<&'a PackageName, FxHashSet<Option<&'a PackageName>>>,
+) {
+    match derivation_tree {
+        DerivationTree::External(External::FromDependencyOf(package, _, dependency, _)) => {
+            let Some(name) = dependency.name_no_root() else {
+                return;
+            };
+            // Ignore dependencies on a package's own extras, markers, and development groups.
+            if package.name() == Some(name) {
+                return;
+            }
+            dependents.entry(name).or_default().insert(package.name());
+        }
+        DerivationTree::External(_) => {}
+        DerivationTree::Derived(derived) => {
+            collect_dependents(&derived.cause1, dependents);
+            collect_dependents(&derived.cause2, dependents);
+        }
+    }
 }
@@ -29,7 +29,7 @@ use uv_git::{GitReference, GitSha, RepositoryReference, ResolvedRepositoryRefere
 use uv_normalize::{ExtraName, GroupName, PackageName};
 
 use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
-use crate::{RequiresPython, ResolutionGraph};
+use crate::{AnnotationStyle, RequiresPython, ResolutionGraph};
 
 /// The current version of the lock file format.
 const VERSION: u32 = 1;
@@ -41,6 +41,11 @@ pub struct Lock {
     distributions: Vec<Distribution>,
     /// The range of supported Python versions.
     requires_python: Option<RequiresPython>,
+    /// The style of the comments that annotate each distribution with its dependents, if any.
+    annotation_style: Option<AnnotationStyle>,
+    /// Whether the optional dependencies of each distribution were collapsed into its base
+    /// dependencies.
+    extras_stripped: bool,
     /// A map from distribution ID to index in `distributions`.
     ///
     /// This can be used to quickly lookup the full distribution for any ID
@@ -134,6 +139,38 @@ impl Lock {
         Ok(lock)
     }
 
+    /// Collapse the optional dependencies of each distribution into its base dependencies, such
+    /// that the lock no longer distinguishes between extras.
+    ///
+    /// The lock records that its extras were stripped, such that subsequent locks can do the same.
+    pub fn strip_extras(self) -> Result<Self, LockError> {
+        let distributions = self
+            .distributions
+            .into_iter()
+            .map(|mut dist| {
+                let optional_dependencies = std::mem::take(&mut dist.optional_dependencies);
+                dist.dependencies
+                    .extend(optional_dependencies.into_values().flatten());
+
+                // Dependencies on extras are now satisfied by the base distribution.
+                for dependencies in std::iter::once(&mut dist.dependencies)
+                    .chain(dist.dev_dependencies.values_mut())
+                {
+                    for dependency in dependencies.iter_mut() {
+                        dependency.extra = None;
+                    }
+                    dependencies.sort();
+                    dependencies.dedup();
+                }
+
+                dist
+            })
+            .collect();
+        let mut lock = Self::new(self.version, distributions, self.requires_python)?;
+        lock.extras_stripped = true;
+        Ok(lock.with_annotation_style(self.annotation_style))
+    }
+
     /// Initialize a [`Lock`] from a list of [`Distribution`] entries.
     fn new(
         version: u32,
@@ -301,10 +338,20 @@ impl Lock {
             version,
             distributions,
             requires_python,
+            annotation_style: None,
+            extras_stripped: false,
             by_id,
         })
     }
 
+    /// Set the style of the comments that annotate each distribution with its dependents, or
+    /// `None` to omit the annotations.
+    #[must_use]
+    pub fn with_annotation_style(mut self, annotation_style: Option<AnnotationStyle>) -> Self {
+        self.annotation_style = annotation_style;
+        self
+    }
+
     /// Returns the [`Distribution`] entries in this lock.
     pub fn distributions(&self) -> &[Distribution] {
         &self.distributions
@@ -315,6 +362,17 @@ impl Lock {
         self.requires_python.as_ref()
     }
 
+    /// Returns the style of the comments that annotate each distribution, if present.
+    pub fn annotation_style(&self) -> Option<AnnotationStyle> {
+        self.annotation_style
+    }
+
+    /// Returns `true` if the optional dependencies of each distribution were collapsed into its
+    /// base dependencies.
+    pub fn extras_stripped(&self) -> bool {
+        self.extras_stripped
+    }
+
     /// Convert the [`Lock`] to a [`Resolution`] using the given marker environment, tags, and root.
     pub fn to_resolution(
         &self,
@@ -398,6 +456,18 @@ impl Lock {
             doc.insert("requires-python", value(requires_python.to_string()));
         }
 
+        if let Some(annotation_style) = self.annotation_style {
+            let annotation_style = match annotation_style {
+                AnnotationStyle::Line => "line",
+                AnnotationStyle::Split => "split",
+            };
+            doc.insert("annotation-style", value(annotation_style));
+        }
+
+        if self.extras_stripped {
+            doc.insert("strip-extras", value(true));
+        }
+
         // Count the number of distributions for each package name. When
         // there's only one distribution for a particular package name (the
         // overwhelmingly common case), we can omit some data (like source and
@@ -407,9 +477,57 @@ impl Lock {
             *dist_count_by_name.entry(dist.id.name.clone()).or_default() += 1;
         }
 
+        // Collect the dependents of each distribution, for annotations.
+        let mut dependents_by_id: FxHashMap<&DistributionId, Vec<String>> = FxHashMap::default();
+        if self.annotation_style.is_some() {
+            for dist in &self.distributions {
+                let name = &dist.id.name;
+                for dep in &dist.dependencies {
+                    dependents_by_id
+                        .entry(&dep.distribution_id)
+                        .or_default()
+                        .push(name.to_string());
+                }
+                for (extra, deps) in &dist.optional_dependencies {
+                    for dep in deps {
+                        dependents_by_id
+                            .entry(&dep.distribution_id)
+                            .or_default()
+                            .push(format!("{name}[{extra}]"));
+                    }
+                }
+                for (group, deps) in &dist.dev_dependencies {
+                    for dep in deps {
+                        dependents_by_id
+                            .entry(&dep.distribution_id)
+                            .or_default()
+                            .push(format!("{name} (group: {group})"));
+                    }
+                }
+            }
+        }
+
         let mut distributions = ArrayOfTables::new();
         for dist in &self.distributions {
-            distributions.push(dist.to_toml(&dist_count_by_name)?);
+            let mut table = dist.to_toml(&dist_count_by_name)?;
+            if let (Some(annotation_style), Some(dependents)) =
+                (self.annotation_style, dependents_by_id.get_mut(&dist.id))
+            {
+                dependents.sort_unstable();
+                dependents.dedup();
+                let annotation = match annotation_style {
+                    AnnotationStyle::Line => format!("# via {}\n", dependents.join(", ")),
+                    AnnotationStyle::Split => std::iter::once("# via\n".to_string())
+                        .chain(
+                            dependents
+                                .iter()
+                                .map(|dependent| format!("#   {dependent}\n")),
+                        )
+                        .collect(),
+                };
+                table.decor_mut().set_prefix(format!("\n{annotation}"));
+            }
+            distributions.push(table);
         }
 
         doc.insert("distribution", Item::ArrayOfTables(distributions));
@@ -449,6 +567,10 @@ struct LockWire {
     distributions: Vec<DistributionWire>,
     #[serde(rename = "requires-python")]
     requires_python: Option<RequiresPython>,
+    #[serde(rename = "annotation-style")]
+    annotation_style: Option<AnnotationStyle>,
+    #[serde(rename = "strip-extras", default)]
+    strip_extras: bool,
 }
 
 impl From<Lock> for LockWire {
@@ -461,6 +583,8 @@ impl From<Lock> for LockWire {
                 .map(DistributionWire::from)
                 .collect(),
             requires_python: lock.requires_python,
+            annotation_style: lock.annotation_style,
+            strip_extras: lock.extras_stripped,
         }
     }
 }
@@ -491,7 +615,9 @@ impl TryFrom<LockWire> for Lock {
             .into_iter()
             .map(|dist| dist.unwire(&unambiguous_dist_ids))
             .collect::<Result<Vec<_>, _>>()?;
-        Lock::new(wire.version, distributions, wire.requires_python)
+        let mut lock = Lock::new(wire.version, distributions, wire.requires_python)?;
+        lock.extras_stripped = wire.strip_extras;
+        Ok(lock.with_annotation_style(wire.annotation_style))
     }
 }
 
@@ -821,6 +947,16 @@ impl Distribution {
         &self.id.name
     }
 
+    /// Returns the [`Version`] of the distribution.
+    pub fn version(&self) -> &Version {
+        &self.id.version
+    }
+
+    /// Returns `true` if the distribution was resolved from a registry.
+    pub fn is_registry(&self) -> bool {
+        matches!(self.id.source, Source::Registry(..))
+    }
+
     /// Returns the [`ResolvedRepositoryReference`] for the distribution, if it is a Git source.
     pub fn as_git_ref(&self) -> Option<ResolvedRepositoryReference> {
         match &self.id.source {
@@ -10,6 +10,8 @@ pub struct Options {
     pub dependency_mode: DependencyMode,
     pub exclude_newer: Option<ExcludeNewer>,
     pub index_strategy: IndexStrategy,
+    pub prefer_binary: bool,
+    pub prefer_sdist: bool,
 }
 
 /// Builder for [`Options`].
@@ -20,6 +22,8 @@ pub struct OptionsBuilder {
     dependency_mode: DependencyMode,
     exclude_newer: Option<ExcludeNewer>,
     index_strategy: IndexStrategy,
+    prefer_binary: bool,
+    prefer_sdist: bool,
 }
 
 impl OptionsBuilder {
@@ -63,6 +67,21 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets whether to prefer versions with compatible wheels over newer versions that would
+    /// need to be built from source.
+    #[must_use]
+    pub fn prefer_binary(mut self, prefer_binary: bool) -> Self {
+        self.prefer_binary = prefer_binary;
+        self
+    }
+
+    /// Sets whether to prefer source distributions over wheels when both are available.
+    #[must_use]
+    pub fn prefer_sdist(mut self, prefer_sdist: bool) -> Self {
+        self.prefer_sdist = prefer_sdist;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
@@ -71,6 +90,8 @@ impl OptionsBuilder {
             dependency_mode: self.dependency_mode,
             exclude_newer: self.exclude_newer,
             index_strategy: self.index_strategy,
+            prefer_binary: self.prefer_binary,
+            prefer_sdist: self.prefer_sdist,
         }
     }
 }
@@ -290,7 +290,7 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
 
 /// Indicate the style of annotation comments, used to indicate the dependencies that requested each
 /// package.
-#[derive(Debug, Default, Copy, Clone, PartialEq, serde::Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, serde::Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
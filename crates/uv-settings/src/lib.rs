@@ -33,24 +33,32 @@ impl Deref for FilesystemOptions {
 
 impl FilesystemOptions {
     /// Load the user [`FilesystemOptions`].
+    ///
+    /// The user-level `uv.toml` is merged with a `.uvrc` file in the user's home directory, with
+    /// the `uv.toml` taking precedence.
     pub fn user() -> Result<Option<Self>, Error> {
+        let rc = match dirs_sys::home_dir() {
+            Some(home) => Self::from_rc_file(home)?,
+            None => None,
+        };
+
         let Some(dir) = config_dir() else {
-            return Ok(None);
+            return Ok(rc);
         };
         let root = dir.join("uv");
         let file = root.join("uv.toml");
 
         debug!("Loading user configuration from: `{}`", file.display());
         match read_file(&file) {
-            Ok(options) => Ok(Some(Self(options))),
-            Err(Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Ok(options) => Ok(Some(Self(options)).combine(rc)),
+            Err(Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => Ok(rc),
             Err(_) if !dir.is_dir() => {
                 // Ex) `XDG_CONFIG_HOME=/dev/null`
                 debug!(
                     "User configuration directory `{}` does not exist or is not a directory",
                     dir.display()
                 );
-                Ok(None)
+                Ok(rc)
             }
             Err(err) => Err(err),
         }
@@ -84,7 +92,18 @@ impl FilesystemOptions {
 
     /// Load a [`FilesystemOptions`] from a directory, preferring a `uv.toml` file over a
     /// `pyproject.toml` file.
+    ///
+    /// If the directory is a project root (i.e., it contains a `uv.toml` or `pyproject.toml` file)
+    /// and also contains a `.uvrc` file, the `.uvrc` is merged with the `uv.toml` or
+    /// `pyproject.toml` configuration, which takes precedence. A `.uvrc` in the home directory is
+    /// left to [`FilesystemOptions::user`].
     pub fn from_directory(dir: impl AsRef<Path>) -> Result<Option<Self>, Error> {
+        let rc = if dirs_sys::home_dir().is_some_and(|home| home == dir.as_ref()) {
+            None
+        } else {
+            Self::from_rc_file(dir.as_ref())?
+        };
+
         // Read a `uv.toml` file in the current directory.
         let path = dir.as_ref().join("uv.toml");
         match fs_err::read_to_string(&path) {
@@ -93,7 +112,7 @@ impl FilesystemOptions {
                     .map_err(|err| Error::UvToml(path.user_display().to_string(), err))?;
 
                 debug!("Found workspace configuration at `{}`", path.display());
-                return Ok(Some(Self(options)));
+                return Ok(Some(Self(options)).combine(rc));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
             Err(err) => return Err(err.into()),
@@ -111,23 +130,24 @@ impl FilesystemOptions {
                         "Skipping `pyproject.toml` in `{}` (no `[tool]` section)",
                         dir.as_ref().display()
                     );
-                    return Ok(None);
+                    return Ok(rc);
                 };
                 let Some(options) = tool.uv else {
                     debug!(
                         "Skipping `pyproject.toml` in `{}` (no `[tool.uv]` section)",
                         dir.as_ref().display()
                     );
-                    return Ok(None);
+                    return Ok(rc);
                 };
 
                 debug!("Found workspace configuration at `{}`", path.display());
-                return Ok(Some(Self(options)));
+                return Ok(Some(Self(options)).combine(rc));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
             Err(err) => return Err(err.into()),
         }
 
+        // A `.uvrc` on its own doesn't mark a project root, so continue the search.
         Ok(None)
     }
 
@@ -135,6 +155,21 @@ impl FilesystemOptions {
     pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
         Ok(Self(read_file(path.as_ref())?))
     }
+
+    /// Load a [`FilesystemOptions`] from a `.uvrc` file in the given directory, if it exists.
+    ///
+    /// A `.uvrc` file uses the same TOML schema as a `uv.toml` file.
+    fn from_rc_file(dir: impl AsRef<Path>) -> Result<Option<Self>, Error> {
+        let path = dir.as_ref().join(".uvrc");
+        match read_file(&path) {
+            Ok(options) => {
+                debug!("Found configuration at `{}`", path.display());
+                Ok(Some(Self(options)))
+            }
+            Err(Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
 }
 
 /// Returns the path to the user configuration directory.
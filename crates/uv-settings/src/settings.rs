@@ -1,4 +1,4 @@
-use std::{fmt::Debug, num::NonZeroUsize, path::PathBuf};
+use std::{collections::BTreeMap, fmt::Debug, num::NonZeroUsize, path::PathBuf};
 
 use serde::Deserialize;
 
@@ -46,6 +46,14 @@ pub struct Options {
         )
     )]
     pub override_dependencies: Option<Vec<pep508_rs::Requirement<VerbatimParsedUrl>>>,
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<BTreeMap<String, Vec<String>>>",
+            description = "Named sets of PEP 508 style constraints, e.g. `ci-strict = [\"flask==3.0.0\"]`, which can be activated with `--constraint-group`."
+        )
+    )]
+    pub constraint_groups: Option<BTreeMap<String, Vec<pep508_rs::Requirement<VerbatimParsedUrl>>>>,
 }
 
 /// Global settings, relevant to all invocations.
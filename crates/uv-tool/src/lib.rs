@@ -161,6 +161,26 @@ impl InstalledTools {
         Ok(())
     }
 
+    /// Return the [`PythonEnvironment`] for the given tool, if it exists.
+    pub fn get_environment(
+        &self,
+        name: &str,
+        cache: &Cache,
+    ) -> Result<Option<PythonEnvironment>, Error> {
+        let _lock = self.acquire_lock();
+        let environment_path = self.root.join(name);
+
+        if !environment_path.is_dir() {
+            return Ok(None);
+        }
+
+        debug!(
+            "Using existing environment for tool `{name}` at `{}`.",
+            environment_path.user_display()
+        );
+        Ok(Some(PythonEnvironment::from_root(environment_path, cache)?))
+    }
+
     pub fn environment(
         &self,
         name: &str,
@@ -191,6 +211,7 @@ impl InstalledTools {
             uv_virtualenv::Prompt::None,
             false,
             false,
+            false,
         )?;
 
         Ok(venv)
@@ -7,6 +7,7 @@ use toml_edit::value;
 use toml_edit::Array;
 use toml_edit::Table;
 use toml_edit::Value;
+use uv_configuration::EntrypointLinkMode;
 
 /// A tool entry.
 #[allow(dead_code)]
@@ -18,6 +19,8 @@ pub struct Tool {
     requirements: Vec<pep508_rs::Requirement<VerbatimParsedUrl>>,
     /// The Python requested by the user during installation.
     python: Option<String>,
+    /// The strategy requested by the user for installing the entry points.
+    entrypoint_link_mode: Option<EntrypointLinkMode>,
     // A mapping of entry point names to their metadata.
     entrypoints: Vec<ToolEntrypoint>,
 }
@@ -61,6 +64,7 @@ impl Tool {
     pub fn new(
         requirements: Vec<pep508_rs::Requirement<VerbatimParsedUrl>>,
         python: Option<String>,
+        entrypoint_link_mode: Option<EntrypointLinkMode>,
         entrypoints: impl Iterator<Item = ToolEntrypoint>,
     ) -> Self {
         let mut entrypoints: Vec<_> = entrypoints.collect();
@@ -68,6 +72,7 @@ impl Tool {
         Self {
             requirements,
             python,
+            entrypoint_link_mode,
             entrypoints,
         }
     }
@@ -93,6 +98,10 @@ impl Tool {
             table.insert("python", value(python));
         }
 
+        if let Some(entrypoint_link_mode) = self.entrypoint_link_mode {
+            table.insert("entrypoint-link-mode", value(entrypoint_link_mode.as_str()));
+        }
+
         table.insert("entrypoints", {
             let entrypoints = each_element_on_its_line_array(
                 self.entrypoints
@@ -109,6 +118,18 @@ impl Tool {
     pub fn entrypoints(&self) -> &[ToolEntrypoint] {
         &self.entrypoints
     }
+
+    pub fn requirements(&self) -> &[pep508_rs::Requirement<VerbatimParsedUrl>] {
+        &self.requirements
+    }
+
+    pub fn python(&self) -> Option<&str> {
+        self.python.as_deref()
+    }
+
+    pub fn entrypoint_link_mode(&self) -> Option<EntrypointLinkMode> {
+        self.entrypoint_link_mode
+    }
 }
 
 impl ToolEntrypoint {
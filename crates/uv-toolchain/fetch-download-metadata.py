@@ -61,7 +61,7 @@ SPECIAL_TRIPLES = {
 _filename_re = re.compile(
     r"""(?x)
     ^
-        cpython-(?P<ver>\d+\.\d+\.\d+?)
+        cpython-(?P<ver>\d+\.\d+\.\d+?(?:(?:a|b|rc)\d+)?)
         (?:\+\d+)?
         -(?P<triple>.*?)
         (?:-[\dT]+)?\.tar\.(?:gz|zst)
@@ -156,6 +156,39 @@ def sha256(path):
     return h.hexdigest()
 
 
+_version_re = re.compile(r"^(\d+)\.(\d+)\.(\d+)(?:(a|b|rc)(\d+))?$")
+
+# Pre-release kinds, in ascending order; final releases sort after all of them
+PRERELEASE_KINDS = ["a", "b", "rc"]
+
+
+def parse_version(version):
+    """
+    Parse a Python version (e.g., `3.12.3` or `3.13.0b1`) into a sortable tuple of
+    `(major, minor, patch, prerelease_kind, prerelease_number)`.
+    """
+    major, minor, patch, kind, number = _version_re.match(version).groups()
+    if kind is None:
+        return (int(major), int(minor), int(patch), len(PRERELEASE_KINDS), 0)
+    return (
+        int(major),
+        int(minor),
+        int(patch),
+        PRERELEASE_KINDS.index(kind),
+        int(number),
+    )
+
+
+def format_prerelease(version_tuple):
+    """
+    Format the pre-release segment of a parsed version, or an empty string for final releases.
+    """
+    _major, _minor, _patch, kind, number = version_tuple
+    if kind == len(PRERELEASE_KINDS):
+        return ""
+    return "%s%d" % (PRERELEASE_KINDS[kind], number)
+
+
 def _sort_by_flavor_preference(info):
     _triple, flavor, _url = info
     try:
@@ -201,7 +234,9 @@ def find():
                 results.setdefault(py_ver, []).append((triple, flavor, url))
 
     # Collapse CPython variants to a single URL flavor per triple
-    cpython_results: dict[tuple[int, int, int], dict[tuple[str, str, str], str]] = {}
+    cpython_results: dict[
+        tuple[int, int, int, int, int], dict[tuple[str, str, str], str]
+    ] = {}
     for py_ver, choices in results.items():
         urls = {}
         for triple, flavor, url in sorted(choices, key=_sort_by_flavor_preference):
@@ -210,7 +245,7 @@ def find():
             if triple in urls:
                 continue
             urls[triple] = url
-        cpython_results[tuple(map(int, py_ver.split(".")))] = urls
+        cpython_results[parse_version(py_ver)] = urls
 
     # Collect variants across interpreter kinds
     # TODO(zanieb): Note we only support CPython downloads at this time
@@ -227,9 +262,11 @@ def find():
         # Sort by the remaining information for determinism
         # This groups download metadata in triple component order
         for (arch, operating_system, libc), url in sorted(choices.items()):
-            key = "%s-%s.%s.%s-%s-%s-%s" % (
+            prerelease = format_prerelease(py_ver)
+            key = "%s-%s.%s.%s%s-%s-%s-%s" % (
                 interpreter,
-                *py_ver,
+                *py_ver[:3],
+                prerelease,
                 operating_system,
                 arch,
                 libc,
@@ -245,6 +282,7 @@ def find():
                 "major": py_ver[0],
                 "minor": py_ver[1],
                 "patch": py_ver[2],
+                "prerelease": prerelease,
                 "url": url,
                 "sha256": sha256,
             }
@@ -58,6 +58,7 @@ pub enum ToolchainRequest {
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum ToolchainPreference {
     /// Only use managed interpreters, never use system interpreters.
+    #[cfg_attr(feature = "clap", value(alias = "managed-only"))]
     OnlyManaged,
     /// Prefer installed managed interpreters, but use system interpreters if not found.
     /// If neither can be found, download a managed interpreter.
@@ -68,6 +69,7 @@ pub enum ToolchainPreference {
     /// Prefer system interpreters, only use managed interpreters if no system interpreter is found.
     PreferSystem,
     /// Only use system interpreters, never use managed interpreters.
+    #[cfg_attr(feature = "clap", value(alias = "system-only"))]
     OnlySystem,
 }
 
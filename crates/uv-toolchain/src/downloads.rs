@@ -14,9 +14,11 @@ use uv_client::BetterReqwestError;
 
 use futures::TryStreamExt;
 
+use pypi_types::{HashAlgorithm, HashDigest};
 use tokio_util::compat::FuturesAsyncReadCompatExt;
 use tracing::{debug, instrument};
 use url::Url;
+use uv_extract::hash::{HashReader, Hasher};
 use uv_fs::{rename_with_retry, Simplified};
 
 #[derive(Error, Debug)]
@@ -60,6 +62,14 @@ pub enum Error {
     // TODO(zanieb): Implement display for `PythonDownloadRequest`
     #[error("No download found for request: {0:?}")]
     NoDownloadFound(PythonDownloadRequest),
+    #[error("No checksum available to verify the download of: {0}")]
+    MissingChecksum(ToolchainKey),
+    #[error("Hash mismatch for `{key}`\n\nExpected:\n  {expected}\n\nComputed:\n  {actual}")]
+    HashMismatch {
+        key: ToolchainKey,
+        expected: String,
+        actual: String,
+    },
 }
 
 #[derive(Debug, PartialEq)]
@@ -76,6 +86,9 @@ pub struct PythonDownloadRequest {
     arch: Option<Arch>,
     os: Option<Os>,
     libc: Option<Libc>,
+    /// Whether to include pre-release Python builds (e.g., alphas, betas, and release
+    /// candidates) when selecting a download.
+    prereleases: bool,
 }
 
 impl PythonDownloadRequest {
@@ -92,6 +105,7 @@ impl PythonDownloadRequest {
             arch,
             os,
             libc,
+            prereleases: false,
         }
     }
 
@@ -125,6 +139,12 @@ impl PythonDownloadRequest {
         self
     }
 
+    #[must_use]
+    pub fn with_prereleases(mut self, prereleases: bool) -> Self {
+        self.prereleases = prereleases;
+        self
+    }
+
     /// Construct a new [`PythonDownloadRequest`] from a [`ToolchainRequest`].
     pub fn from_request(request: ToolchainRequest) -> Result<Self, Error> {
         let result = match request {
@@ -197,12 +217,21 @@ impl PythonDownloadRequest {
         self.libc.as_ref()
     }
 
+    pub fn prereleases(&self) -> bool {
+        self.prereleases
+    }
+
     /// Iterate over all [`PythonDownload`]'s that match this request.
+    ///
+    /// Pre-release Python builds are excluded unless explicitly allowed.
     pub fn iter_downloads(&self) -> impl Iterator<Item = &'static PythonDownload> + '_ {
         PythonDownload::iter_all().filter(move |download| self.satisfied_by_download(download))
     }
 
     pub fn satisfied_by_key(&self, key: &ToolchainKey) -> bool {
+        if key.is_prerelease() && !self.prereleases {
+            return false;
+        }
         if let Some(arch) = &self.arch {
             if key.arch != *arch {
                 return false;
@@ -364,6 +393,19 @@ impl PythonDownload {
         PYTHON_DOWNLOADS.iter()
     }
 
+    /// Return the download whose archive has the given filename (e.g.,
+    /// `cpython-3.12.3+20240415-x86_64-unknown-linux-gnu-debug-full.tar.zst`), if any.
+    pub fn from_filename(filename: &str) -> Option<&'static PythonDownload> {
+        PYTHON_DOWNLOADS.iter().find(|download| {
+            download
+                .url
+                .rsplit('/')
+                .next()
+                .and_then(|segment| urlencoding::decode(segment).ok())
+                .is_some_and(|segment| segment == filename)
+        })
+    }
+
     pub fn url(&self) -> &str {
         self.url
     }
@@ -381,11 +423,15 @@ impl PythonDownload {
     }
 
     /// Download and extract
+    ///
+    /// If `verify` is set, the SHA-256 checksum of the downloaded archive is compared against the
+    /// checksum in the download manifest, and the download is discarded on a mismatch.
     #[instrument(skip(client, parent_path), fields(download = %self.key()))]
     pub async fn fetch(
         &self,
         client: &uv_client::BaseClient,
         parent_path: &Path,
+        verify: bool,
     ) -> Result<DownloadResult, Error> {
         let url = Url::parse(self.url)?;
         let path = parent_path.join(self.key().to_string()).clone();
@@ -395,29 +441,124 @@ impl PythonDownload {
             return Ok(DownloadResult::AlreadyAvailable(path));
         }
 
+        // If verification was requested, ensure that a checksum is available.
+        let expected = self.expected_sha256(verify)?;
+
         let filename = url.path_segments().unwrap().last().unwrap();
         let response = client.get(url.clone()).send().await?;
 
         // Ensure the request was successful.
         response.error_for_status_ref()?;
 
-        // Download and extract into a temporary directory.
-        let temp_dir = tempfile::tempdir_in(parent_path).map_err(Error::DownloadDirError)?;
-
-        debug!(
-            "Downloading {url} to temporary location {}",
-            temp_dir.path().display()
-        );
+        debug!("Downloading {url}");
         let reader = response
             .bytes_stream()
             .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err))
             .into_async_read();
 
+        self.extract(
+            reader.compat(),
+            filename,
+            parent_path,
+            path,
+            expected,
+            false,
+        )
+        .await
+    }
+
+    /// Install the toolchain from a local copy of its archive, rather than downloading it.
+    ///
+    /// The archive is extracted and installed in the same way as a download. If `force` is set,
+    /// any existing installation of the toolchain is replaced.
+    pub async fn install_from_file(
+        &self,
+        archive: &Path,
+        parent_path: &Path,
+        verify: bool,
+        force: bool,
+    ) -> Result<DownloadResult, Error> {
+        let path = parent_path.join(self.key().to_string());
+
+        // If it already exists, return it, unless we're reinstalling.
+        if path.is_dir() && !force {
+            return Ok(DownloadResult::AlreadyAvailable(path));
+        }
+
+        // If verification was requested, ensure that a checksum is available.
+        let expected = self.expected_sha256(verify)?;
+
+        let filename = archive
+            .file_name()
+            .map(|filename| filename.to_string_lossy())
+            .unwrap_or_default();
+        let reader = fs_err::tokio::File::open(archive).await?;
+
+        debug!("Installing from {}", archive.user_display());
+        self.extract(reader, &filename, parent_path, path, expected, force)
+            .await
+    }
+
+    /// Return the checksum to verify the archive against, if verification was requested.
+    fn expected_sha256(&self, verify: bool) -> Result<Option<&'static str>, Error> {
+        if verify {
+            self.sha256
+                .map(Some)
+                .ok_or_else(|| Error::MissingChecksum(self.key().clone()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Extract the archive from the given reader, then move it into place at `path`.
+    ///
+    /// If `replace` is set, any existing directory at `path` is removed once the archive has been
+    /// extracted (and verified).
+    async fn extract<R: tokio::io::AsyncRead + Unpin>(
+        &self,
+        reader: R,
+        filename: &str,
+        parent_path: &Path,
+        path: PathBuf,
+        expected: Option<&str>,
+        replace: bool,
+    ) -> Result<DownloadResult, Error> {
+        // Extract into a temporary directory.
+        let temp_dir = tempfile::tempdir_in(parent_path).map_err(Error::DownloadDirError)?;
+        debug!(
+            "Extracting to temporary location {}",
+            temp_dir.path().display()
+        );
+
+        // Hash the archive as it's extracted, if verification was requested.
+        let mut hashers = expected
+            .iter()
+            .map(|_| Hasher::from(HashAlgorithm::Sha256))
+            .collect::<Vec<_>>();
+        let mut hasher = HashReader::new(reader, &mut hashers);
+
         debug!("Extracting {filename}");
-        uv_extract::stream::archive(reader.compat(), filename, temp_dir.path())
+        uv_extract::stream::archive(&mut hasher, filename, temp_dir.path())
             .await
             .map_err(|err| Error::ExtractError(filename.to_string(), err))?;
 
+        // Compare the checksum against the expected value.
+        if let Some(expected) = expected {
+            hasher.finish().await?;
+            let actual = hashers
+                .pop()
+                .map(|hasher| HashDigest::from(hasher).digest)
+                .unwrap_or_default();
+            if &*actual != expected {
+                return Err(Error::HashMismatch {
+                    key: self.key().clone(),
+                    expected: expected.to_string(),
+                    actual: actual.to_string(),
+                });
+            }
+            debug!("Verified checksum of {filename}");
+        }
+
         // Extract the top-level directory.
         let extracted = match uv_extract::strip_component(temp_dir.path()) {
             Ok(top_level) => top_level,
@@ -425,6 +566,12 @@ impl PythonDownload {
             Err(err) => return Err(Error::ExtractError(filename.to_string(), err)),
         };
 
+        // Remove any existing installation, if requested.
+        if replace && path.is_dir() {
+            debug!("Removing existing toolchain at {}", path.user_display());
+            fs_err::tokio::remove_dir_all(&path).await?;
+        }
+
         // Persist it to the target
         debug!("Moving {} to {}", extracted.display(), path.user_display());
         rename_with_retry(extracted, &path)
@@ -464,3 +611,55 @@ impl Display for PythonDownload {
         write!(f, "{}", self.key)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::toolchain::ToolchainKey;
+
+    use super::{PythonDownload, PythonDownloadRequest};
+
+    #[test]
+    fn satisfied_by_prerelease_key() {
+        let key = ToolchainKey::from_str("cpython-3.13.0b1-linux-x86_64-gnu").unwrap();
+        let request = PythonDownloadRequest::new(None, None, None, None, None);
+
+        // Pre-releases are excluded by default.
+        assert!(!request.satisfied_by_key(&key));
+        assert!(request
+            .clone()
+            .with_prereleases(true)
+            .satisfied_by_key(&key));
+
+        // Final releases are always included.
+        let key = ToolchainKey::from_str("cpython-3.13.0-linux-x86_64-gnu").unwrap();
+        assert!(request.satisfied_by_key(&key));
+        assert!(request.with_prereleases(true).satisfied_by_key(&key));
+    }
+
+    #[test]
+    fn from_filename() {
+        // The filename is matched against the (decoded) last segment of the download URL.
+        let download = PythonDownload::from_filename(
+            "cpython-3.12.3+20240415-x86_64-unknown-linux-gnu-debug-full.tar.zst",
+        )
+        .unwrap();
+        assert_eq!(
+            download.key(),
+            &ToolchainKey::from_str("cpython-3.12.3-linux-x86_64-gnu").unwrap()
+        );
+
+        // The URL-encoded filename doesn't match.
+        assert!(PythonDownload::from_filename(
+            "cpython-3.12.3%2B20240415-x86_64-unknown-linux-gnu-debug-full.tar.zst"
+        )
+        .is_none());
+
+        // Neither does an archive that isn't in the download manifest.
+        assert!(PythonDownload::from_filename(
+            "cpython-3.12.3+20240415-x86_64-unknown-linux-gnu-install_only.tar.gz"
+        )
+        .is_none());
+    }
+}
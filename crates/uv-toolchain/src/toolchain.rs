@@ -1,7 +1,7 @@
 use std::fmt;
 use std::str::FromStr;
 
-use pep440_rs::Version;
+use pep440_rs::{PreRelease, PreReleaseKind, Version};
 use tracing::{debug, info};
 use uv_client::BaseClientBuilder;
 
@@ -110,7 +110,7 @@ impl Toolchain {
         let client = client_builder.build();
 
         info!("Fetching requested toolchain...");
-        let result = download.fetch(&client, toolchain_dir).await?;
+        let result = download.fetch(&client, toolchain_dir, false).await?;
 
         let path = match result {
             DownloadResult::AlreadyAvailable(path) => path,
@@ -145,6 +145,7 @@ impl Toolchain {
             self.interpreter.python_major(),
             self.interpreter.python_minor(),
             self.interpreter.python_patch(),
+            self.interpreter.python_version().pre(),
             self.os(),
             self.arch(),
             self.libc(),
@@ -198,6 +199,7 @@ pub struct ToolchainKey {
     pub(crate) major: u8,
     pub(crate) minor: u8,
     pub(crate) patch: u8,
+    pub(crate) prerelease: Option<PreRelease>,
     pub(crate) os: Os,
     pub(crate) arch: Arch,
     pub(crate) libc: Libc,
@@ -209,6 +211,7 @@ impl ToolchainKey {
         major: u8,
         minor: u8,
         patch: u8,
+        prerelease: Option<PreRelease>,
         os: Os,
         arch: Arch,
         libc: Libc,
@@ -218,6 +221,7 @@ impl ToolchainKey {
             major,
             minor,
             patch,
+            prerelease,
             os,
             arch,
             libc,
@@ -229,8 +233,28 @@ impl ToolchainKey {
     }
 
     pub fn version(&self) -> PythonVersion {
-        PythonVersion::from_str(&format!("{}.{}.{}", self.major, self.minor, self.patch))
-            .expect("Toolchain keys must have valid Python versions")
+        PythonVersion::from_str(&format!(
+            "{}.{}.{}{}",
+            self.major,
+            self.minor,
+            self.patch,
+            self.prerelease_suffix()
+        ))
+        .expect("Toolchain keys must have valid Python versions")
+    }
+
+    /// Returns `true` if the toolchain is a pre-release (e.g., an alpha, beta, or release
+    /// candidate).
+    pub fn is_prerelease(&self) -> bool {
+        self.prerelease.is_some()
+    }
+
+    /// Returns the pre-release suffix for the version (e.g., `b1`), or an empty string for final
+    /// releases.
+    fn prerelease_suffix(&self) -> String {
+        self.prerelease
+            .map(|prerelease| format!("{}{}", prerelease.kind, prerelease.number))
+            .unwrap_or_default()
     }
 
     pub fn arch(&self) -> &Arch {
@@ -250,8 +274,15 @@ impl fmt::Display for ToolchainKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{}-{}.{}.{}-{}-{}-{}",
-            self.implementation, self.major, self.minor, self.patch, self.os, self.arch, self.libc
+            "{}-{}.{}.{}{}-{}-{}-{}",
+            self.implementation,
+            self.major,
+            self.minor,
+            self.patch,
+            self.prerelease_suffix(),
+            self.os,
+            self.arch,
+            self.libc
         )
     }
 }
@@ -282,6 +313,23 @@ impl FromStr for ToolchainKey {
             ToolchainKeyError::ParseError(key.to_string(), format!("invalid libc: {err}"))
         })?;
 
+        // Split off any pre-release suffix from the patch version (e.g., `3.13.0b1`).
+        let (version, prerelease) = match version.find(|c: char| c.is_ascii_alphabetic()) {
+            Some(index) => {
+                let (version, suffix) = version.split_at(index);
+                (
+                    version,
+                    Some(parse_prerelease(suffix).ok_or_else(|| {
+                        ToolchainKeyError::ParseError(
+                            key.to_string(),
+                            format!("invalid pre-release: `{suffix}`"),
+                        )
+                    })?),
+                )
+            }
+            None => (*version, None),
+        };
+
         let [major, minor, patch] = version
             .splitn(3, '.')
             .map(str::parse::<u8>)
@@ -304,6 +352,7 @@ impl FromStr for ToolchainKey {
             major,
             minor,
             patch,
+            prerelease,
             os,
             arch,
             libc,
@@ -311,13 +360,79 @@ impl FromStr for ToolchainKey {
     }
 }
 
+/// Parse a pre-release suffix, e.g., `a1`, `b2`, or `rc1`.
+fn parse_prerelease(suffix: &str) -> Option<PreRelease> {
+    let (kind, number) = if let Some(number) = suffix.strip_prefix("rc") {
+        (PreReleaseKind::Rc, number)
+    } else if let Some(number) = suffix.strip_prefix('a') {
+        (PreReleaseKind::Alpha, number)
+    } else if let Some(number) = suffix.strip_prefix('b') {
+        (PreReleaseKind::Beta, number)
+    } else {
+        return None;
+    };
+    let number = number.parse::<u64>().ok()?;
+    Some(PreRelease { kind, number })
+}
+
 impl PartialOrd for ToolchainKey {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 impl Ord for ToolchainKey {
+    /// Compare keys by implementation and then by version, such that a pre-release (e.g.,
+    /// `3.13.0b1`) sorts below the corresponding final release (e.g., `3.13.0`), falling back to
+    /// the string representation to order the platforms.
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.to_string().cmp(&other.to_string())
+        self.implementation
+            .to_string()
+            .cmp(&other.implementation.to_string())
+            .then_with(|| {
+                (self.major, self.minor, self.patch).cmp(&(other.major, other.minor, other.patch))
+            })
+            .then_with(|| match (&self.prerelease, &other.prerelease) {
+                (None, None) => std::cmp::Ordering::Equal,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (Some(a), Some(b)) => a.cmp(b),
+            })
+            .then_with(|| self.to_string().cmp(&other.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::ToolchainKey;
+
+    #[test]
+    fn toolchain_key_ordering() {
+        let keys = [
+            "cpython-3.13.0-linux-x86_64-gnu",
+            "cpython-3.9.19-linux-x86_64-gnu",
+            "cpython-3.13.0rc1-linux-x86_64-gnu",
+            "cpython-3.13.0b1-linux-x86_64-gnu",
+            "cpython-3.12.3-linux-x86_64-gnu",
+            "cpython-3.13.0b2-linux-x86_64-gnu",
+        ];
+        let mut keys = keys
+            .into_iter()
+            .map(|key| ToolchainKey::from_str(key).unwrap())
+            .collect::<Vec<_>>();
+        keys.sort();
+
+        assert_eq!(
+            keys.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            [
+                "cpython-3.9.19-linux-x86_64-gnu",
+                "cpython-3.12.3-linux-x86_64-gnu",
+                "cpython-3.13.0b1-linux-x86_64-gnu",
+                "cpython-3.13.0b2-linux-x86_64-gnu",
+                "cpython-3.13.0rc1-linux-x86_64-gnu",
+                "cpython-3.13.0-linux-x86_64-gnu",
+            ]
+        );
     }
 }
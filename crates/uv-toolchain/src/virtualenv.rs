@@ -28,6 +28,8 @@ pub struct PyVenvConfiguration {
     pub(crate) virtualenv: bool,
     /// If the `uv` package was used to create the virtual environment.
     pub(crate) uv: bool,
+    /// If the virtual environment is relocatable.
+    pub(crate) relocatable: bool,
 }
 
 #[derive(Debug, Error)]
@@ -136,6 +138,7 @@ impl PyVenvConfiguration {
     pub fn parse(cfg: impl AsRef<Path>) -> Result<Self, Error> {
         let mut virtualenv = false;
         let mut uv = false;
+        let mut relocatable = false;
 
         // Per https://snarky.ca/how-virtual-environments-work/, the `pyvenv.cfg` file is not a
         // valid INI file, and is instead expected to be parsed by partitioning each line on the
@@ -143,7 +146,7 @@ impl PyVenvConfiguration {
         let content = fs::read_to_string(&cfg)
             .map_err(|err| Error::ParsePyVenvCfg(cfg.as_ref().to_path_buf(), err))?;
         for line in content.lines() {
-            let Some((key, _value)) = line.split_once('=') else {
+            let Some((key, value)) = line.split_once('=') else {
                 continue;
             };
             match key.trim() {
@@ -153,11 +156,18 @@ impl PyVenvConfiguration {
                 "uv" => {
                     uv = true;
                 }
+                "relocatable" => {
+                    relocatable = value.trim().eq_ignore_ascii_case("true");
+                }
                 _ => {}
             }
         }
 
-        Ok(Self { virtualenv, uv })
+        Ok(Self {
+            virtualenv,
+            uv,
+            relocatable,
+        })
     }
 
     /// Returns true if the virtual environment was created with the `virtualenv` package.
@@ -169,4 +179,9 @@ impl PyVenvConfiguration {
     pub fn is_uv(&self) -> bool {
         self.uv
     }
+
+    /// Returns true if the virtual environment is relocatable.
+    pub fn is_relocatable(&self) -> bool {
+        self.relocatable
+    }
 }
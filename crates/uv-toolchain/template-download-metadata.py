@@ -13,6 +13,7 @@ import sys
 import logging
 import argparse
 import json
+import re
 import subprocess
 from pathlib import Path
 
@@ -61,11 +62,27 @@ def prepare_arch(arch: str) -> str:
             return arch.capitalize()
 
 
+def prepare_prerelease(prerelease: str | None) -> str | None:
+    if not prerelease:
+        return None
+    match = re.match(r"^(a|b|rc)(\d+)$", prerelease)
+    if match is None:
+        raise ValueError(f"Unknown pre-release: {prerelease}")
+    kind, number = match.groups()
+    kind = {"a": "Alpha", "b": "Beta", "rc": "Rc"}[kind]
+    return (
+        "pep440_rs::PreRelease { "
+        f"kind: pep440_rs::PreReleaseKind::{kind}, number: {number} "
+        "}"
+    )
+
+
 def prepare_value(value: dict) -> dict:
     value["os"] = value["os"].title()
     value["arch"] = prepare_arch(value["arch"])
     value["name"] = prepare_name(value["name"])
     value["libc"] = prepare_libc(value["libc"])
+    value["prerelease"] = prepare_prerelease(value.get("prerelease"))
     return value
 
 
@@ -1,3 +1,4 @@
+use uv_normalize::PackageName;
 use uv_toolchain::PythonEnvironment;
 
 /// Whether to enforce build isolation when building source distributions.
@@ -6,6 +7,9 @@ pub enum BuildIsolation<'a> {
     #[default]
     Isolated,
     Shared(&'a PythonEnvironment),
+    /// Disable build isolation for the given packages only; all other packages are built in
+    /// isolation.
+    SharedPackage(&'a PythonEnvironment, &'a [PackageName]),
 }
 
 impl<'a> BuildIsolation<'a> {
@@ -13,4 +17,22 @@ impl<'a> BuildIsolation<'a> {
     pub fn is_isolated(&self) -> bool {
         matches!(self, Self::Isolated)
     }
+
+    /// Returns the [`BuildIsolation`] to use when building the given package.
+    ///
+    /// Builds for which the package name is unknown are always isolated under
+    /// [`BuildIsolation::SharedPackage`].
+    #[must_use]
+    pub fn for_package(self, package: Option<&PackageName>) -> Self {
+        match self {
+            Self::SharedPackage(environment, packages) => {
+                if package.is_some_and(|package| packages.contains(package)) {
+                    Self::Shared(environment)
+                } else {
+                    Self::Isolated
+                }
+            }
+            isolation => isolation,
+        }
+    }
 }
@@ -50,6 +50,7 @@ pub fn create_bare_venv(
     prompt: Prompt,
     system_site_packages: bool,
     allow_existing: bool,
+    relocatable: bool,
 ) -> Result<VirtualEnvironment, Error> {
     // Determine the base Python executable; that is, the Python executable that should be
     // considered the "base" for the virtual environment. This is typically the Python executable
@@ -212,12 +213,26 @@ pub fn create_bare_venv(
         .map(|path| path.simplified().to_str().unwrap().replace('\\', "\\\\"))
         .join(path_sep);
 
+        // In a relocatable environment, the activation scripts compute the environment's location
+        // from their own path, where the shell supports it. The template values are wrapped in
+        // single quotes, so the expressions close and re-open the quotes.
+        let virtual_env_dir = match (relocatable, *name) {
+            (true, "activate") => {
+                r#"'"$(dirname -- "$(cd "$(dirname -- "${BASH_SOURCE[0]:-$0}")" && pwd)")"'"#
+                    .to_string()
+            }
+            (true, "activate.fish") => {
+                r"'(dirname -- (dirname -- (realpath -- (status filename))))'".to_string()
+            }
+            (true, "activate.bat") => r"%~dp0..".to_string(),
+            // `activate.ps1` already derives the location from the script path, and there's no
+            // equivalent for `activate.csh` or `activate.nu`.
+            // SAFETY: `unwrap` is guaranteed to succeed because `location` is an `Utf8PathBuf`.
+            _ => location.simplified().to_str().unwrap().to_string(),
+        };
+
         let activator = template
-            .replace(
-                "{{ VIRTUAL_ENV_DIR }}",
-                // SAFETY: `unwrap` is guaranteed to succeed because `location` is an `Utf8PathBuf`.
-                location.simplified().to_str().unwrap(),
-            )
+            .replace("{{ VIRTUAL_ENV_DIR }}", &virtual_env_dir)
             .replace("{{ BIN_NAME }}", bin_name)
             .replace(
                 "{{ VIRTUAL_PROMPT }}",
@@ -255,6 +270,10 @@ pub fn create_bare_venv(
         ),
     ];
 
+    if relocatable {
+        pyvenv_cfg_data.push(("relocatable".to_string(), "true".to_string()));
+    }
+
     if let Some(prompt) = prompt {
         pyvenv_cfg_data.push(("prompt".to_string(), prompt));
     }
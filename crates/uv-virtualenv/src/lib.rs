@@ -54,6 +54,7 @@ pub fn create_venv(
     prompt: Prompt,
     system_site_packages: bool,
     allow_existing: bool,
+    relocatable: bool,
 ) -> Result<PythonEnvironment, Error> {
     // Create the virtualenv at the given location.
     let virtualenv = create_bare_venv(
@@ -62,6 +63,7 @@ pub fn create_venv(
         prompt,
         system_site_packages,
         allow_existing,
+        relocatable,
     )?;
 
     // Create the corresponding `PythonEnvironment`.
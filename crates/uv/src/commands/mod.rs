@@ -18,6 +18,7 @@ pub(crate) use pip::sync::pip_sync;
 pub(crate) use pip::tree::pip_tree;
 pub(crate) use pip::uninstall::pip_uninstall;
 pub(crate) use project::add::add;
+pub(crate) use project::init::init;
 pub(crate) use project::lock::lock;
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::run;
@@ -25,14 +26,19 @@ pub(crate) use project::sync::sync;
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
 pub(crate) use tool::dir::dir as tool_dir;
+pub(crate) use tool::inspect::inspect as tool_inspect;
 pub(crate) use tool::install::install as tool_install;
 pub(crate) use tool::list::list as tool_list;
+pub(crate) use tool::reinstall::reinstall as tool_reinstall;
 pub(crate) use tool::run::run as tool_run;
 pub(crate) use tool::uninstall::uninstall as tool_uninstall;
+pub(crate) use tool::upgrade::upgrade as tool_upgrade;
 pub(crate) use toolchain::dir::dir as toolchain_dir;
 pub(crate) use toolchain::find::find as toolchain_find;
 pub(crate) use toolchain::install::install as toolchain_install;
 pub(crate) use toolchain::list::list as toolchain_list;
+pub(crate) use toolchain::show::show as toolchain_show;
+pub(crate) use toolchain::uninstall::uninstall as toolchain_uninstall;
 use uv_cache::Cache;
 use uv_fs::Simplified;
 use uv_installer::compile_tree;
@@ -40,6 +46,11 @@ use uv_normalize::PackageName;
 use uv_toolchain::PythonEnvironment;
 pub(crate) use venv::venv;
 pub(crate) use version::version;
+pub(crate) use workspace::clean::clean as workspace_clean;
+pub(crate) use workspace::graph::graph as workspace_graph;
+pub(crate) use workspace::info::info as workspace_info;
+pub(crate) use workspace::member::add as workspace_member_add;
+pub(crate) use workspace::publish::publish as workspace_publish;
 
 use crate::printer::Printer;
 
@@ -56,6 +67,7 @@ mod toolchain;
 mod self_update;
 mod venv;
 mod version;
+mod workspace;
 
 #[derive(Copy, Clone)]
 pub(crate) enum ExitStatus {
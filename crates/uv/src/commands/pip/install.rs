@@ -1,37 +1,58 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use anstream::eprint;
+use anyhow::Context;
+use chrono::{SecondsFormat, Utc};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use tracing::{debug, enabled, Level};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use tracing::{debug, enabled, warn, Level};
+use url::Url;
 
-use distribution_types::{IndexLocations, Resolution, UnresolvedRequirementSpecification};
+use distribution_types::{
+    Dist, IndexLocations, InstalledDist, InstalledMetadata, Name, Resolution, ResolvedDist,
+    SourceDist, UnresolvedRequirement, UnresolvedRequirementSpecification,
+};
 use install_wheel_rs::linker::LinkMode;
-use pypi_types::Requirement;
+use pep440_rs::{Version, VersionSpecifier, VersionSpecifiers};
+use pep508_rs::{MarkerExpression, MarkerOperator, MarkerTree, MarkerValueString};
+use pypi_types::{Requirement, RequirementSource};
 use uv_auth::store_credentials_from_url;
 use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_cli::DryRunFormat;
+use uv_client::{
+    BaseClientBuilder, CaBundle, Connectivity, FlatIndexClient, RegistryClientBuilder,
+};
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, ExtrasSpecification, IndexStrategy, PreviewMode,
     Reinstall, SetupPyStrategy, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::BuildDispatch;
+use uv_distribution::Workspace;
 use uv_fs::Simplified;
 use uv_git::GitResolver;
 use uv_installer::{SatisfiesResult, SitePackages};
+use uv_normalize::PackageName;
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
-    DependencyMode, ExcludeNewer, FlatIndex, InMemoryIndex, OptionsBuilder, PreReleaseMode,
+    DependencyMode, ExcludeNewer, FlatIndex, InMemoryIndex, Lock, OptionsBuilder, PreReleaseMode,
     PythonRequirement, ResolutionMode,
 };
 use uv_toolchain::{
     EnvironmentPreference, Prefix, PythonEnvironment, PythonVersion, Target, ToolchainRequest,
 };
 use uv_types::{BuildIsolation, HashStrategy, InFlight};
+use uv_warnings::warn_user;
 
-use crate::commands::pip::operations::Modifications;
-use crate::commands::pip::{operations, resolution_environment};
+use crate::commands::pip::operations::{Changelog, Modifications};
+use crate::commands::pip::{operations, override_markers, resolution_environment};
 use crate::commands::{elapsed, ExitStatus};
 use crate::printer::Printer;
 
@@ -42,11 +63,18 @@ pub(crate) async fn pip_install(
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
     overrides_from_workspace: Vec<Requirement>,
+    constraint_group: &[String],
+    constraint_groups: &BTreeMap<String, Vec<Requirement>>,
+    exclude: &[PackageName],
+    strip_extras: bool,
+    marker: Option<MarkerTree>,
+    marker_env: Vec<(String, String)>,
     extras: &ExtrasSpecification,
     resolution_mode: ResolutionMode,
     prerelease_mode: PreReleaseMode,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
+    keep_installed: bool,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     keyring_provider: KeyringProviderType,
@@ -73,14 +101,51 @@ pub(crate) async fn pip_install(
     preview: PreviewMode,
     cache: Cache,
     dry_run: bool,
+    dry_run_format: DryRunFormat,
+    log: Option<PathBuf>,
+    generate_checksums: Option<PathBuf>,
+    check_build_dependencies: bool,
+    isolated_resolution: bool,
+    ignore_conflicts: bool,
+    no_warn_conflicts: bool,
+    report_diff: bool,
+    report_unchanged: bool,
+    report_skipped: bool,
+    skip_locked: bool,
+    force_reinstall_deps: bool,
+    prefer_binary: bool,
+    prefer_sdist: bool,
+    retries: u32,
+    retry_delay: Option<Duration>,
+    max_retries: Option<u32>,
+    retry_status_codes: Option<Vec<u16>>,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    socks_proxy: Option<Url>,
+    ssl_ca_bundle: Option<PathBuf>,
+    resolver_timeout: Option<Duration>,
     printer: Printer,
 ) -> anyhow::Result<ExitStatus> {
     let start = std::time::Instant::now();
 
+    // Read the CA bundle up front, such that an invalid bundle is an error rather than ignored.
+    let ssl_ca_bundle = ssl_ca_bundle
+        .as_deref()
+        .map(CaBundle::from_path)
+        .transpose()?;
+
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
-        .keyring(keyring_provider);
+        .keyring(keyring_provider)
+        .retries(retries)
+        .retry_delay(retry_delay)
+        .max_retries(max_retries)
+        .retry_status_codes(retry_status_codes.clone())
+        .connect_timeout(connect_timeout)
+        .read_timeout(read_timeout)
+        .socks_proxy(socks_proxy.clone())
+        .ssl_ca_bundle(ssl_ca_bundle.clone());
 
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
@@ -102,10 +167,22 @@ pub(crate) async fn pip_install(
         overrides,
         extras,
         &client_builder,
+        &cache,
     )
     .await?;
 
-    let overrides: Vec<UnresolvedRequirementSpecification> = overrides
+    // Add the constraints from any requested `tool.uv.constraint-groups`.
+    let mut constraints = constraints;
+    for group in constraint_group {
+        let Some(requirements) = constraint_groups.get(group) else {
+            return Err(anyhow::anyhow!(
+                "Constraint group `{group}` is not defined in `tool.uv.constraint-groups`"
+            ));
+        };
+        constraints.extend(requirements.iter().cloned());
+    }
+
+    let mut overrides: Vec<UnresolvedRequirementSpecification> = overrides
         .iter()
         .cloned()
         .chain(
@@ -115,6 +192,67 @@ pub(crate) async fn pip_install(
         )
         .collect();
 
+    // Exclude any packages requested via `--exclude`, by overriding every requirement on the
+    // package with a requirement whose markers never apply.
+    if !exclude.is_empty() {
+        overrides.retain(|spec| match &spec.requirement {
+            UnresolvedRequirement::Named(requirement) => !exclude.contains(&requirement.name),
+            UnresolvedRequirement::Unnamed(_) => true,
+        });
+        overrides.extend(exclude.iter().map(|name| {
+            UnresolvedRequirementSpecification::from(Requirement {
+                name: name.clone(),
+                extras: vec![],
+                marker: Some(MarkerTree::Expression(MarkerExpression::String {
+                    key: MarkerValueString::SysPlatform,
+                    operator: MarkerOperator::Equal,
+                    value: "never".to_string(),
+                })),
+                source: RequirementSource::Registry {
+                    specifier: VersionSpecifiers::empty(),
+                    index: None,
+                },
+                origin: None,
+            })
+        }));
+    }
+
+    // Discard the extras from the requirements, if requested.
+    let requirements = if strip_extras {
+        requirements
+            .into_iter()
+            .map(|mut spec| {
+                match &mut spec.requirement {
+                    UnresolvedRequirement::Named(requirement) => requirement.extras.clear(),
+                    UnresolvedRequirement::Unnamed(requirement) => requirement.extras.clear(),
+                }
+                spec
+            })
+            .collect()
+    } else {
+        requirements
+    };
+
+    // Apply the `--marker` expression to each requirement, in addition to its own markers.
+    let requirements = if let Some(marker) = marker {
+        requirements
+            .into_iter()
+            .map(|mut spec| {
+                let existing = match &mut spec.requirement {
+                    UnresolvedRequirement::Named(requirement) => &mut requirement.marker,
+                    UnresolvedRequirement::Unnamed(requirement) => &mut requirement.marker,
+                };
+                *existing = Some(match existing.take() {
+                    Some(existing) => MarkerTree::And(vec![existing, marker.clone()]),
+                    None => marker.clone(),
+                });
+                spec
+            })
+            .collect()
+    } else {
+        requirements
+    };
+
     // Detect the current Python interpreter.
     let environment = PythonEnvironment::find(
         &python
@@ -175,10 +313,60 @@ pub(crate) async fn pip_install(
     // Determine the set of installed packages.
     let site_packages = SitePackages::from_environment(&environment)?;
 
+    // Pin every package that was installed from a registry to its installed version, if requested,
+    // such that only missing packages are installed. Packages that were explicitly marked for
+    // upgrade (via `--upgrade-package`) are left unpinned.
+    if keep_installed {
+        constraints.extend(
+            site_packages
+                .iter()
+                .filter_map(|dist| match dist {
+                    InstalledDist::Registry(dist) => Some(dist),
+                    _ => None,
+                })
+                .filter(|dist| match &upgrade {
+                    Upgrade::Packages(packages) => !packages.contains(&dist.name),
+                    Upgrade::None | Upgrade::All => true,
+                })
+                .map(|dist| Requirement {
+                    name: dist.name.clone(),
+                    extras: vec![],
+                    marker: None,
+                    source: RequirementSource::Registry {
+                        specifier: VersionSpecifier::equals_version(dist.version.clone()).into(),
+                        index: None,
+                    },
+                    origin: None,
+                }),
+        );
+    }
+
+    // Skip any requirements that are already installed at the locked version, if requested.
+    let requirements = if skip_locked {
+        skip_locked_requirements(requirements, &site_packages).await?
+    } else {
+        requirements
+    };
+
+    // Snapshot the installed packages, to report the changes after installation.
+    let snapshot = report_diff.then(|| InstalledVersions::from_site_packages(&site_packages));
+
     // Check if the current environment satisfies the requirements.
     // Ideally, the resolver would be fast enough to let us remove this check. But right now, for large environments,
     // it's an order of magnitude faster to validate the environment than to resolve the requirements.
-    if reinstall.is_none() && upgrade.is_none() && source_trees.is_empty() && overrides.is_empty() {
+    //
+    // The check evaluates markers against the interpreter, so it's skipped when any marker
+    // variables are overridden with `--marker-env`. It's also skipped when generating checksums,
+    // which are computed from the resolution, to include any packages that are already installed.
+    if reinstall.is_none()
+        && !force_reinstall_deps
+        && upgrade.is_none()
+        && source_trees.is_empty()
+        && overrides.is_empty()
+        && marker_env.is_empty()
+        && generate_checksums.is_none()
+        && !isolated_resolution
+    {
         match site_packages.satisfies(&requirements, &constraints)? {
             // If the requirements are already satisfied, we're done.
             SatisfiesResult::Fresh {
@@ -206,7 +394,26 @@ pub(crate) async fn pip_install(
                     .dimmed()
                 )?;
                 if dry_run {
-                    writeln!(printer.stderr(), "Would make no changes")?;
+                    match dry_run_format {
+                        DryRunFormat::Text => {
+                            writeln!(printer.stderr(), "Would make no changes")?;
+                        }
+                        DryRunFormat::Json => {
+                            writeln!(printer.stdout(), "[]")?;
+                        }
+                    }
+                }
+                if report_unchanged || report_skipped {
+                    let names = recursive_requirements.iter().filter_map(|entry| {
+                        match &entry.requirement {
+                            UnresolvedRequirement::Named(requirement) => Some(&requirement.name),
+                            UnresolvedRequirement::Unnamed(_) => None,
+                        }
+                    });
+                    report_unchanged_packages(names, &site_packages, report_skipped, printer)?;
+                }
+                if let Some(snapshot) = &snapshot {
+                    snapshot.report_diff(snapshot, printer)?;
                 }
                 return Ok(ExitStatus::Success);
             }
@@ -228,6 +435,13 @@ pub(crate) async fn pip_install(
     // Determine the environment for the resolution.
     let (tags, markers) = resolution_environment(python_version, python_platform, interpreter)?;
 
+    // Override any marker variables provided with `--marker-env`.
+    let markers = if marker_env.is_empty() {
+        markers
+    } else {
+        Cow::Owned(override_markers(&markers, &marker_env)?)
+    };
+
     // Collect the set of required hashes.
     let hasher = if require_hashes {
         HashStrategy::from_requirements(
@@ -252,6 +466,14 @@ pub(crate) async fn pip_install(
     let index_locations =
         index_locations.combine(index_url, extra_index_urls, find_links, no_index);
 
+    // Without any indexes or flat indexes, only installed packages and direct URL requirements can
+    // be used to satisfy the resolution.
+    if index_locations.no_index() && index_locations.flat_index().next().is_none() {
+        warn_user!(
+            "Index lookups are disabled (`--no-index`), but no additional package locations were provided (`--find-links`)"
+        );
+    }
+
     // Add all authenticated sources to the cache.
     for url in index_locations.urls() {
         store_credentials_from_url(url);
@@ -264,6 +486,14 @@ pub(crate) async fn pip_install(
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
         .keyring(keyring_provider)
+        .retries(retries)
+        .retry_delay(retry_delay)
+        .max_retries(max_retries)
+        .retry_status_codes(retry_status_codes)
+        .connect_timeout(connect_timeout)
+        .read_timeout(read_timeout)
+        .socks_proxy(socks_proxy)
+        .ssl_ca_bundle(ssl_ca_bundle)
         .markers(&markers)
         .platform(interpreter.platform())
         .build();
@@ -318,44 +548,182 @@ pub(crate) async fn pip_install(
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
         .index_strategy(index_strategy)
+        .prefer_binary(prefer_binary)
+        .prefer_sdist(prefer_sdist)
         .build();
 
-    // Resolve the requirements.
-    let resolution = match operations::resolve(
-        requirements,
-        constraints,
-        overrides,
-        dev,
-        source_trees,
-        project,
-        extras,
-        preferences,
-        site_packages.clone(),
-        &hasher,
-        &reinstall,
-        &upgrade,
-        Some(&tags),
-        Some(&markers),
-        python_requirement,
-        &client,
-        &flat_index,
-        &index,
-        &resolve_dispatch,
-        concurrency,
-        options,
-        printer,
-        preview,
-    )
-    .await
-    {
-        Ok(resolution) => Resolution::from(resolution),
-        Err(operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err))) => {
-            let report = miette::Report::msg(format!("{err}"))
-                .context("No solution found when resolving dependencies:");
-            eprint!("{report:?}");
-            return Ok(ExitStatus::Failure);
+    // If `--isolated-resolution` was provided, resolve as if the environment were empty.
+    let installed_packages = if isolated_resolution {
+        SitePackages::empty(&environment)
+    } else {
+        site_packages.clone()
+    };
+
+    // Resolve the requirements. With `--ignore-conflicts`, any packages with conflicting
+    // requirements are overridden to accept any version, and the resolution is retried.
+    let mut overrides = overrides;
+    let mut ignored = BTreeSet::default();
+    let deadline = resolver_timeout.map(|timeout| (timeout, tokio::time::Instant::now() + timeout));
+    let resolution = loop {
+        let resolve = operations::resolve(
+            requirements.clone(),
+            constraints.clone(),
+            overrides.clone(),
+            dev.clone(),
+            source_trees.clone(),
+            project.clone(),
+            extras,
+            preferences.clone(),
+            installed_packages.clone(),
+            &hasher,
+            &reinstall,
+            &upgrade,
+            Some(&tags),
+            Some(&markers),
+            python_requirement.clone(),
+            &client,
+            &flat_index,
+            &index,
+            &resolve_dispatch,
+            concurrency,
+            options,
+            printer,
+            preview,
+        );
+
+        // Abort the resolution if it exceeds the `--resolver-timeout`, including any retries.
+        let result = if let Some((timeout, deadline)) = deadline {
+            match tokio::time::timeout_at(deadline, resolve).await {
+                Ok(result) => result,
+                Err(_) => {
+                    // Report the packages for which the resolver was still waiting on metadata.
+                    let pending = index
+                        .packages()
+                        .pending()
+                        .into_iter()
+                        .map(|name| name.to_string())
+                        .chain(
+                            index
+                                .distributions()
+                                .pending()
+                                .into_iter()
+                                .map(|id| id.to_string()),
+                        )
+                        .sorted()
+                        .map(|package| format!("`{package}`"))
+                        .join(", ");
+                    return if pending.is_empty() {
+                        Err(anyhow::anyhow!(
+                            "Resolution did not complete within {}s (increase `--resolver-timeout` to allow more time)",
+                            timeout.as_secs()
+                        ))
+                    } else {
+                        Err(anyhow::anyhow!(
+                            "Resolution did not complete within {}s while waiting on metadata for: {pending} (increase `--resolver-timeout` to allow more time)",
+                            timeout.as_secs()
+                        ))
+                    };
+                }
+            }
+        } else {
+            resolve.await
+        };
+
+        match result {
+            Ok(resolution) => break Resolution::from(resolution),
+            Err(operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err))) => {
+                let conflicts = if ignore_conflicts {
+                    err.conflicting_packages()
+                        .into_iter()
+                        .filter(|name| !ignored.contains(name))
+                        .collect::<Vec<_>>()
+                } else {
+                    Vec::new()
+                };
+
+                if conflicts.is_empty() {
+                    let report = miette::Report::msg(format!("{err}"))
+                        .context("No solution found when resolving dependencies:");
+                    eprint!("{report:?}");
+                    return Ok(ExitStatus::Failure);
+                }
+
+                for name in conflicts {
+                    if no_warn_conflicts {
+                        warn!(
+                            "Ignoring conflicting requirements for `{name}`; the environment may be broken"
+                        );
+                    } else {
+                        warn_user!(
+                            "Ignoring conflicting requirements for `{name}`; the environment may be broken"
+                        );
+                    }
+                    // Override the package with an unconstrained requirement, which discards
+                    // every other requirement on it, including any user-provided constraints.
+                    overrides.push(UnresolvedRequirementSpecification::from(Requirement {
+                        name: name.clone(),
+                        extras: vec![],
+                        marker: None,
+                        source: RequirementSource::Registry {
+                            specifier: VersionSpecifiers::empty(),
+                            index: None,
+                        },
+                        origin: None,
+                    }));
+                    ignored.insert(name);
+                }
+            }
+            Err(err) => return Err(err.into()),
         }
-        Err(err) => return Err(err.into()),
+    };
+
+    // Resolve the build dependencies of any local source trees, to fail before starting any builds.
+    //
+    // Other source distributions (from a registry, URL, Git repository, or local archive) would
+    // need to be fetched and unpacked to read their build requirements, so they're skipped, as are
+    // any source trees that are built without isolation (which don't install build requirements).
+    if check_build_dependencies {
+        for dist in resolution.distributions() {
+            let ResolvedDist::Installable(Dist::Source(dist)) = dist else {
+                continue;
+            };
+            let SourceDist::Directory(dist) = dist else {
+                debug!("Skipping build dependency check for non-local source tree: {dist}");
+                continue;
+            };
+            if !build_isolation.for_package(Some(&dist.name)).is_isolated() {
+                debug!("Skipping build dependency check for non-isolated build: {dist}");
+                continue;
+            }
+            debug!("Checking build dependencies for: {dist}");
+            resolve_dispatch
+                .check_build_requirements(&dist.install_path)
+                .await
+                .with_context(|| {
+                    format!("Failed to resolve build dependencies for `{}`", dist.name)
+                })?;
+        }
+    }
+
+    // With `--force-reinstall-deps`, reinstall every package in the resolution, other than the
+    // requested packages themselves.
+    let reinstall = if force_reinstall_deps {
+        let requested = requirements
+            .iter()
+            .filter_map(|entry| match &entry.requirement {
+                UnresolvedRequirement::Named(requirement) => Some(&requirement.name),
+                UnresolvedRequirement::Unnamed(_) => None,
+            })
+            .collect::<BTreeSet<_>>();
+        Reinstall::Packages(
+            resolution
+                .packages()
+                .filter(|name| !requested.contains(name))
+                .cloned()
+                .collect(),
+        )
+    } else {
+        reinstall
     };
 
     // Re-initialize the in-flight map.
@@ -388,7 +756,7 @@ pub(crate) async fn pip_install(
     };
 
     // Sync the environment.
-    operations::install(
+    let changelog = operations::install(
         &resolution,
         site_packages,
         Modifications::Sufficient,
@@ -406,18 +774,363 @@ pub(crate) async fn pip_install(
         &cache,
         &environment,
         dry_run,
+        dry_run_format,
         printer,
         preview,
     )
     .await?;
 
+    // Write the installation log, if requested.
+    if let Some(log) = log {
+        write_log(&log, &changelog)?;
+    }
+
+    // Write the checksums of the installed files, if requested.
+    if let Some(checksums) = generate_checksums {
+        let site_packages = SitePackages::from_environment(&environment)?;
+        write_checksums(&checksums, resolution.packages(), &site_packages)?;
+    }
+
+    // Report the packages that were left unchanged, if requested.
+    if report_unchanged || report_skipped {
+        let installed = changelog
+            .installed
+            .iter()
+            .map(Name::name)
+            .collect::<BTreeSet<_>>();
+        let names = resolution
+            .packages()
+            .filter(|name| !installed.contains(name));
+        let site_packages = SitePackages::from_environment(&environment)?;
+        report_unchanged_packages(names, &site_packages, report_skipped, printer)?;
+    }
+
+    // Report the changes to the environment, if requested.
+    if let Some(snapshot) = &snapshot {
+        let site_packages = SitePackages::from_environment(&environment)?;
+        let after = InstalledVersions::from_site_packages(&site_packages);
+        snapshot.report_diff(&after, printer)?;
+    }
+
     // Notify the user of any resolution diagnostics.
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
     // Notify the user of any environment diagnostics.
     if strict && !dry_run {
-        operations::diagnose_environment(&resolution, &environment, printer)?;
+        operations::diagnose_environment(&resolution, &environment, !no_warn_conflicts, printer)?;
     }
 
     Ok(ExitStatus::Success)
 }
+
+/// Remove any requirements that are locked in the project's `uv.lock` and already installed at
+/// the locked version.
+async fn skip_locked_requirements(
+    requirements: Vec<UnresolvedRequirementSpecification>,
+    site_packages: &SitePackages,
+) -> anyhow::Result<Vec<UnresolvedRequirementSpecification>> {
+    let Ok(workspace) = Workspace::discover(&std::env::current_dir()?, None).await else {
+        warn_user!("No project found; ignoring `--skip-locked`");
+        return Ok(requirements);
+    };
+
+    let lockfile = workspace.root().join("uv.lock");
+    let lock: Lock = match fs_err::tokio::read_to_string(&lockfile).await {
+        Ok(encoded) => toml::from_str(&encoded)?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            warn_user!(
+                "No lockfile found at `{}`; ignoring `--skip-locked`",
+                lockfile.user_display()
+            );
+            return Ok(requirements);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    Ok(requirements
+        .into_iter()
+        .filter(|entry| {
+            let UnresolvedRequirement::Named(requirement) = &entry.requirement else {
+                return true;
+            };
+            let RequirementSource::Registry { specifier, .. } = &requirement.source else {
+                return true;
+            };
+            let installed = site_packages.get_packages(&requirement.name);
+            let locked = lock
+                .distributions()
+                .iter()
+                .filter(|dist| dist.name() == &requirement.name)
+                .any(|dist| {
+                    specifier.contains(dist.version())
+                        && installed
+                            .iter()
+                            .any(|installed| installed.version() == dist.version())
+                });
+            if locked {
+                debug!("Skipping locked requirement: {requirement}");
+            }
+            !locked
+        })
+        .collect())
+}
+
+/// A record in an installation log.
+#[derive(Debug, Serialize)]
+struct LogRecord<'a> {
+    action: &'static str,
+    name: &'a PackageName,
+    version: &'a Version,
+    previous_version: Option<&'a Version>,
+    filename: Option<String>,
+    timestamp: &'a str,
+}
+
+/// Append a record for each modified distribution to the installation log at the given path, in
+/// JSON Lines format.
+fn write_log(path: &Path, changelog: &Changelog) -> anyhow::Result<()> {
+    let timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    let mut records = Vec::new();
+    for dist in &changelog.installed {
+        let version = &dist.filename().version;
+        let previous_version = changelog
+            .uninstalled
+            .iter()
+            .find(|uninstalled| uninstalled.name() == dist.name())
+            .map(InstalledDist::version);
+        let action = match previous_version.map(|previous| version.cmp(previous)) {
+            None => "installed",
+            Some(Ordering::Greater) => "upgraded",
+            Some(Ordering::Less) => "downgraded",
+            Some(Ordering::Equal) => "reinstalled",
+        };
+        records.push(LogRecord {
+            action,
+            name: dist.name(),
+            version,
+            previous_version,
+            filename: Some(dist.filename().to_string()),
+            timestamp: &timestamp,
+        });
+    }
+    for dist in &changelog.uninstalled {
+        if changelog
+            .installed
+            .iter()
+            .any(|installed| installed.name() == dist.name())
+        {
+            continue;
+        }
+        records.push(LogRecord {
+            action: "removed",
+            name: dist.name(),
+            version: dist.version(),
+            previous_version: None,
+            filename: None,
+            timestamp: &timestamp,
+        });
+    }
+    records.sort_by(|a, b| a.name.cmp(b.name));
+
+    let mut contents = String::new();
+    for record in records {
+        contents.push_str(&serde_json::to_string(&record)?);
+        contents.push('\n');
+    }
+
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs_err::create_dir_all(parent)?;
+    }
+    let mut file = fs_err::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    std::io::Write::write_all(&mut file, contents.as_bytes())?;
+
+    Ok(())
+}
+
+/// Write the SHA-256 hash of every file installed by the given packages, as listed in each
+/// package's `RECORD`, to the given path.
+///
+/// The checksums are written as CSV if the path has a `.csv` extension, and as a JSON object
+/// otherwise.
+fn write_checksums<'a>(
+    path: &Path,
+    names: impl Iterator<Item = &'a PackageName>,
+    site_packages: &SitePackages,
+) -> anyhow::Result<()> {
+    let mut checksums = BTreeMap::new();
+    for name in names {
+        for dist in site_packages.get_packages(name) {
+            let Some(root) = dist.path().parent() else {
+                continue;
+            };
+            let record = dist.path().join("RECORD");
+            let mut file = match fs_err::File::open(&record) {
+                Ok(file) => file,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                    debug!("No `RECORD` file found for: {dist}");
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            };
+            for entry in install_wheel_rs::read_record_file(&mut file)? {
+                // Skip files without a recorded hash, like the `RECORD` itself.
+                if entry.hash.is_none() {
+                    continue;
+                }
+                let mut hasher = Sha256::new();
+                std::io::copy(
+                    &mut fs_err::File::open(root.join(&entry.path))?,
+                    &mut hasher,
+                )?;
+                checksums.insert(entry.path, format!("{:x}", hasher.finalize()));
+            }
+        }
+    }
+
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs_err::create_dir_all(parent)?;
+    }
+    if path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"))
+    {
+        let mut writer = csv::Writer::from_writer(fs_err::File::create(path)?);
+        writer.write_record(["path", "sha256"])?;
+        for (path, sha256) in &checksums {
+            writer.write_record([path, sha256])?;
+        }
+        writer.flush()?;
+    } else {
+        let mut contents = serde_json::to_string_pretty(&checksums)?;
+        contents.push('\n');
+        fs_err::write(path, contents)?;
+    }
+
+    Ok(())
+}
+
+/// Report the given packages as unchanged, along with their installed versions.
+///
+/// If `pip_style` is set, each package is reported as `Requirement already satisfied`, as in pip.
+fn report_unchanged_packages<'a>(
+    names: impl Iterator<Item = &'a PackageName>,
+    site_packages: &SitePackages,
+    pip_style: bool,
+    printer: Printer,
+) -> anyhow::Result<()> {
+    for name in names.collect::<BTreeSet<_>>() {
+        for dist in site_packages.get_packages(name) {
+            if pip_style {
+                writeln!(
+                    printer.stderr(),
+                    "Requirement already satisfied: {name}{}",
+                    dist.installed_version()
+                )?;
+            } else {
+                writeln!(
+                    printer.stderr(),
+                    " {} {}{}",
+                    "✓".dimmed(),
+                    name.bold(),
+                    dist.installed_version().dimmed()
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The versions of the packages installed in an environment at a point in time.
+#[derive(Debug)]
+struct InstalledVersions(BTreeMap<PackageName, Version>);
+
+impl InstalledVersions {
+    /// Snapshot the installed versions from the given [`SitePackages`].
+    fn from_site_packages(site_packages: &SitePackages) -> Self {
+        Self(
+            site_packages
+                .iter()
+                .map(|dist| (dist.name().clone(), dist.version().clone()))
+                .collect(),
+        )
+    }
+
+    /// Report the packages that were added, removed, upgraded, or downgraded between this snapshot
+    /// and the given (later) snapshot.
+    fn report_diff(&self, after: &Self, printer: Printer) -> anyhow::Result<()> {
+        let mut added = 0;
+        let mut removed = 0;
+        let mut upgraded = 0;
+        let mut downgraded = 0;
+
+        let mut lines = Vec::new();
+        for name in self.0.keys().chain(after.0.keys()).collect::<BTreeSet<_>>() {
+            match (self.0.get(name), after.0.get(name)) {
+                (None, Some(version)) => {
+                    added += 1;
+                    lines.push(format!(
+                        " {} {}{}",
+                        "+".green(),
+                        name.bold(),
+                        format!("=={version}").dimmed()
+                    ));
+                }
+                (Some(version), None) => {
+                    removed += 1;
+                    lines.push(format!(
+                        " {} {}{}",
+                        "-".red(),
+                        name.bold(),
+                        format!("=={version}").dimmed()
+                    ));
+                }
+                (Some(previous), Some(version)) if previous != version => {
+                    if version > previous {
+                        upgraded += 1;
+                    } else {
+                        downgraded += 1;
+                    }
+                    lines.push(format!(
+                        " {} {} {}",
+                        "~".yellow(),
+                        name.bold(),
+                        format!("{previous} -> {version}").dimmed()
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        if lines.is_empty() {
+            writeln!(printer.stderr(), "No changes to the environment")?;
+            return Ok(());
+        }
+
+        let summary = [
+            (added, "added"),
+            (removed, "removed"),
+            (upgraded, "upgraded"),
+            (downgraded, "downgraded"),
+        ]
+        .into_iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, action)| format!("{count} {action}"))
+        .join(", ");
+        writeln!(printer.stderr(), "Changes to the environment: {summary}")?;
+        for line in lines {
+            writeln!(printer.stderr(), "{line}")?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,9 @@
 use std::borrow::Cow;
+use std::str::FromStr;
 
-use pep508_rs::MarkerEnvironment;
+use anyhow::{bail, Context};
+
+use pep508_rs::{MarkerEnvironment, StringVersion};
 use platform_tags::{Tags, TagsError};
 use uv_configuration::TargetTriple;
 use uv_toolchain::{Interpreter, PythonVersion};
@@ -59,3 +62,33 @@ pub(crate) fn resolution_environment(
 
     Ok((tags, markers))
 }
+
+/// Override individual marker variables (e.g., `sys_platform`), as provided by `--marker-env`.
+pub(crate) fn override_markers(
+    markers: &MarkerEnvironment,
+    overrides: &[(String, String)],
+) -> anyhow::Result<MarkerEnvironment> {
+    let version = |key: &str, value: &str| {
+        StringVersion::from_str(value)
+            .with_context(|| format!("Invalid value for marker `{key}`: `{value}`"))
+    };
+
+    let mut markers = markers.clone();
+    for (key, value) in overrides {
+        markers = match key.as_str() {
+            "implementation_name" => markers.with_implementation_name(value),
+            "implementation_version" => markers.with_implementation_version(version(key, value)?),
+            "os_name" => markers.with_os_name(value),
+            "platform_machine" => markers.with_platform_machine(value),
+            "platform_python_implementation" => markers.with_platform_python_implementation(value),
+            "platform_release" => markers.with_platform_release(value),
+            "platform_system" => markers.with_platform_system(value),
+            "platform_version" => markers.with_platform_version(value),
+            "python_full_version" => markers.with_python_full_version(version(key, value)?),
+            "python_version" => markers.with_python_version(version(key, value)?),
+            "sys_platform" => markers.with_sys_platform(value),
+            _ => bail!("Unknown marker variable: `{key}`"),
+        };
+    }
+    Ok(markers)
+}
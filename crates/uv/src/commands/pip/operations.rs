@@ -1,30 +1,40 @@
 //! Common operations shared across the `pip` API and subcommands.
 
+use std::collections::VecDeque;
 use std::fmt::Write;
 use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
 
 use anyhow::{anyhow, Context};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use serde::Serialize;
 use tracing::debug;
+use url::Url;
 
 use distribution_types::{
     CachedDist, Diagnostic, InstalledDist, ResolutionDiagnostic, UnresolvedRequirementSpecification,
 };
 use distribution_types::{
     DistributionMetadata, IndexLocations, InstalledMetadata, LocalDist, Name, Resolution,
+    VersionOrUrlRef,
 };
 use install_wheel_rs::linker::LinkMode;
+use pep440_rs::Version;
 use pep508_rs::MarkerEnvironment;
 use platform_tags::Tags;
-use pypi_types::Requirement;
-use uv_cache::Cache;
+use pypi_types::{ParsedUrl, Requirement};
+use requirements_txt::RequirementsTxtRequirement;
+use uv_cache::{Cache, CacheBucket, CacheEntry};
+use uv_cli::DryRunFormat;
 use uv_client::{BaseClientBuilder, RegistryClient};
 use uv_configuration::{
     BuildOptions, Concurrency, Constraints, ExtrasSpecification, Overrides, PreviewMode, Reinstall,
     Upgrade,
 };
 use uv_dispatch::BuildDispatch;
+use uv_distribution::pyproject::PyProjectToml;
 use uv_distribution::DistributionDatabase;
 use uv_fs::Simplified;
 use uv_installer::{Plan, Planner, Preparer, SitePackages};
@@ -52,26 +62,265 @@ pub(crate) async fn read_requirements(
     overrides: &[RequirementsSource],
     extras: &ExtrasSpecification,
     client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
 ) -> Result<RequirementsSpecification, Error> {
+    // If the user requests `extras` alongside a local project (e.g., `uv pip install --all-extras .`),
+    // apply the extras to the project itself.
+    let mut requirements = requirements.to_vec();
+    let mut project_extras = false;
+    if !extras.is_empty() {
+        for source in &mut requirements {
+            if let Some(with_extras) = with_project_extras(source, extras)? {
+                *source = with_extras;
+                project_extras = true;
+            }
+        }
+    }
+
     // If the user requests `extras` but does not provide a valid source (e.g., a `pyproject.toml`),
     // return an error.
-    if !extras.is_empty() && !requirements.iter().any(RequirementsSource::allows_extras) {
+    if !extras.is_empty()
+        && !project_extras
+        && !requirements.iter().any(RequirementsSource::allows_extras)
+    {
         return Err(anyhow!(
-            "Requesting extras requires a `pyproject.toml`, `setup.cfg`, or `setup.py` file."
+            "Requesting extras requires a `pyproject.toml`, `setup.cfg`, or `setup.py` file, or a path to a local project."
         )
         .into());
     }
 
+    // Read any remote constraints files through the cache.
+    let mut cached_constraints = Vec::with_capacity(constraints.len());
+    for constraint in constraints {
+        cached_constraints
+            .push(cached_remote_constraints(constraint, client_builder, cache).await?);
+    }
+
     // Read all requirements from the provided sources.
     Ok(RequirementsSpecification::from_sources(
-        requirements,
-        constraints,
+        &requirements,
+        &cached_constraints,
         overrides,
         client_builder,
     )
     .await?)
 }
 
+/// If the [`RequirementsSource`] refers to a local project directory (e.g., `.` or `-e ./lib`),
+/// return a source with the requested extras applied to the project (e.g., `.[dev,test]`).
+///
+/// For [`ExtrasSpecification::All`], the extras are read from the `project.optional-dependencies`
+/// table in the project's `pyproject.toml`; dynamic optional dependencies are not supported.
+fn with_project_extras(
+    source: &RequirementsSource,
+    extras: &ExtrasSpecification,
+) -> Result<Option<RequirementsSource>, Error> {
+    let (RequirementsSource::Package(given) | RequirementsSource::Editable(given)) = source else {
+        return Ok(None);
+    };
+    let editable = matches!(source, RequirementsSource::Editable(_));
+
+    // Only consider unnamed requirements that point to a local directory. Any parse errors are
+    // reported when the requirements are read.
+    let Ok(RequirementsTxtRequirement::Unnamed(requirement)) =
+        RequirementsTxtRequirement::parse(given, std::env::current_dir()?, editable)
+    else {
+        return Ok(None);
+    };
+    let ParsedUrl::Directory(directory) = &requirement.url.parsed_url else {
+        return Ok(None);
+    };
+
+    // Ignore any requirements that already include extras or a marker.
+    if !requirement.extras.is_empty() || requirement.marker.is_some() {
+        return Ok(None);
+    }
+
+    let pyproject_toml = directory.install_path.join("pyproject.toml");
+    if !pyproject_toml.is_file() {
+        return Ok(None);
+    }
+
+    let names = match extras {
+        ExtrasSpecification::None => return Ok(None),
+        ExtrasSpecification::All => {
+            let contents = fs_err::read_to_string(&pyproject_toml)?;
+            let pyproject_toml = PyProjectToml::from_string(contents)
+                .with_context(|| format!("Failed to parse: `{}`", pyproject_toml.user_display()))?;
+            pyproject_toml
+                .project
+                .and_then(|project| project.optional_dependencies)
+                .map(|optional_dependencies| optional_dependencies.into_keys().collect())
+                .unwrap_or_default()
+        }
+        ExtrasSpecification::Some(extras) => extras.clone(),
+    };
+
+    debug!(
+        "Applying extras to local project at `{given}`: {}",
+        names.iter().join(", ")
+    );
+
+    // If the project doesn't define any extras, install it as-is.
+    if names.is_empty() {
+        return Ok(Some(source.clone()));
+    }
+
+    let given = format!("{given}[{}]", names.iter().join(","));
+    Ok(Some(if editable {
+        RequirementsSource::Editable(given)
+    } else {
+        RequirementsSource::Package(given)
+    }))
+}
+
+/// The duration for which a downloaded constraints file is reused before being re-downloaded.
+const REMOTE_CONSTRAINTS_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Download a remote constraints file (e.g., `-c https://...`) into the cache, returning a
+/// [`RequirementsSource`] that points to the cached copy.
+///
+/// Any files included by the constraints file (e.g., via `-c` or `-r`) are cached alongside it,
+/// with the includes rewritten to point to the cached copies. Each downloaded file is reused for
+/// [`REMOTE_CONSTRAINTS_TTL`] (unless `--refresh` is provided), or indefinitely when offline. Local
+/// files are returned unchanged.
+async fn cached_remote_constraints(
+    source: &RequirementsSource,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
+) -> Result<RequirementsSource, Error> {
+    let RequirementsSource::ConstraintsTxt(path) = source else {
+        return Ok(source.clone());
+    };
+    let Some(url) = path.to_str().filter(|url| is_remote_url(url)) else {
+        return Ok(source.clone());
+    };
+    let url =
+        Url::parse(url).with_context(|| format!("Failed to parse constraints file URL: {url}"))?;
+
+    // Defer to the requirements parser when offline, to surface a consistent error.
+    if client_builder.is_offline() && !remote_file_entry(cache, &url, "txt").path().is_file() {
+        return Ok(source.clone());
+    }
+
+    // Cache the constraints file and every file that it includes, transitively.
+    let mut seen = FxHashSet::default();
+    let mut queue = VecDeque::from([url.clone()]);
+    seen.insert(url.clone());
+    while let Some(url) = queue.pop_front() {
+        let content = read_remote_file(&url, client_builder, cache).await?;
+
+        // Rewrite each include to point to the cached copy of the included file.
+        let mut resolved = String::with_capacity(content.len());
+        for line in content.lines() {
+            if let Some((flag, target)) = parse_include(line) {
+                let target = if is_remote_url(target) {
+                    Url::parse(target)
+                } else {
+                    url.join(target)
+                }
+                .with_context(|| format!("Failed to resolve `{target}` relative to: {url}"))?;
+                let entry = remote_file_entry(cache, &target, "resolved.txt");
+                resolved.push_str(&format!("{flag} {}", entry.path().simplified_display()));
+                if seen.insert(target.clone()) {
+                    queue.push_back(target);
+                }
+            } else {
+                resolved.push_str(line);
+            }
+            resolved.push('\n');
+        }
+
+        let entry = remote_file_entry(cache, &url, "resolved.txt");
+        fs_err::create_dir_all(entry.dir())?;
+        uv_fs::write_atomic(entry.path(), resolved).await?;
+    }
+
+    let entry = remote_file_entry(cache, &url, "resolved.txt");
+    Ok(RequirementsSource::ConstraintsTxt(entry.into_path_buf()))
+}
+
+/// Read a remote requirements file, reusing the cached copy if it's sufficiently recent, and
+/// downloading it into the cache otherwise.
+async fn read_remote_file(
+    url: &Url,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
+) -> Result<String, Error> {
+    let entry = remote_file_entry(cache, url, "txt");
+
+    // Reuse the cached copy, if it's sufficiently recent.
+    if let Ok(modified) = fs_err::metadata(entry.path()).and_then(|metadata| metadata.modified()) {
+        let age = SystemTime::now()
+            .duration_since(modified)
+            .unwrap_or_default();
+        let fresh = age < REMOTE_CONSTRAINTS_TTL && cache.freshness(&entry, None)?.is_fresh();
+        if client_builder.is_offline() || fresh {
+            debug!("Using cached constraints file for: {url}");
+            return Ok(fs_err::tokio::read_to_string(entry.path()).await?);
+        }
+    }
+
+    debug!("Downloading constraints file from: {url}");
+    let content = client_builder
+        .build()
+        .client()
+        .get(url.clone())
+        .send()
+        .await
+        .and_then(|response| response.error_for_status().map_err(Into::into))
+        .map_err(anyhow::Error::from)
+        .with_context(|| format!("Failed to download constraints file from: {url}"))?
+        .text()
+        .await
+        .with_context(|| format!("Failed to download constraints file from: {url}"))?;
+
+    fs_err::create_dir_all(entry.dir())?;
+    uv_fs::write_atomic(entry.path(), &content).await?;
+
+    Ok(content)
+}
+
+/// Return the cache entry for a remote requirements file with the given extension.
+///
+/// The downloaded file is stored with the `txt` extension, and the copy with its includes
+/// rewritten to point to the cache is stored with the `resolved.txt` extension.
+fn remote_file_entry(cache: &Cache, url: &Url, extension: &str) -> CacheEntry {
+    cache.entry(
+        CacheBucket::Requirements,
+        "",
+        format!("{}.{extension}", cache_key::digest(&url.as_str())),
+    )
+}
+
+/// Returns `true` if the given path refers to a remote file.
+fn is_remote_url(path: &str) -> bool {
+    path.starts_with("http://") || path.starts_with("https://")
+}
+
+/// Parse an include (e.g., `-r other.txt` or `--constraint=other.txt`) from a line of a
+/// requirements file, returning the flag and the included path or URL.
+fn parse_include(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim_start();
+    let (flag, rest) = ["--requirement", "--constraint"]
+        .into_iter()
+        .find_map(|flag| {
+            line.strip_prefix(flag)
+                .filter(|rest| rest.starts_with(|c: char| c == '=' || c.is_whitespace()))
+                .map(|rest| (flag, rest))
+        })
+        .or_else(|| {
+            ["-r", "-c"]
+                .into_iter()
+                .find_map(|flag| line.strip_prefix(flag).map(|rest| (flag, rest)))
+        })?;
+    let target = rest
+        .trim_start_matches(|c: char| c == '=' || c.is_whitespace())
+        .split_whitespace()
+        .next()?;
+    Some((flag, target))
+}
+
 /// Resolve a set of requirements, similar to running `pip compile`.
 pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     requirements: Vec<UnresolvedRequirementSpecification>,
@@ -276,6 +525,17 @@ pub(crate) enum Modifications {
     Exact,
 }
 
+/// The changes made to an environment by [`install`].
+#[derive(Debug, Default)]
+pub(crate) struct Changelog {
+    /// The distributions that were installed, including any reinstalls.
+    pub(crate) installed: Vec<CachedDist>,
+    /// The distributions that were removed, including any that were replaced.
+    pub(crate) uninstalled: Vec<InstalledDist>,
+    /// In a dry run, whether the environment would have been modified.
+    pub(crate) would_modify: bool,
+}
+
 /// Install a set of requirements into the current environment.
 pub(crate) async fn install(
     resolution: &Resolution,
@@ -295,9 +555,10 @@ pub(crate) async fn install(
     cache: &Cache,
     venv: &PythonEnvironment,
     dry_run: bool,
+    dry_run_format: DryRunFormat,
     printer: Printer,
     preview: PreviewMode,
-) -> Result<(), Error> {
+) -> Result<Changelog, Error> {
     let start = std::time::Instant::now();
 
     // Extract the requirements from the resolution.
@@ -319,7 +580,14 @@ pub(crate) async fn install(
         .context("Failed to determine installation plan")?;
 
     if dry_run {
-        return report_dry_run(resolution, plan, modifications, start, printer);
+        let would_modify = match dry_run_format {
+            DryRunFormat::Text => report_dry_run(resolution, plan, modifications, start, printer)?,
+            DryRunFormat::Json => report_dry_run_json(resolution, plan, modifications, printer)?,
+        };
+        return Ok(Changelog {
+            would_modify,
+            ..Changelog::default()
+        });
     }
 
     let Plan {
@@ -348,7 +616,7 @@ pub(crate) async fn install(
             )
             .dimmed()
         )?;
-        return Ok(());
+        return Ok(Changelog::default());
     }
 
     // Map any registry-based requirements back to those returned by the resolver.
@@ -467,20 +735,28 @@ pub(crate) async fn install(
         compile_bytecode(venv, cache, printer).await?;
     }
 
+    let changelog = Changelog {
+        installed: wheels.clone(),
+        uninstalled: extraneous.iter().chain(&reinstalls).cloned().collect(),
+        would_modify: false,
+    };
+
     // Notify the user of any environment modifications.
     report_modifications(wheels, reinstalls, extraneous, printer)?;
 
-    Ok(())
+    Ok(changelog)
 }
 
 /// Report on the results of a dry-run installation.
+///
+/// Returns `true` if the installation would modify the environment.
 fn report_dry_run(
     resolution: &Resolution,
     plan: Plan,
     modifications: Modifications,
     start: std::time::Instant,
     printer: Printer,
-) -> Result<(), Error> {
+) -> Result<bool, Error> {
     let Plan {
         cached,
         remote,
@@ -508,7 +784,7 @@ fn report_dry_run(
             .dimmed()
         )?;
         writeln!(printer.stderr(), "Would make no changes")?;
-        return Ok(());
+        return Ok(false);
     }
 
     // Map any registry-based requirements back to those returned by the resolver.
@@ -611,7 +887,125 @@ fn report_dry_run(
         }
     }
 
-    Ok(())
+    Ok(true)
+}
+
+/// A planned operation in a dry-run installation.
+#[derive(Debug, Serialize)]
+struct DryRunOperation<'a> {
+    op: DryRunOp,
+    name: &'a PackageName,
+    from_version: Option<&'a Version>,
+    to_version: Option<&'a Version>,
+    wheel_url: Option<String>,
+}
+
+#[derive(Debug, Copy, Clone, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum DryRunOp {
+    Install,
+    Upgrade,
+    Remove,
+}
+
+/// Report on the results of a dry-run installation as a JSON array of operations, written to
+/// `stdout`.
+///
+/// Returns `true` if the installation would modify the environment.
+fn report_dry_run_json(
+    resolution: &Resolution,
+    plan: Plan,
+    modifications: Modifications,
+    printer: Printer,
+) -> Result<bool, Error> {
+    let Plan {
+        cached,
+        remote,
+        reinstalls,
+        extraneous,
+    } = plan;
+
+    // If we're in `install` mode, ignore any extraneous distributions.
+    let extraneous = match modifications {
+        Modifications::Sufficient => vec![],
+        Modifications::Exact => extraneous,
+    };
+
+    // Map any registry-based requirements back to those returned by the resolver.
+    let remote = remote
+        .iter()
+        .map(|dist| {
+            resolution
+                .get_remote(&dist.name)
+                .expect("Resolution should contain all packages")
+        })
+        .collect::<Vec<_>>();
+
+    // Any distribution that replaces an existing installation is reported as an upgrade.
+    let previous = |name: &PackageName| {
+        reinstalls
+            .iter()
+            .find(|dist| dist.name() == name)
+            .map(InstalledDist::version)
+    };
+    let op = |from_version: Option<&Version>| {
+        if from_version.is_some() {
+            DryRunOp::Upgrade
+        } else {
+            DryRunOp::Install
+        }
+    };
+
+    let mut operations = Vec::new();
+    for dist in &remote {
+        let from_version = previous(dist.name());
+        let wheel_url = match dist.version_or_url() {
+            VersionOrUrlRef::Url(url) => Some(url.to_string()),
+            VersionOrUrlRef::Version(_) => dist
+                .file()
+                .and_then(|file| file.url.to_url().ok())
+                .map(|url| url.to_string()),
+        };
+        operations.push(DryRunOperation {
+            op: op(from_version),
+            name: dist.name(),
+            from_version,
+            to_version: dist.version(),
+            wheel_url,
+        });
+    }
+    for dist in &cached {
+        let from_version = previous(dist.name());
+        operations.push(DryRunOperation {
+            op: op(from_version),
+            name: dist.name(),
+            from_version,
+            to_version: Some(&dist.filename().version),
+            wheel_url: None,
+        });
+    }
+    for dist in reinstalls.iter().chain(&extraneous) {
+        if operations
+            .iter()
+            .any(|operation| operation.name == dist.name())
+        {
+            continue;
+        }
+        operations.push(DryRunOperation {
+            op: DryRunOp::Remove,
+            name: dist.name(),
+            from_version: Some(dist.version()),
+            to_version: None,
+            wheel_url: None,
+        });
+    }
+    operations.sort_by(|a, b| a.name.cmp(b.name));
+
+    let output =
+        serde_json::to_string(&operations).context("Failed to serialize the installation plan")?;
+    writeln!(printer.stdout(), "{output}")?;
+
+    Ok(!operations.is_empty())
 }
 
 /// Report on any modifications to the Python environment.
@@ -682,9 +1076,12 @@ pub(crate) fn diagnose_resolution(
 }
 
 /// Report any diagnostics on installed distributions in the Python environment.
+///
+/// If `warn` is `false`, the diagnostics are only emitted to the log.
 pub(crate) fn diagnose_environment(
     resolution: &Resolution,
     venv: &PythonEnvironment,
+    warn: bool,
     printer: Printer,
 ) -> Result<(), Error> {
     let site_packages = SitePackages::from_environment(venv)?;
@@ -694,6 +1091,10 @@ pub(crate) fn diagnose_environment(
             .packages()
             .any(|package| diagnostic.includes(package))
         {
+            if !warn {
+                tracing::warn!("{}", diagnostic.message());
+                continue;
+            }
             writeln!(
                 printer.stderr(),
                 "{}{} {}",
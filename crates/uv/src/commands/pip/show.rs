@@ -1,9 +1,11 @@
 use std::fmt::Write;
+use std::path::PathBuf;
 
 use anyhow::Result;
 use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
+use serde::Serialize;
 use tracing::debug;
 
 use distribution_types::{Diagnostic, Name};
@@ -21,6 +23,7 @@ use crate::printer::Printer;
 pub(crate) fn pip_show(
     mut packages: Vec<PackageName>,
     strict: bool,
+    json: bool,
     python: Option<&str>,
     system: bool,
     _preview: PreviewMode,
@@ -131,64 +134,86 @@ pub(crate) fn pip_show(
         }
     }
 
-    // Print the information for each package.
-    for (i, distribution) in distributions.iter().enumerate() {
-        if i > 0 {
-            // Print a separator between packages.
-            writeln!(printer.stdout(), "---")?;
-        }
-
-        // Print the name, version, and location (e.g., the `site-packages` directory).
-        writeln!(printer.stdout(), "Name: {}", distribution.name())?;
-        writeln!(printer.stdout(), "Version: {}", distribution.version())?;
-        writeln!(
-            printer.stdout(),
-            "Location: {}",
-            distribution
-                .path()
-                .parent()
-                .expect("package path is not root")
-                .simplified_display()
-        )?;
+    // Collect the information for each package.
+    let entries = distributions
+        .iter()
+        .map(|distribution| {
+            let requires = requires_map.get(distribution.name()).cloned();
+            let required_by = requires.as_ref().map(|_| {
+                requires_map
+                    .iter()
+                    .filter(|(name, pkgs)| {
+                        **name != distribution.name()
+                            && pkgs.iter().any(|pkg| pkg == distribution.name())
+                    })
+                    .map(|(name, _)| (*name).clone())
+                    .sorted_unstable()
+                    .dedup()
+                    .collect_vec()
+            });
+            ShowEntry {
+                name: distribution.name().clone(),
+                version: distribution.version().to_string(),
+                location: distribution
+                    .path()
+                    .parent()
+                    .expect("package path is not root")
+                    .to_path_buf(),
+                editable_project_location: distribution
+                    .as_editable()
+                    .and_then(|url| url.to_file_path().ok()),
+                requires,
+                required_by,
+            }
+        })
+        .collect_vec();
+
+    if json {
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&entries)?)?;
+    } else {
+        // Print the information for each package.
+        for (i, entry) in entries.iter().enumerate() {
+            if i > 0 {
+                // Print a separator between packages.
+                writeln!(printer.stdout(), "---")?;
+            }
 
-        if let Some(path) = distribution
-            .as_editable()
-            .and_then(|url| url.to_file_path().ok())
-        {
+            // Print the name, version, and location (e.g., the `site-packages` directory).
+            writeln!(printer.stdout(), "Name: {}", entry.name)?;
+            writeln!(printer.stdout(), "Version: {}", entry.version)?;
             writeln!(
                 printer.stdout(),
-                "Editable project location: {}",
-                path.simplified_display()
+                "Location: {}",
+                entry.location.simplified_display()
             )?;
-        }
 
-        // If available, print the requirements.
-        if let Some(requires) = requires_map.get(distribution.name()) {
-            if requires.is_empty() {
-                writeln!(printer.stdout(), "Requires:")?;
-            } else {
-                writeln!(printer.stdout(), "Requires: {}", requires.iter().join(", "))?;
-            }
-
-            let required_by = requires_map
-                .iter()
-                .filter(|(name, pkgs)| {
-                    **name != distribution.name()
-                        && pkgs.iter().any(|pkg| pkg == distribution.name())
-                })
-                .map(|(name, _)| name)
-                .sorted_unstable()
-                .dedup()
-                .collect_vec();
-            if required_by.is_empty() {
-                writeln!(printer.stdout(), "Required-by:")?;
-            } else {
+            if let Some(path) = &entry.editable_project_location {
                 writeln!(
                     printer.stdout(),
-                    "Required-by: {}",
-                    required_by.into_iter().join(", "),
+                    "Editable project location: {}",
+                    path.simplified_display()
                 )?;
             }
+
+            // If available, print the requirements.
+            if let Some(requires) = &entry.requires {
+                if requires.is_empty() {
+                    writeln!(printer.stdout(), "Requires:")?;
+                } else {
+                    writeln!(printer.stdout(), "Requires: {}", requires.iter().join(", "))?;
+                }
+            }
+            if let Some(required_by) = &entry.required_by {
+                if required_by.is_empty() {
+                    writeln!(printer.stdout(), "Required-by:")?;
+                } else {
+                    writeln!(
+                        printer.stdout(),
+                        "Required-by: {}",
+                        required_by.iter().join(", "),
+                    )?;
+                }
+            }
         }
     }
 
@@ -207,3 +232,17 @@ pub(crate) fn pip_show(
 
     Ok(ExitStatus::Success)
 }
+
+/// The information displayed for an installed package.
+#[derive(Serialize)]
+struct ShowEntry {
+    name: PackageName,
+    version: String,
+    location: PathBuf,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    editable_project_location: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requires: Option<Vec<PackageName>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_by: Option<Vec<PackageName>>,
+}
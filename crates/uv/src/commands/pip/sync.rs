@@ -9,6 +9,7 @@ use distribution_types::{IndexLocations, Resolution};
 use install_wheel_rs::linker::LinkMode;
 use uv_auth::store_credentials_from_url;
 use uv_cache::Cache;
+use uv_cli::DryRunFormat;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, ExtrasSpecification, IndexStrategy, PreviewMode,
@@ -100,6 +101,7 @@ pub(crate) async fn pip_sync(
         overrides,
         &extras,
         &client_builder,
+        &cache,
     )
     .await?;
 
@@ -358,6 +360,7 @@ pub(crate) async fn pip_sync(
         &cache,
         &environment,
         dry_run,
+        DryRunFormat::Text,
         printer,
         preview,
     )
@@ -368,7 +371,7 @@ pub(crate) async fn pip_sync(
 
     // Notify the user of any environment diagnostics.
     if strict && !dry_run {
-        operations::diagnose_environment(&resolution, &environment, printer)?;
+        operations::diagnose_environment(&resolution, &environment, true, printer)?;
     }
 
     Ok(ExitStatus::Success)
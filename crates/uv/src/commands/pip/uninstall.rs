@@ -1,6 +1,8 @@
 use std::fmt::Write;
+use std::io::IsTerminal;
 
 use anyhow::Result;
+use console::Term;
 use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
 use tracing::debug;
@@ -13,7 +15,7 @@ use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::{KeyringProviderType, PreviewMode};
 use uv_fs::Simplified;
-use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_requirements::{confirm, RequirementsSource, RequirementsSpecification};
 use uv_toolchain::EnvironmentPreference;
 use uv_toolchain::ToolchainRequest;
 use uv_toolchain::{Prefix, PythonEnvironment, Target};
@@ -22,6 +24,7 @@ use crate::commands::{elapsed, ExitStatus};
 use crate::printer::Printer;
 
 /// Uninstall packages from the current environment.
+#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn pip_uninstall(
     sources: &[RequirementsSource],
     python: Option<String>,
@@ -34,6 +37,7 @@ pub(crate) async fn pip_uninstall(
     native_tls: bool,
     _preview: PreviewMode,
     keyring_provider: KeyringProviderType,
+    yes: bool,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
@@ -179,6 +183,25 @@ pub(crate) async fn pip_uninstall(
         distributions
     };
 
+    // Unless `--yes` was provided, confirm each removal in an interactive terminal.
+    let term = Term::stderr();
+    let distributions = if yes || !std::io::stdout().is_terminal() || !term.is_term() {
+        distributions
+    } else {
+        let mut confirmed = Vec::with_capacity(distributions.len());
+        for distribution in distributions {
+            let prompt = format!(
+                "Remove package {}{}?",
+                distribution.name(),
+                distribution.installed_version()
+            );
+            if confirm(&prompt, &term, false)? {
+                confirmed.push(distribution);
+            }
+        }
+        confirmed
+    };
+
     if distributions.is_empty() {
         writeln!(
             printer.stderr(),
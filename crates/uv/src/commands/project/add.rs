@@ -1,23 +1,38 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+
 use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use similar::{ChangeTag, TextDiff};
+use tracing::debug;
+use url::Url;
 
-use pep508_rs::ExtraName;
+use distribution_types::{IndexUrl, Verbatim};
+use pep440_rs::{Operator, VersionSpecifiers};
+use pep508_rs::{ExtraName, MarkerTree};
 use uv_cache::Cache;
+use uv_cli::DiffFormat;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{Concurrency, ExtrasSpecification, PreviewMode, SetupPyStrategy};
 use uv_dispatch::BuildDispatch;
 use uv_distribution::pyproject::{DependencyType, Source, SourceError};
 use uv_distribution::pyproject_mut::PyProjectTomlMut;
 use uv_distribution::{DistributionDatabase, ProjectWorkspace, VirtualProject, Workspace};
+use uv_fs::Simplified;
 use uv_git::GitResolver;
 use uv_normalize::PackageName;
+use uv_requirements::upgrade::read_lock;
 use uv_requirements::{NamedRequirementsResolver, RequirementsSource, RequirementsSpecification};
 use uv_resolver::{FlatIndex, InMemoryIndex};
-use uv_toolchain::{ToolchainPreference, ToolchainRequest};
-use uv_types::{BuildIsolation, HashStrategy, InFlight};
+use uv_toolchain::{PythonEnvironment, ToolchainPreference, ToolchainRequest};
+use uv_types::{HashStrategy, InFlight};
 use uv_warnings::warn_user_once;
 
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::resolution_environment;
+use crate::commands::project::lock::{LockAnnotations, LockedVersions};
+use crate::commands::project::FoundInterpreter;
 use crate::commands::reporters::ResolverReporter;
 use crate::commands::{project, ExitStatus};
 use crate::printer::Printer;
@@ -33,10 +48,17 @@ pub(crate) async fn add(
     rev: Option<String>,
     tag: Option<String>,
     branch: Option<String>,
+    index: Option<IndexUrl>,
     extras: Vec<ExtraName>,
+    python_version: Option<String>,
+    no_build_isolation: Vec<PackageName>,
+    reuse_pinned: bool,
+    dry_run: bool,
+    format: DiffFormat,
+    directory: &Path,
     package: Option<PackageName>,
     python: Option<String>,
-    settings: ResolverInstallerSettings,
+    mut settings: ResolverInstallerSettings,
     toolchain_preference: ToolchainPreference,
     preview: PreviewMode,
     connectivity: Connectivity,
@@ -49,27 +71,81 @@ pub(crate) async fn add(
         warn_user_once!("`uv add` is experimental and may change without warning.");
     }
 
+    // If the tag is a bare version (e.g., `1.2.3`), match it against the tags of the repository,
+    // which conventionally include a `v` prefix (e.g., `v1.2.3`).
+    let tag = match tag {
+        Some(tag) if tag.starts_with(|c: char| c.is_ascii_digit()) => {
+            Some(resolve_version_tag(&requirements, tag, connectivity).await?)
+        }
+        tag => tag,
+    };
+
+    // If a Git reference was provided, expand any GitHub shorthand (e.g., `astral-sh/uv`) into a
+    // Git URL.
+    let requirements = if let Some(reference) = rev.as_ref().or(tag.as_ref()).or(branch.as_ref()) {
+        requirements
+            .into_iter()
+            .map(|source| expand_git_shorthand(source, reference))
+            .collect::<Result<Vec<_>>>()?
+    } else {
+        requirements
+    };
+
+    // Include the `--index` when resolving, alongside any other indexes.
+    if let Some(index) = &index {
+        settings.index_locations =
+            settings
+                .index_locations
+                .combine(None, vec![index.clone()], vec![], false);
+    }
+
+    // Convert the `--python-version` specifiers to a marker.
+    let python_version_marker = python_version
+        .as_deref()
+        .map(python_version_marker)
+        .transpose()?;
+
     // Find the project in the workspace.
     let project = if let Some(package) = package {
-        Workspace::discover(&std::env::current_dir()?, None)
+        Workspace::discover(directory, None)
             .await?
             .with_current_project(package.clone())
             .with_context(|| format!("Package `{package}` not found in workspace"))?
     } else {
-        ProjectWorkspace::discover(&std::env::current_dir()?, None).await?
+        ProjectWorkspace::discover(directory, None).await?
     };
 
-    // Discover or create the virtual environment.
-    let venv = project::get_or_init_environment(
-        project.workspace(),
-        python.as_deref().map(ToolchainRequest::parse),
-        toolchain_preference,
-        connectivity,
-        native_tls,
-        cache,
-        printer,
-    )
-    .await?;
+    // Discover or create the virtual environment. In a dry run, avoid creating the environment,
+    // and fall back to the discovered interpreter instead.
+    let venv = if dry_run {
+        match FoundInterpreter::discover(
+            project.workspace(),
+            python.as_deref().map(ToolchainRequest::parse),
+            toolchain_preference,
+            connectivity,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await?
+        {
+            FoundInterpreter::Environment(venv) => venv,
+            FoundInterpreter::Interpreter(interpreter) => {
+                PythonEnvironment::from_interpreter(interpreter)
+            }
+        }
+    } else {
+        project::get_or_init_environment(
+            project.workspace(),
+            python.as_deref().map(ToolchainRequest::parse),
+            toolchain_preference,
+            connectivity,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await?
+    };
 
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
@@ -86,7 +162,14 @@ pub(crate) async fn add(
     let python_platform = None;
     let hasher = HashStrategy::default();
     let setup_py = SetupPyStrategy::default();
-    let build_isolation = BuildIsolation::default();
+
+    // Disable build isolation for the requested packages, alongside any packages declared in
+    // `tool.uv.no-build-isolation-package`, building them in the project environment.
+    let mut no_build_isolation_packages = project.workspace().no_build_isolation_packages();
+    no_build_isolation_packages.extend(no_build_isolation.iter().cloned());
+    no_build_isolation_packages.sort_unstable();
+    no_build_isolation_packages.dedup();
+    let build_isolation = project::build_isolation(&venv, &no_build_isolation_packages);
 
     // Determine the environment for the resolution.
     let (tags, markers) =
@@ -147,14 +230,27 @@ pub(crate) async fn add(
     .resolve()
     .await?;
 
-    // Add the requirements to the `pyproject.toml`.
+    // Add the requirements to the `pyproject.toml`, retaining them for resolution in a dry run.
     let mut pyproject = PyProjectTomlMut::from_toml(project.current_project().pyproject_toml())?;
+    let mut added = Vec::with_capacity(requirements.len());
     for mut req in requirements {
         // Add the specified extras.
         req.extras.extend(extras.iter().cloned());
         req.extras.sort_unstable();
         req.extras.dedup();
 
+        // Add the `--python-version` marker, alongside any existing marker.
+        if let Some(marker) = &python_version_marker {
+            match req.marker.as_mut() {
+                Some(existing) => existing.and(marker.clone()),
+                None => req.marker = Some(marker.clone()),
+            }
+        }
+
+        if dry_run {
+            added.push(req.clone());
+        }
+
         let (req, source) = if raw_sources {
             // Use the PEP 508 requirement directly.
             (pep508_rs::Requirement::from(req), None)
@@ -179,6 +275,20 @@ pub(crate) async fn add(
                 Err(err) => return Err(err.into()),
             };
 
+            // Record the `--index` as the source of registry requirements, if provided.
+            let source = match (source, &index) {
+                (None, Some(index)) => Some(Source::Registry {
+                    index: index.verbatim().to_string(),
+                }),
+                (Some(_), Some(_)) => {
+                    anyhow::bail!(
+                        "`--index` can only be used with requirements that are resolved from a registry, but `{}` is not",
+                        req.name
+                    )
+                }
+                (source, None) => source,
+            };
+
             // Ignore the PEP 508 source.
             let mut req = pep508_rs::Requirement::from(req);
             req.clear_url();
@@ -193,23 +303,83 @@ pub(crate) async fn add(
             DependencyType::Dev => {
                 pyproject.add_dev_dependency(req, source)?;
             }
+            DependencyType::DevScript => {
+                pyproject.add_dev_script(req, source)?;
+            }
             DependencyType::Optional(ref group) => {
                 pyproject.add_optional_dependency(req, group, source)?;
             }
         }
     }
 
+    // Add the `--index` to the project's indexes, such that subsequent resolutions (e.g., `uv lock`)
+    // include it.
+    if let Some(index) = &index {
+        pyproject.add_extra_index_url(&index.verbatim())?;
+    }
+
+    // Record the packages to build without build isolation, such that subsequent commands (e.g.,
+    // `uv sync`) build them in the same way.
+    for package in &no_build_isolation {
+        pyproject.add_no_build_isolation_package(package)?;
+    }
+
+    let locked_versions = if reuse_pinned {
+        LockedVersions::Pin
+    } else {
+        LockedVersions::Prefer
+    };
+
+    // In a dry run, resolve the project alongside the new requirements to ensure that they're
+    // satisfiable, then print the changes rather than saving them.
+    let pyproject_path = project.current_project().root().join("pyproject.toml");
+    if dry_run {
+        let existing = read_lock(&project.workspace().root().join("uv.lock")).await?;
+        project::lock::do_resolve(
+            project.workspace(),
+            added,
+            venv.interpreter(),
+            settings.as_ref().into(),
+            build_isolation,
+            locked_versions,
+            existing.as_ref(),
+            preview,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await?;
+
+        let modified = pyproject.to_string();
+        match format {
+            DiffFormat::Diff => {
+                let original = fs_err::read_to_string(&pyproject_path)?;
+                let path = pyproject_path.user_display().to_string();
+                print_diff(&original, &modified, &path, printer)?;
+            }
+            DiffFormat::Full => {
+                write!(printer.stdout(), "{modified}")?;
+            }
+        }
+        return Ok(ExitStatus::Success);
+    }
+
     // Save the modified `pyproject.toml`.
-    fs_err::write(
-        project.current_project().root().join("pyproject.toml"),
-        pyproject.to_string(),
-    )?;
+    fs_err::write(&pyproject_path, pyproject.to_string())?;
 
     // Lock and sync the environment.
     let lock = project::lock::do_lock(
         project.workspace(),
         venv.interpreter(),
         settings.as_ref().into(),
+        build_isolation,
+        locked_versions,
+        LockAnnotations::default(),
+        None,
+        false,
+        None,
         preview,
         connectivity,
         concurrency,
@@ -231,7 +401,10 @@ pub(crate) async fn add(
         extras,
         dev,
         Modifications::Sufficient,
+        false,
+        false,
         settings.as_ref().into(),
+        build_isolation,
         preview,
         connectivity,
         concurrency,
@@ -243,3 +416,183 @@ pub(crate) async fn add(
 
     Ok(ExitStatus::Success)
 }
+
+/// Convert a set of Python version specifiers (e.g., `>=3.12`) into a `python_version` marker.
+///
+/// Since `python_version` only includes the major and minor version, specifiers that refer to a
+/// patch, pre-release, post-release, development, or local version are rejected, as they would
+/// produce a marker that doesn't match the intended versions.
+fn python_version_marker(specifiers: &str) -> Result<MarkerTree> {
+    let specifiers = VersionSpecifiers::from_str(specifiers).with_context(|| {
+        format!("`{specifiers}` is not a valid Python version specifier (e.g., `>=3.12`)")
+    })?;
+    if specifiers.is_empty() {
+        anyhow::bail!(
+            "`--python-version` requires at least one version specifier (e.g., `>=3.12`)"
+        );
+    }
+
+    let mut expressions = Vec::with_capacity(specifiers.len());
+    for specifier in specifiers.iter() {
+        let version = specifier.version();
+        if version.release().len() > 2
+            || version.epoch() != 0
+            || version.any_prerelease()
+            || version.is_post()
+            || version.is_local()
+            || *specifier.operator() == Operator::ExactEqual
+        {
+            anyhow::bail!("`{specifier}` can't be expressed as a `python_version` marker; use a major and minor version (e.g., `>=3.12`), or write the marker on the requirement directly");
+        }
+        let star = match specifier.operator() {
+            Operator::EqualStar | Operator::NotEqualStar => ".*",
+            _ => "",
+        };
+        expressions.push(MarkerTree::from_str(&format!(
+            "python_version {} '{version}{star}'",
+            specifier.operator()
+        ))?);
+    }
+
+    if expressions.len() == 1 {
+        Ok(expressions.remove(0))
+    } else {
+        Ok(MarkerTree::And(expressions))
+    }
+}
+
+/// Expand a GitHub shorthand requirement (e.g., `astral-sh/uv`) into a Git URL at the given
+/// reference.
+///
+/// Requirements that already include a URL are returned unchanged.
+fn expand_git_shorthand(source: RequirementsSource, reference: &str) -> Result<RequirementsSource> {
+    let RequirementsSource::Package(requirement) = source else {
+        return Ok(source);
+    };
+
+    if requirement.contains("://") {
+        return Ok(RequirementsSource::Package(requirement));
+    }
+
+    let Some(url) = github_shorthand_url(&requirement) else {
+        anyhow::bail!("`{requirement}` is not a Git URL or GitHub shorthand (e.g., `owner/repo`); a Git reference (`--rev`, `--tag`, or `--branch`) can only be used with Git requirements");
+    };
+
+    Ok(RequirementsSource::Package(format!(
+        "git+{url}@{reference}"
+    )))
+}
+
+/// Return the URL of the GitHub repository referenced by a shorthand requirement (e.g.,
+/// `astral-sh/uv`), if the requirement is in that form.
+fn github_shorthand_url(requirement: &str) -> Option<String> {
+    let is_segment = |segment: &str| {
+        segment.starts_with(|c: char| c.is_ascii_alphanumeric())
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    };
+    let (owner, repository) = requirement
+        .split_once('/')
+        .filter(|(owner, repository)| is_segment(owner) && is_segment(repository))?;
+
+    let repository = repository.strip_suffix(".git").unwrap_or(repository);
+    Some(format!("https://github.com/{owner}/{repository}"))
+}
+
+/// Return the URL of the Git repository referenced by a requirement, whether as an explicit Git
+/// URL (e.g., `git+https://github.com/astral-sh/uv`) or as GitHub shorthand (e.g.,
+/// `astral-sh/uv`), without any Git reference.
+fn git_repository_url(requirement: &str) -> Option<Url> {
+    if let Some((_, url)) = requirement.split_once("git+") {
+        let url = url.split(|c: char| c.is_whitespace() || c == ';').next()?;
+        let mut url = Url::parse(url).ok()?;
+        url.set_fragment(None);
+        if let Some((path, _reference)) = url.path().rsplit_once('@') {
+            let path = path.to_string();
+            url.set_path(&path);
+        }
+        return Some(url);
+    }
+
+    if requirement.contains("://") {
+        return None;
+    }
+
+    github_shorthand_url(requirement).and_then(|url| Url::parse(&url).ok())
+}
+
+/// Resolve a tag given as a bare version (e.g., `1.2.3`) to the matching tag in the repository of
+/// the first Git requirement, trying the version as-is and with a `v` prefix (e.g., `v1.2.3`).
+///
+/// If none of the requirements are Git requirements, or if the network is unavailable, the tag is
+/// returned unchanged.
+async fn resolve_version_tag(
+    requirements: &[RequirementsSource],
+    version: String,
+    connectivity: Connectivity,
+) -> Result<String> {
+    let Some(url) = requirements.iter().find_map(|source| match source {
+        RequirementsSource::Package(requirement) => git_repository_url(requirement),
+        _ => None,
+    }) else {
+        return Ok(version);
+    };
+
+    if matches!(connectivity, Connectivity::Offline) {
+        debug!("Skipping tag lookup for `{url}` in offline mode");
+        return Ok(version);
+    }
+
+    let candidates = vec![version.clone(), format!("v{version}")];
+    let tags = tokio::task::spawn_blocking({
+        let url = url.clone();
+        let candidates = candidates.clone();
+        move || uv_git::remote_tags(&url, &candidates)
+    })
+    .await?
+    .with_context(|| format!("Failed to list the tags of `{url}`"))?;
+    let Some(tag) = candidates
+        .into_iter()
+        .find(|candidate| tags.contains(candidate))
+    else {
+        anyhow::bail!("Neither `{version}` nor `v{version}` is a tag of `{url}`");
+    };
+
+    debug!("Resolved tag `{version}` to `{tag}` in `{url}`");
+    Ok(tag)
+}
+
+/// Print a unified diff between two versions of a file, with three lines of context.
+fn print_diff(original: &str, modified: &str, path: &str, printer: Printer) -> Result<()> {
+    let diff = TextDiff::from_lines(original, modified);
+    let mut unified = diff.unified_diff();
+    let unified = unified.context_radius(3);
+
+    let mut hunks = unified.iter_hunks().peekable();
+    if hunks.peek().is_none() {
+        return Ok(());
+    }
+
+    writeln!(printer.stdout(), "--- {path}")?;
+    writeln!(printer.stdout(), "+++ {path}")?;
+    for hunk in hunks {
+        writeln!(printer.stdout(), "{}", hunk.header().cyan())?;
+        for change in hunk.iter_changes() {
+            let line = change.value().trim_end_matches(['\r', '\n']);
+            match change.tag() {
+                ChangeTag::Equal => {
+                    writeln!(printer.stdout(), " {line}")?;
+                }
+                ChangeTag::Delete => {
+                    writeln!(printer.stdout(), "{}", format!("-{line}").red())?;
+                }
+                ChangeTag::Insert => {
+                    writeln!(printer.stdout(), "{}", format!("+{line}").green())?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,261 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{Datelike, Utc};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_cli::{DiffFormat, License};
+use uv_client::Connectivity;
+use uv_configuration::{Concurrency, PackageNameSpecifiers, PreviewMode};
+use uv_distribution::pyproject::DependencyType;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_requirements::RequirementsSource;
+use uv_toolchain::ToolchainPreference;
+use uv_warnings::{warn_user, warn_user_once};
+
+use crate::commands::project::add::add;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
+
+/// The default `.gitignore` for new projects.
+///
+/// Intentionally minimal, and embedded (rather than fetched) so that `uv init` works offline.
+const GITIGNORE: &str = r"# Python-generated files
+__pycache__/
+*.pyc
+build/
+dist/
+wheels/
+*.egg-info
+
+# Virtual environments
+.venv/
+";
+
+/// The classifier that marks a project as private, such that package indexes like PyPI reject
+/// uploads of it.
+pub(crate) const PRIVATE_CLASSIFIER: &str = "Private :: Do Not Upload";
+
+/// The placeholder author used in generated `LICENSE` files.
+const LICENSE_AUTHOR: &str = "<author>";
+
+/// Create a new project.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn init(
+    explicit_path: Option<String>,
+    name: Option<PackageName>,
+    gitignore: bool,
+    license: Option<License>,
+    workspace_root: bool,
+    flat_layout: bool,
+    private: bool,
+    dependencies: Vec<String>,
+    settings: ResolverInstallerSettings,
+    toolchain_preference: ToolchainPreference,
+    preview: PreviewMode,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv init` is experimental and may change without warning.");
+    }
+
+    // Default to the current directory if a path was not provided.
+    let path = match explicit_path {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+
+    // Make sure a project does not already exist in the given directory.
+    if path.join("pyproject.toml").try_exists()? {
+        anyhow::bail!(
+            "Project is already initialized in `{}`",
+            path.simplified_display()
+        );
+    }
+
+    if workspace_root {
+        return init_workspace_root(&path, gitignore, printer);
+    }
+
+    // Default to the directory name if a name was not provided.
+    let name = match name {
+        Some(name) => name,
+        None => {
+            let path = uv_fs::absolutize_path(&path)?;
+            let name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .context("Unable to determine the project name from the path; use `--name`")?;
+            PackageName::new(name.to_string())
+                .with_context(|| format!("`{name}` is not a valid project name; use `--name`"))?
+        }
+    };
+
+    init_project(&path, &name, license, flat_layout, private)?;
+
+    if gitignore {
+        write_gitignore(&path)?;
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Initialized project `{}` at `{}`",
+        name.cyan(),
+        path.simplified_display().cyan()
+    )?;
+
+    if private {
+        warn_user!(
+            "`{name}` is marked as private (`{PRIVATE_CLASSIFIER}`), so `uv workspace publish` will skip it"
+        );
+    }
+
+    // Add any initial dependencies, as with `uv add`, which also locks and syncs the project.
+    if !dependencies.is_empty() {
+        let requirements = dependencies
+            .into_iter()
+            .map(RequirementsSource::from_package)
+            .collect();
+        return add(
+            requirements,
+            None,
+            DependencyType::Production,
+            false,
+            None,
+            None,
+            None,
+            None,
+            vec![],
+            None,
+            PackageNameSpecifiers::None,
+            false,
+            false,
+            DiffFormat::default(),
+            &uv_fs::absolutize_path(&path)?,
+            None,
+            None,
+            settings,
+            toolchain_preference,
+            preview,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Create the `pyproject.toml`, package directory, and (optionally) `LICENSE` for a new project at
+/// the given path.
+///
+/// The package is placed in `src/{name}/`, or in `{name}/` at the project root if `flat_layout`
+/// is set. If `private` is set, the project is marked with the [`PRIVATE_CLASSIFIER`].
+pub(crate) fn init_project(
+    path: &Path,
+    name: &PackageName,
+    license: Option<License>,
+    flat_layout: bool,
+    private: bool,
+) -> Result<()> {
+    // Create the source directory for the project.
+    let src_dir = if flat_layout {
+        path.join(name.as_dist_info_name().as_ref())
+    } else {
+        path.join("src").join(name.as_dist_info_name().as_ref())
+    };
+    fs_err::create_dir_all(&src_dir)?;
+
+    // Create the `pyproject.toml`.
+    let license_field = license
+        .map(|license| format!("license = {{ text = \"{}\" }}\n", license.spdx_id()))
+        .unwrap_or_default();
+    let classifiers_field = if private {
+        format!("classifiers = [\"{PRIVATE_CLASSIFIER}\"]\n")
+    } else {
+        String::new()
+    };
+    let pyproject = format!(
+        r#"[project]
+name = "{name}"
+version = "0.1.0"
+{license_field}{classifiers_field}dependencies = []
+"#
+    );
+    fs_err::write(path.join("pyproject.toml"), pyproject)?;
+
+    // Create an empty `{name}/__init__.py`, if it does not already exist.
+    let init_py = src_dir.join("__init__.py");
+    if !init_py.try_exists()? {
+        fs_err::write(init_py, String::new())?;
+    }
+
+    // Create the `LICENSE`, if it does not already exist.
+    if let Some(license) = license {
+        let license_file = path.join("LICENSE");
+        if !license_file.try_exists()? {
+            fs_err::write(license_file, license_text(license, Utc::now().year()))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Create a virtual workspace root, i.e., a `pyproject.toml` without a `[project]` table.
+fn init_workspace_root(path: &Path, gitignore: bool, printer: Printer) -> Result<ExitStatus> {
+    fs_err::create_dir_all(path)?;
+
+    // Create the `pyproject.toml`.
+    let pyproject = r"[tool.uv.workspace]
+members = []
+";
+    fs_err::write(path.join("pyproject.toml"), pyproject)?;
+
+    if gitignore {
+        write_gitignore(path)?;
+    }
+
+    writeln!(
+        printer.stderr(),
+        "Initialized workspace root at `{}`",
+        path.simplified_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Create the `.gitignore`, if it does not already exist.
+fn write_gitignore(path: &Path) -> Result<()> {
+    let gitignore = path.join(".gitignore");
+    if !gitignore.try_exists()? {
+        fs_err::write(gitignore, GITIGNORE)?;
+    }
+    Ok(())
+}
+
+/// Render the `LICENSE` file for the given license.
+///
+/// The MIT and BSD licenses are short enough to be included in full; for the Apache and GPL
+/// licenses, we include the notice recommended by each license, which refers to the full text.
+fn license_text(license: License, year: i32) -> String {
+    let template = match license {
+        License::Mit => include_str!("license/MIT"),
+        License::Apache2 => include_str!("license/Apache-2.0"),
+        License::Gpl3 => include_str!("license/GPL-3.0-only"),
+        License::Bsd2Clause => include_str!("license/BSD-2-Clause"),
+    };
+    template
+        .replace("{{ YEAR }}", &year.to_string())
+        .replace("{{ AUTHOR }}", LICENSE_AUTHOR)
+}
@@ -1,29 +1,42 @@
 use std::collections::Bound;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
 
 use anstream::eprint;
+use owo_colors::OwoColorize;
+use tracing::debug;
 
 use distribution_types::UnresolvedRequirementSpecification;
+use pep440_rs::VersionSpecifier;
+use pypi_types::{Requirement, RequirementSource};
 use uv_cache::Cache;
 use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{Concurrency, ExtrasSpecification, PreviewMode, Reinstall, SetupPyStrategy};
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{Workspace, DEV_DEPENDENCIES};
+use uv_fs::Simplified;
 use uv_git::GitResolver;
-use uv_requirements::upgrade::{read_lockfile, LockedRequirements};
+use uv_requirements::upgrade::{read_lock, LockedRequirements};
 use uv_resolver::{
-    FlatIndex, InMemoryIndex, Lock, OptionsBuilder, PythonRequirement, RequiresPython,
+    AnnotationStyle, FlatIndex, InMemoryIndex, Lock, OptionsBuilder, PythonRequirement,
+    RequiresPython, ResolutionGraph,
 };
 use uv_toolchain::{Interpreter, ToolchainPreference, ToolchainRequest};
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy, InFlight};
 use uv_warnings::{warn_user, warn_user_once};
 
-use crate::commands::project::{find_requires_python, FoundInterpreter, ProjectError};
+use crate::commands::project::{self, find_requires_python, ProjectError};
 use crate::commands::{pip, ExitStatus};
 use crate::printer::Printer;
 use crate::settings::{ResolverSettings, ResolverSettingsRef};
 
 /// Resolve the project requirements into a lockfile.
 pub(crate) async fn lock(
+    annotation_style: Option<AnnotationStyle>,
+    no_annotate: bool,
+    strip_extras: Option<bool>,
+    check: bool,
+    output_file: Option<PathBuf>,
     python: Option<String>,
     settings: ResolverSettings,
     preview: PreviewMode,
@@ -42,7 +55,7 @@ pub(crate) async fn lock(
     let workspace = Workspace::discover(&std::env::current_dir()?, None).await?;
 
     // Find an interpreter for the project
-    let interpreter = FoundInterpreter::discover(
+    let venv = project::lock_environment(
         &workspace,
         python.as_deref().map(ToolchainRequest::parse),
         toolchain_preference,
@@ -51,14 +64,31 @@ pub(crate) async fn lock(
         cache,
         printer,
     )
-    .await?
-    .into_interpreter();
+    .await?;
+
+    // Build any packages declared in `tool.uv.no-build-isolation-package` in the environment.
+    let no_build_isolation = workspace.no_build_isolation_packages();
+
+    // Annotate the lockfile as requested, or retain the annotations of the existing lockfile.
+    let annotations = if no_annotate {
+        LockAnnotations::None
+    } else if let Some(annotation_style) = annotation_style {
+        LockAnnotations::Style(annotation_style)
+    } else {
+        LockAnnotations::Existing
+    };
 
     // Perform the lock operation.
     match do_lock(
         &workspace,
-        &interpreter,
+        venv.interpreter(),
         settings.as_ref(),
+        project::build_isolation(&venv, &no_build_isolation),
+        LockedVersions::default(),
+        annotations,
+        strip_extras,
+        check,
+        output_file.as_deref(),
         preview,
         connectivity,
         concurrency,
@@ -69,6 +99,15 @@ pub(crate) async fn lock(
     .await
     {
         Ok(_) => Ok(ExitStatus::Success),
+        Err(err @ ProjectError::LockMismatch(_)) => {
+            writeln!(
+                printer.stderr(),
+                "{}{} {err}",
+                "error".red().bold(),
+                ":".bold()
+            )?;
+            Ok(ExitStatus::Failure)
+        }
         Err(ProjectError::Operation(pip::operations::Error::Resolve(
             uv_resolver::ResolveError::NoSolution(err),
         ))) => {
@@ -81,11 +120,53 @@ pub(crate) async fn lock(
     }
 }
 
+/// The treatment of the versions in an existing lockfile when re-locking.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum LockedVersions {
+    /// Prefer the locked versions, but allow the resolver to change any of them (e.g., to satisfy
+    /// a new requirement).
+    #[default]
+    Prefer,
+    /// Retain every locked version, such that only packages that are not yet locked are resolved
+    /// freely, failing if the requirements can't be satisfied with the locked versions.
+    Pin,
+}
+
+/// The annotations to include in the lockfile, which indicate the distributions that depend on
+/// each locked distribution.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum LockAnnotations {
+    /// Retain the annotation style recorded in the existing lockfile, if any.
+    #[default]
+    Existing,
+    /// Annotate the lockfile using the given style.
+    Style(AnnotationStyle),
+    /// Omit the annotations.
+    None,
+}
+
 /// Lock the project requirements into a lockfile.
-pub(super) async fn do_lock(
+///
+/// If an existing lockfile is present, its versions are treated according to `locked_versions`,
+/// and its annotation style is retained unless `annotations` requests otherwise. Similarly, if
+/// `strip_extras` is `None`, the extras are stripped if they were stripped in the existing lockfile.
+///
+/// The lockfile is read from and written to `output_file`, if provided, or `uv.lock` in the
+/// workspace root otherwise.
+///
+/// If `check` is set, the lockfile is never written; instead, an error is returned if the
+/// resolution differs from the existing lockfile (or if no lockfile exists).
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn do_lock(
     workspace: &Workspace,
     interpreter: &Interpreter,
     settings: ResolverSettingsRef<'_>,
+    build_isolation: BuildIsolation<'_>,
+    locked_versions: LockedVersions,
+    annotations: LockAnnotations,
+    strip_extras: Option<bool>,
+    check: bool,
+    output_file: Option<&Path>,
     preview: PreviewMode,
     connectivity: Connectivity,
     concurrency: Concurrency,
@@ -93,6 +174,84 @@ pub(super) async fn do_lock(
     cache: &Cache,
     printer: Printer,
 ) -> Result<Lock, ProjectError> {
+    let lockfile = output_file.map_or_else(|| workspace.root().join("uv.lock"), Path::to_path_buf);
+    let existing = read_lock(&lockfile).await?;
+
+    let resolution = do_resolve(
+        workspace,
+        vec![],
+        interpreter,
+        settings,
+        build_isolation,
+        locked_versions,
+        existing.as_ref(),
+        preview,
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?;
+
+    // Write the lockfile to disk, retaining the settings of the existing lockfile unless
+    // requested otherwise.
+    let annotation_style = match annotations {
+        LockAnnotations::Existing => existing.as_ref().and_then(Lock::annotation_style),
+        LockAnnotations::Style(annotation_style) => Some(annotation_style),
+        LockAnnotations::None => None,
+    };
+    let strip_extras =
+        strip_extras.unwrap_or_else(|| existing.as_ref().is_some_and(Lock::extras_stripped));
+    let lock = Lock::from_resolution_graph(&resolution)?;
+    let lock = if strip_extras {
+        lock.strip_extras()?
+    } else {
+        lock
+    };
+    let lock = lock.with_annotation_style(annotation_style);
+    let encoded = lock.to_toml()?;
+    if check {
+        let existing = match fs_err::tokio::read_to_string(&lockfile).await {
+            Ok(existing) => Some(existing),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => return Err(err.into()),
+        };
+        if existing.as_deref() != Some(encoded.as_str()) {
+            return Err(ProjectError::LockMismatch(
+                lockfile.user_display().to_string(),
+            ));
+        }
+        debug!(
+            "The lockfile at `{}` is up-to-date",
+            lockfile.user_display()
+        );
+    } else {
+        fs_err::tokio::write(&lockfile, encoded.as_bytes()).await?;
+    }
+
+    Ok(lock)
+}
+
+/// Resolve the project requirements, alongside any additional `requirements`, without writing a
+/// lockfile.
+///
+/// The versions in the `existing` lockfile, if any, are treated according to `locked_versions`.
+pub(crate) async fn do_resolve(
+    workspace: &Workspace,
+    requirements: Vec<Requirement>,
+    interpreter: &Interpreter,
+    settings: ResolverSettingsRef<'_>,
+    build_isolation: BuildIsolation<'_>,
+    locked_versions: LockedVersions,
+    existing: Option<&Lock>,
+    preview: PreviewMode,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ResolutionGraph, ProjectError> {
     // Extract the project settings.
     let ResolverSettingsRef {
         index_locations,
@@ -107,10 +266,12 @@ pub(super) async fn do_lock(
         build_options,
     } = settings;
 
-    // When locking, include the project itself (as editable).
+    // When locking, include the project itself (as editable), alongside any additional
+    // requirements.
     let requirements = workspace
         .members_as_requirements()
         .into_iter()
+        .chain(requirements)
         .map(UnresolvedRequirementSpecification::from)
         .collect();
     let overrides = workspace
@@ -118,7 +279,6 @@ pub(super) async fn do_lock(
         .into_iter()
         .map(UnresolvedRequirementSpecification::from)
         .collect();
-    let constraints = vec![];
     let dev = vec![DEV_DEPENDENCIES.clone()];
     let source_trees = vec![];
 
@@ -167,7 +327,6 @@ pub(super) async fn do_lock(
 
     // TODO(charlie): These are all default values. We should consider whether we want to make them
     // optional on the downstream APIs.
-    let build_isolation = BuildIsolation::default();
     let extras = ExtrasSpecification::default();
     let setup_py = SetupPyStrategy::default();
 
@@ -179,7 +338,30 @@ pub(super) async fn do_lock(
     };
 
     // If an existing lockfile exists, build up a set of preferences.
-    let LockedRequirements { preferences, git } = read_lockfile(workspace, upgrade).await?;
+    let LockedRequirements {
+        preferences,
+        git,
+        registry,
+    } = existing
+        .map(|lock| LockedRequirements::from_lock(lock, upgrade))
+        .unwrap_or_default();
+
+    // If requested, pin every locked registry package to its locked version. Packages that are
+    // being upgraded, along with Git, URL, and path dependencies (e.g., the workspace members), and
+    // packages locked at multiple versions, are left unpinned.
+    let pins = registry
+        .iter()
+        .map(|preference| Requirement {
+            name: preference.name().clone(),
+            extras: vec![],
+            marker: None,
+            source: RequirementSource::Registry {
+                specifier: VersionSpecifier::equals_version(preference.version().clone()).into(),
+                index: None,
+            },
+            origin: None,
+        })
+        .collect::<Vec<_>>();
 
     // Create the Git resolver.
     let git = GitResolver::from_refs(git);
@@ -206,6 +388,10 @@ pub(super) async fn do_lock(
     );
 
     // Resolve the requirements.
+    let constraints = match locked_versions {
+        LockedVersions::Prefer => vec![],
+        LockedVersions::Pin => pins,
+    };
     let resolution = pip::operations::resolve(
         requirements,
         constraints,
@@ -236,10 +422,5 @@ pub(super) async fn do_lock(
     // Notify the user of any resolution diagnostics.
     pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
-    // Write the lockfile to disk.
-    let lock = Lock::from_resolution_graph(&resolution)?;
-    let encoded = lock.to_toml()?;
-    fs_err::tokio::write(workspace.root().join("uv.lock"), encoded.as_bytes()).await?;
-
-    Ok(lock)
+    Ok(resolution)
 }
@@ -7,6 +7,7 @@ use tracing::debug;
 use distribution_types::Resolution;
 use pep440_rs::Version;
 use uv_cache::Cache;
+use uv_cli::DryRunFormat;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{Concurrency, ExtrasSpecification, PreviewMode, SetupPyStrategy};
 use uv_dispatch::BuildDispatch;
@@ -14,6 +15,7 @@ use uv_distribution::Workspace;
 use uv_fs::Simplified;
 use uv_git::GitResolver;
 use uv_installer::{SatisfiesResult, SitePackages};
+use uv_normalize::PackageName;
 use uv_requirements::RequirementsSpecification;
 use uv_resolver::{FlatIndex, InMemoryIndex, OptionsBuilder, PythonRequirement, RequiresPython};
 use uv_toolchain::{
@@ -27,6 +29,7 @@ use crate::printer::Printer;
 use crate::settings::ResolverInstallerSettings;
 
 pub(crate) mod add;
+pub(crate) mod init;
 pub(crate) mod lock;
 pub(crate) mod remove;
 pub(crate) mod run;
@@ -40,6 +43,9 @@ pub(crate) enum ProjectError {
     #[error("The requested Python interpreter ({0}) is incompatible with the project Python requirement: `{1}`")]
     RequestedPythonIncompatibility(Version, RequiresPython),
 
+    #[error("The lockfile at `{0}` needs to be updated, but `--check` was provided")]
+    LockMismatch(String),
+
     #[error(transparent)]
     Toolchain(#[from] uv_toolchain::Error),
 
@@ -113,6 +119,78 @@ fn interpreter_meets_requirements(
     }
 }
 
+/// Determine the Python request for the project: an explicit request from the user, then a
+/// `.python-version` file, then the `Requires-Python` in `pyproject.toml`.
+async fn python_request_for(
+    python_request: Option<ToolchainRequest>,
+    requires_python: Option<&RequiresPython>,
+) -> Result<Option<ToolchainRequest>, ProjectError> {
+    // (1) Explicit request from user
+    if let Some(request) = python_request {
+        return Ok(Some(request));
+    }
+
+    // (2) Request from `.python-version`
+    if let Some(request) = request_from_version_file().await? {
+        return Ok(Some(request));
+    }
+
+    // (3) `Requires-Python` in `pyproject.toml`
+    Ok(requires_python
+        .map(RequiresPython::specifiers)
+        .map(|specifiers| ToolchainRequest::Version(VersionRequest::Range(specifiers.clone()))))
+}
+
+/// Find the virtual environment for the current project, if it exists and its interpreter
+/// satisfies both the Python request and the project's Python requirement.
+fn find_compatible_environment(
+    workspace: &Workspace,
+    python_request: Option<&ToolchainRequest>,
+    requires_python: Option<&RequiresPython>,
+    cache: &Cache,
+) -> Result<Option<PythonEnvironment>, uv_toolchain::Error> {
+    let venv = match find_environment(workspace, cache) {
+        Ok(venv) => venv,
+        Err(uv_toolchain::Error::MissingEnvironment(_)) => return Ok(None),
+        Err(err) => return Err(err),
+    };
+
+    if !interpreter_meets_requirements(venv.interpreter(), python_request, cache) {
+        return Ok(None);
+    }
+
+    if let Some(requires_python) = requires_python {
+        if !requires_python.contains(venv.interpreter().python_version()) {
+            debug!(
+                "Interpreter does not meet the project's Python requirement: `{requires_python}`"
+            );
+            return Ok(None);
+        }
+    }
+
+    Ok(Some(venv))
+}
+
+/// Find the existing virtual environment for the current project, without creating, removing, or
+/// otherwise modifying it.
+///
+/// Returns `None` if the environment doesn't exist or is incompatible with the project, i.e., if
+/// [`get_or_init_environment`] would create or replace it.
+pub(crate) async fn find_existing_environment(
+    workspace: &Workspace,
+    python: Option<ToolchainRequest>,
+    cache: &Cache,
+) -> Result<Option<PythonEnvironment>, ProjectError> {
+    let requires_python = find_requires_python(workspace)?;
+    let python_request = python_request_for(python, requires_python.as_ref()).await?;
+    Ok(find_compatible_environment(
+        workspace,
+        python_request.as_ref(),
+        requires_python.as_ref(),
+        cache,
+    )?)
+}
+
 #[derive(Debug)]
 pub(crate) enum FoundInterpreter {
     Interpreter(Interpreter),
@@ -131,46 +209,17 @@ impl FoundInterpreter {
         printer: Printer,
     ) -> Result<Self, ProjectError> {
         let requires_python = find_requires_python(workspace)?;
-
-        // (1) Explicit request from user
-        let python_request = if let Some(request) = python_request {
-            Some(request)
-            // (2) Request from `.python-version`
-        } else if let Some(request) = request_from_version_file().await? {
-            Some(request)
-            // (3) `Requires-Python` in `pyproject.toml`
-        } else {
-            requires_python
-                .as_ref()
-                .map(RequiresPython::specifiers)
-                .map(|specifiers| {
-                    ToolchainRequest::Version(VersionRequest::Range(specifiers.clone()))
-                })
-        };
+        let python_request = python_request_for(python_request, requires_python.as_ref()).await?;
 
         // Read from the virtual environment first.
-        match find_environment(workspace, cache) {
-            Ok(venv) => {
-                if interpreter_meets_requirements(
-                    venv.interpreter(),
-                    python_request.as_ref(),
-                    cache,
-                ) {
-                    if let Some(requires_python) = requires_python.as_ref() {
-                        if requires_python.contains(venv.interpreter().python_version()) {
-                            return Ok(Self::Environment(venv));
-                        }
-                        debug!(
-                            "Interpreter does not meet the project's Python requirement: `{requires_python}`"
-                        );
-                    } else {
-                        return Ok(Self::Environment(venv));
-                    }
-                }
-            }
-            Err(uv_toolchain::Error::MissingEnvironment(_)) => {}
-            Err(err) => return Err(err.into()),
-        };
+        if let Some(venv) = find_compatible_environment(
+            workspace,
+            python_request.as_ref(),
+            requires_python.as_ref(),
+            cache,
+        )? {
+            return Ok(Self::Environment(venv));
+        }
 
         let client_builder = BaseClientBuilder::default()
             .connectivity(connectivity)
@@ -215,6 +264,65 @@ impl FoundInterpreter {
     }
 }
 
+/// Find the environment in which to lock the workspace.
+///
+/// Packages declared in `tool.uv.no-build-isolation-package` are built in the project environment,
+/// so the environment is created if the workspace declares any such packages. Otherwise, the
+/// discovered interpreter is used directly, without creating an environment.
+pub(crate) async fn lock_environment(
+    workspace: &Workspace,
+    python: Option<ToolchainRequest>,
+    toolchain_preference: ToolchainPreference,
+    connectivity: Connectivity,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<PythonEnvironment, ProjectError> {
+    if !workspace.no_build_isolation_packages().is_empty() {
+        return get_or_init_environment(
+            workspace,
+            python,
+            toolchain_preference,
+            connectivity,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await;
+    }
+
+    match FoundInterpreter::discover(
+        workspace,
+        python,
+        toolchain_preference,
+        connectivity,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?
+    {
+        FoundInterpreter::Environment(environment) => Ok(environment),
+        FoundInterpreter::Interpreter(interpreter) => {
+            Ok(PythonEnvironment::from_interpreter(interpreter))
+        }
+    }
+}
+
+/// Returns the [`BuildIsolation`] for a workspace, building the given packages (i.e., those
+/// declared in `tool.uv.no-build-isolation-package`) in the given environment rather than in
+/// isolation.
+pub(crate) fn build_isolation<'a>(
+    venv: &'a PythonEnvironment,
+    no_build_isolation: &'a [PackageName],
+) -> BuildIsolation<'a> {
+    if no_build_isolation.is_empty() {
+        BuildIsolation::Isolated
+    } else {
+        BuildIsolation::SharedPackage(venv, no_build_isolation)
+    }
+}
+
 /// Initialize a virtual environment for the current project.
 pub(crate) async fn get_or_init_environment(
     workspace: &Workspace,
@@ -268,6 +376,7 @@ pub(crate) async fn get_or_init_environment(
                 uv_virtualenv::Prompt::None,
                 false,
                 false,
+                false,
             )?)
         }
     }
@@ -303,7 +412,7 @@ pub(crate) async fn update_environment(
 
     // Check if the current environment satisfies the requirements
     let site_packages = SitePackages::from_environment(&venv)?;
-    if spec.source_trees.is_empty() && reinstall.is_none() {
+    if spec.source_trees.is_empty() && reinstall.is_none() && upgrade.is_none() {
         match site_packages.satisfies(&spec.requirements, &spec.constraints)? {
             // If the requirements are already satisfied, we're done.
             SatisfiesResult::Fresh {
@@ -472,6 +581,7 @@ pub(crate) async fn update_environment(
         cache,
         &venv,
         dry_run,
+        DryRunFormat::Text,
         printer,
         preview,
     )
@@ -11,6 +11,7 @@ use uv_toolchain::{ToolchainPreference, ToolchainRequest};
 use uv_warnings::{warn_user, warn_user_once};
 
 use crate::commands::pip::operations::Modifications;
+use crate::commands::project::lock::{LockAnnotations, LockedVersions};
 use crate::commands::{project, ExitStatus};
 use crate::printer::Printer;
 use crate::settings::{InstallerSettings, ResolverSettings};
@@ -62,6 +63,13 @@ pub(crate) async fn remove(
                     );
                 }
             }
+            DependencyType::DevScript => {
+                let deps = pyproject.remove_dev_script(&req)?;
+                if deps.is_empty() {
+                    warn_if_present(&req, &pyproject);
+                    anyhow::bail!("The dependency `{req}` could not be found in `dev-scripts`");
+                }
+            }
             DependencyType::Optional(ref group) => {
                 let deps = pyproject.remove_optional_dependency(&req, group)?;
                 if deps.is_empty() {
@@ -95,11 +103,20 @@ pub(crate) async fn remove(
     // Use the default settings.
     let settings = ResolverSettings::default();
 
+    // Build any packages declared in `tool.uv.no-build-isolation-package` in the environment.
+    let no_build_isolation = project.workspace().no_build_isolation_packages();
+
     // Lock and sync the environment.
     let lock = project::lock::do_lock(
         project.workspace(),
         venv.interpreter(),
         settings.as_ref(),
+        project::build_isolation(&venv, &no_build_isolation),
+        LockedVersions::default(),
+        LockAnnotations::default(),
+        None,
+        false,
+        None,
         preview,
         connectivity,
         concurrency,
@@ -122,7 +139,10 @@ pub(crate) async fn remove(
         extras,
         dev,
         Modifications::Exact,
+        false,
+        false,
         settings.as_ref(),
+        project::build_isolation(&venv, &no_build_isolation),
         preview,
         connectivity,
         concurrency,
@@ -148,6 +168,9 @@ fn warn_if_present(name: &PackageName, pyproject: &PyProjectTomlMut) {
             DependencyType::Dev => {
                 warn_user!("`{name}` is a development dependency; try calling `uv remove --dev`");
             }
+            DependencyType::DevScript => {
+                warn_user!("`{name}` is a development script; try calling `uv remove --script`");
+            }
             DependencyType::Optional(group) => {
                 warn_user!(
                     "`{name}` is an optional dependency; try calling `uv remove --optional {group}`"
@@ -1,8 +1,10 @@
 use std::borrow::Cow;
 use std::ffi::OsString;
+use std::fmt::Write;
 use std::path::PathBuf;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
 use tokio::process::Command;
 use tracing::debug;
 
@@ -18,21 +20,43 @@ use uv_toolchain::{
     request_from_version_file, EnvironmentPreference, Interpreter, PythonEnvironment, Toolchain,
     ToolchainPreference, ToolchainRequest, VersionRequest,
 };
-use uv_warnings::warn_user_once;
+use uv_warnings::{warn_user, warn_user_once};
 
 use crate::commands::pip::operations::Modifications;
+use crate::commands::project::lock::{LockAnnotations, LockedVersions};
 use crate::commands::{project, ExitStatus};
 use crate::printer::Printer;
 use crate::settings::ResolverInstallerSettings;
 
+/// The variables passed through from the parent process when running with `--clean-env`, in
+/// addition to `PATH`.
+const CLEAN_ENV_VARS: &[&str] = &[
+    "HOME",
+    "USER",
+    "TMPDIR",
+    #[cfg(windows)]
+    "SYSTEMROOT",
+    #[cfg(windows)]
+    "USERPROFILE",
+    #[cfg(windows)]
+    "TEMP",
+    #[cfg(windows)]
+    "TMP",
+];
+
 /// Run a command.
+#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn run(
     extras: ExtrasSpecification,
     dev: bool,
-    command: ExternalCommand,
+    command: Option<ExternalCommand>,
+    list: bool,
     requirements: Vec<RequirementsSource>,
+    env: Vec<(String, String)>,
+    clean_env: bool,
     python: Option<String>,
     package: Option<PackageName>,
+    no_project: bool,
     settings: ResolverInstallerSettings,
     isolated: bool,
     preview: PreviewMode,
@@ -47,7 +71,19 @@ pub(crate) async fn run(
         warn_user_once!("`uv run` is experimental and may change without warning.");
     }
 
+    if list {
+        // Clap can't express a conflict between an argument and the external subcommand, so
+        // reject the combination here, rather than ignoring the command.
+        if command.is_some() {
+            bail!("`--list` cannot be used with a command");
+        }
+        return list_scripts(package, printer).await;
+    }
+
     // Parse the input command.
+    let Some(command) = command else {
+        bail!("A command is required (use `uv run --list` to see the project's scripts)");
+    };
     let command = RunCommand::from(command);
 
     // Determine whether the command to execute is a PEP 723 script.
@@ -99,6 +135,7 @@ pub(crate) async fn run(
                 uv_virtualenv::Prompt::None,
                 false,
                 false,
+                false,
             )?;
 
             // Install the script requirements.
@@ -130,7 +167,10 @@ pub(crate) async fn run(
         // package is `None`, isolated and package are marked as conflicting in clap.
         None
     } else {
-        let project = if let Some(package) = package {
+        let project = if no_project {
+            debug!("Skipping project discovery due to `--no-project`");
+            None
+        } else if let Some(package) = package {
             // We need a workspace, but we don't need to have a current package, we can be e.g. in
             // the root of a virtual workspace and then switch into the selected package.
             Some(VirtualProject::Project(
@@ -161,6 +201,19 @@ pub(crate) async fn run(
                 );
             }
 
+            // Warn if the requested Python differs from that of the project environment, in which
+            // case the environment is recreated with the requested Python.
+            if let Some(python) = python.as_deref() {
+                if let Ok(venv) = PythonEnvironment::from_root(project.workspace().venv(), cache) {
+                    if !ToolchainRequest::parse(python).satisfied(venv.interpreter(), cache) {
+                        warn_user!(
+                            "The requested Python version (`{python}`) differs from that of the project environment ({})",
+                            venv.interpreter().python_version()
+                        );
+                    }
+                }
+            }
+
             let venv = project::get_or_init_environment(
                 project.workspace(),
                 python.as_deref().map(ToolchainRequest::parse),
@@ -172,11 +225,21 @@ pub(crate) async fn run(
             )
             .await?;
 
+            // Build any packages declared in `tool.uv.no-build-isolation-package` in the
+            // environment.
+            let no_build_isolation = project.workspace().no_build_isolation_packages();
+
             // Lock and sync the environment.
             let lock = project::lock::do_lock(
                 project.workspace(),
                 venv.interpreter(),
                 settings.as_ref().into(),
+                project::build_isolation(&venv, &no_build_isolation),
+                LockedVersions::default(),
+                LockAnnotations::default(),
+                None,
+                false,
+                None,
                 preview,
                 connectivity,
                 concurrency,
@@ -192,7 +255,10 @@ pub(crate) async fn run(
                 extras,
                 dev,
                 Modifications::Sufficient,
+                false,
+                false,
                 settings.as_ref().into(),
+                project::build_isolation(&venv, &no_build_isolation),
                 preview,
                 connectivity,
                 concurrency,
@@ -210,10 +276,19 @@ pub(crate) async fn run(
                 .connectivity(connectivity)
                 .native_tls(native_tls);
 
+            // With `--no-project`, avoid picking up the project environment (e.g., a `.venv` in the
+            // working directory), unless a virtual environment is explicitly active.
+            let active_venv = std::env::var_os("VIRTUAL_ENV").is_some();
+            let environment_preference = if no_project && !active_venv {
+                EnvironmentPreference::OnlySystem
+            } else {
+                EnvironmentPreference::Any
+            };
+
             let toolchain = Toolchain::find_or_fetch(
                 python.as_deref().map(ToolchainRequest::parse),
                 // No opt-in is required for system environments, since we are not mutating it.
-                EnvironmentPreference::Any,
+                environment_preference,
                 toolchain_preference,
                 client_builder,
                 cache,
@@ -274,6 +349,7 @@ pub(crate) async fn run(
             uv_virtualenv::Prompt::None,
             false,
             false,
+            false,
         )?;
 
         let client_builder = BaseClientBuilder::new()
@@ -303,6 +379,16 @@ pub(crate) async fn run(
     debug!("Running `{command}`");
     let mut process = Command::from(&command);
 
+    // With `--clean-env`, only pass through a minimal set of variables from the parent process.
+    if clean_env {
+        process.env_clear();
+        for key in CLEAN_ENV_VARS {
+            if let Some(value) = std::env::var_os(key) {
+                process.env(key, value);
+            }
+        }
+    }
+
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
         ephemeral_env
@@ -343,6 +429,7 @@ pub(crate) async fn run(
             .map(PathBuf::from)
             .chain(
                 std::env::var_os("PYTHONPATH")
+                    .filter(|_| !clean_env)
                     .as_ref()
                     .iter()
                     .flat_map(std::env::split_paths),
@@ -350,6 +437,11 @@ pub(crate) async fn run(
     )?;
     process.env("PYTHONPATH", new_python_path);
 
+    // Apply any user-provided environment variables, which take precedence over the above.
+    for (key, value) in env {
+        process.env(key, value);
+    }
+
     // Spawn and wait for completion
     // Standard input, output, and error streams are all inherited
     // TODO(zanieb): Throw a nicer error message if the command is not found
@@ -370,6 +462,61 @@ pub(crate) async fn run(
     }
 }
 
+/// List the scripts defined in the `project.scripts` table of the current project.
+///
+/// In a virtual workspace (i.e., without a root project), the scripts of every workspace member
+/// are listed.
+async fn list_scripts(package: Option<PackageName>, printer: Printer) -> Result<ExitStatus> {
+    let project = if let Some(package) = package {
+        VirtualProject::Project(
+            Workspace::discover(&std::env::current_dir()?, None)
+                .await?
+                .with_current_project(package.clone())
+                .with_context(|| format!("Package `{package}` not found in workspace"))?,
+        )
+    } else {
+        VirtualProject::discover(&std::env::current_dir()?, None).await?
+    };
+
+    let scripts = match &project {
+        VirtualProject::Project(project) => project
+            .current_project()
+            .project()
+            .scripts
+            .iter()
+            .flatten()
+            .collect::<Vec<_>>(),
+        VirtualProject::Virtual(workspace) => workspace
+            .packages()
+            .values()
+            .filter_map(|member| member.project().scripts.as_ref())
+            .flatten()
+            .collect::<Vec<_>>(),
+    };
+
+    if scripts.is_empty() {
+        writeln!(printer.stderr(), "No scripts defined in `project.scripts`")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let width = scripts
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0);
+    for (name, target) in scripts {
+        writeln!(
+            printer.stdout(),
+            "{}{} {}",
+            name.bold(),
+            " ".repeat(width - name.len()),
+            target.dimmed()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
 #[derive(Debug)]
 enum RunCommand {
     /// Execute a `python` script.
@@ -1,18 +1,24 @@
-use anyhow::Result;
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
 
 use uv_cache::Cache;
+use uv_cli::DryRunFormat;
 use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{Concurrency, ExtrasSpecification, PreviewMode, SetupPyStrategy};
 use uv_dispatch::BuildDispatch;
-use uv_distribution::{VirtualProject, DEV_DEPENDENCIES};
+use uv_distribution::{VirtualProject, Workspace, DEV_DEPENDENCIES};
+use uv_fs::Simplified;
 use uv_git::GitResolver;
 use uv_installer::SitePackages;
+use uv_normalize::PackageName;
 use uv_resolver::{FlatIndex, InMemoryIndex, Lock};
 use uv_toolchain::{PythonEnvironment, ToolchainPreference, ToolchainRequest};
 use uv_types::{BuildIsolation, HashStrategy, InFlight};
-use uv_warnings::warn_user_once;
+use uv_warnings::{warn_user, warn_user_once};
 
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{Changelog, Modifications};
 use crate::commands::project::ProjectError;
 use crate::commands::{pip, project, ExitStatus};
 use crate::printer::Printer;
@@ -23,6 +29,9 @@ pub(crate) async fn sync(
     extras: ExtrasSpecification,
     dev: bool,
     modifications: Modifications,
+    check: bool,
+    no_editable: bool,
+    package: Option<PackageName>,
     python: Option<String>,
     toolchain_preference: ToolchainPreference,
     settings: InstallerSettings,
@@ -37,20 +46,60 @@ pub(crate) async fn sync(
         warn_user_once!("`uv sync` is experimental and may change without warning.");
     }
 
-    // Identify the project
-    let project = VirtualProject::discover(&std::env::current_dir()?, None).await?;
+    // Identify the project. If a workspace member was requested, sync it alone.
+    let project = if let Some(package) = package {
+        VirtualProject::Project(
+            Workspace::discover(&std::env::current_dir()?, None)
+                .await?
+                .with_current_project(package.clone())
+                .with_context(|| format!("Package `{package}` not found in workspace"))?,
+        )
+    } else {
+        VirtualProject::discover(&std::env::current_dir()?, None).await?
+    };
 
-    // Discover or create the virtual environment.
-    let venv = project::get_or_init_environment(
-        project.workspace(),
-        python.as_deref().map(ToolchainRequest::parse),
-        toolchain_preference,
-        connectivity,
-        native_tls,
-        cache,
-        printer,
-    )
-    .await?;
+    // Discover or create the virtual environment. With `--check`, the environment is only
+    // inspected: if it's missing or incompatible, report that it would be (re)created.
+    let venv = if check {
+        let Some(venv) = project::find_existing_environment(
+            project.workspace(),
+            python.as_deref().map(ToolchainRequest::parse),
+            cache,
+        )
+        .await?
+        else {
+            let venv = project.workspace().venv();
+            if venv.exists() {
+                writeln!(
+                    printer.stderr(),
+                    "Would replace virtual environment at: {}",
+                    venv.user_display().cyan()
+                )?;
+            } else {
+                writeln!(
+                    printer.stderr(),
+                    "Would create virtualenv at: {}",
+                    venv.user_display().cyan()
+                )?;
+            }
+            return Ok(ExitStatus::Failure);
+        };
+        venv
+    } else {
+        project::get_or_init_environment(
+            project.workspace(),
+            python.as_deref().map(ToolchainRequest::parse),
+            toolchain_preference,
+            connectivity,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await?
+    };
+
+    // Build any packages declared in `tool.uv.no-build-isolation-package` in the environment.
+    let no_build_isolation = project.workspace().no_build_isolation_packages();
 
     // Read the lockfile.
     let lock: Lock = {
@@ -59,15 +108,18 @@ pub(crate) async fn sync(
         toml::from_str(&encoded)?
     };
 
-    // Perform the sync operation.
-    do_sync(
+    // Perform the sync operation. With `--check`, report the changes without applying them.
+    let changelog = do_sync(
         &project,
         &venv,
         &lock,
         extras,
         dev,
         modifications,
+        check,
+        no_editable,
         settings.as_ref(),
+        project::build_isolation(&venv, &no_build_isolation),
         preview,
         connectivity,
         concurrency,
@@ -77,6 +129,10 @@ pub(crate) async fn sync(
     )
     .await?;
 
+    if changelog.would_modify {
+        return Ok(ExitStatus::Failure);
+    }
+
     Ok(ExitStatus::Success)
 }
 
@@ -88,14 +144,17 @@ pub(super) async fn do_sync(
     extras: ExtrasSpecification,
     dev: bool,
     modifications: Modifications,
+    dry_run: bool,
+    no_editable: bool,
     settings: InstallerSettingsRef<'_>,
+    build_isolation: BuildIsolation<'_>,
     preview: PreviewMode,
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
     cache: &Cache,
     printer: Printer,
-) -> Result<(), ProjectError> {
+) -> Result<Changelog, ProjectError> {
     // Extract the project settings.
     let InstallerSettingsRef {
         index_locations,
@@ -118,6 +177,23 @@ pub(super) async fn do_sync(
         }
     }
 
+    // Warn about any requested extras that aren't defined by the project.
+    if let ExtrasSpecification::Some(extras) = &extras {
+        for extra in extras {
+            let defined = project.packages().any(|name| {
+                project
+                    .workspace()
+                    .packages()
+                    .get(name)
+                    .and_then(|member| member.project().optional_dependencies.as_ref())
+                    .is_some_and(|optional_dependencies| optional_dependencies.contains_key(extra))
+            });
+            if !defined {
+                warn_user!("The extra `{extra}` is not defined by the project; ignoring it");
+            }
+        }
+    }
+
     // Include development dependencies, if requested.
     let dev = if dev {
         vec![DEV_DEPENDENCIES.clone()]
@@ -131,6 +207,14 @@ pub(super) async fn do_sync(
     // Read the lockfile.
     let resolution = lock.to_resolution(project, markers, tags, &extras, &dev)?;
 
+    // With `--no-editable`, install the workspace members as regular packages, such that the
+    // environment doesn't depend on the source tree.
+    let resolution = if no_editable {
+        resolution.into_non_editable()
+    } else {
+        resolution
+    };
+
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
@@ -149,8 +233,6 @@ pub(super) async fn do_sync(
 
     // TODO(charlie): These are all default values. We should consider whether we want to make them
     // optional on the downstream APIs.
-    let build_isolation = BuildIsolation::default();
-    let dry_run = false;
     let exclude_newer = None;
     let hasher = HashStrategy::default();
     let setup_py = SetupPyStrategy::default();
@@ -186,7 +268,7 @@ pub(super) async fn do_sync(
     let site_packages = SitePackages::from_environment(venv)?;
 
     // Sync the environment.
-    pip::operations::install(
+    let changelog = pip::operations::install(
         &resolution,
         site_packages,
         modifications,
@@ -204,10 +286,11 @@ pub(super) async fn do_sync(
         cache,
         venv,
         dry_run,
+        DryRunFormat::Text,
         printer,
         preview,
     )
     .await?;
 
-    Ok(())
+    Ok(changelog)
 }
@@ -0,0 +1,167 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use distribution_types::Name;
+use uv_cache::Cache;
+use uv_configuration::PreviewMode;
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_tool::{InstalledTools, Tool};
+use uv_warnings::warn_user_once;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Show details about an installed tool.
+pub(crate) async fn inspect(
+    name: String,
+    json: bool,
+    preview: PreviewMode,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv tool inspect` is experimental and may change without warning.");
+    }
+
+    let installed_tools = InstalledTools::from_settings()?;
+
+    let Some(receipt) = installed_tools.get_tool_receipt(&name)? else {
+        bail!("Tool `{}` is not installed", name);
+    };
+
+    let Some(environment) = installed_tools.get_environment(&name, cache)? else {
+        bail!("Tool `{name}` is malformed: missing environment");
+    };
+
+    let interpreter = environment.interpreter();
+    let site_packages = SitePackages::from_environment(&environment)?;
+    let packages = site_packages
+        .iter()
+        .map(|dist| Package {
+            name: dist.name().to_string(),
+            version: dist.version().to_string(),
+        })
+        .sorted_by(|a, b| a.name.cmp(&b.name))
+        .collect::<Vec<_>>();
+
+    let details = Details {
+        name,
+        python: Python {
+            request: receipt.python().map(ToString::to_string),
+            executable: interpreter
+                .sys_executable()
+                .simplified_display()
+                .to_string(),
+            version: interpreter.python_version().to_string(),
+        },
+        packages,
+        entrypoints: receipt
+            .entrypoints()
+            .iter()
+            .map(|entrypoint| Entrypoint {
+                name: entrypoint.name.clone(),
+                path: entrypoint.install_path.simplified_display().to_string(),
+            })
+            .collect(),
+        command: install_command(&receipt),
+    };
+
+    if json {
+        let output = serde_json::to_string(&details)?;
+        writeln!(printer.stdout(), "{output}")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(printer.stdout(), "{}", details.name.bold())?;
+    writeln!(
+        printer.stdout(),
+        "Python: {} ({})",
+        details.python.executable,
+        details.python.version
+    )?;
+    writeln!(printer.stdout(), "Packages:")?;
+    for package in &details.packages {
+        writeln!(printer.stdout(), " - {}=={}", package.name, package.version)?;
+    }
+    writeln!(printer.stdout(), "Entry points:")?;
+    for entrypoint in &details.entrypoints {
+        writeln!(
+            printer.stdout(),
+            " - {} ({})",
+            entrypoint.name,
+            entrypoint.path
+        )?;
+    }
+    writeln!(printer.stdout(), "Install command: {}", details.command)?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Reconstruct the `uv tool install` command used to install a tool from its receipt.
+fn install_command(receipt: &Tool) -> String {
+    let mut command = String::from("uv tool install");
+    let mut requirements = receipt.requirements().iter();
+    if let Some(requirement) = requirements.next() {
+        command.push(' ');
+        command.push_str(&quote(&requirement.to_string()));
+    }
+    for requirement in requirements {
+        command.push_str(" --with ");
+        command.push_str(&quote(&requirement.to_string()));
+    }
+    if let Some(python) = receipt.python() {
+        command.push_str(" --python ");
+        command.push_str(&quote(python));
+    }
+    command
+}
+
+/// Quote an argument for display in a shell command, if necessary.
+fn quote(argument: &str) -> String {
+    if argument
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '=' | '/' | ':' | '@'))
+    {
+        argument.to_string()
+    } else {
+        format!("'{}'", argument.replace('\'', r"'\''"))
+    }
+}
+
+/// The details of an installed tool.
+#[derive(Debug, Serialize)]
+struct Details {
+    name: String,
+    python: Python,
+    packages: Vec<Package>,
+    entrypoints: Vec<Entrypoint>,
+    command: String,
+}
+
+/// The Python interpreter used by an installed tool.
+#[derive(Debug, Serialize)]
+struct Python {
+    /// The Python requested by the user during installation, if any.
+    request: Option<String>,
+    executable: String,
+    version: String,
+}
+
+/// A package installed in a tool's environment.
+#[derive(Debug, Serialize)]
+struct Package {
+    name: String,
+    version: String,
+}
+
+/// An entry point installed by a tool.
+#[derive(Debug, Serialize)]
+struct Entrypoint {
+    name: String,
+    path: String,
+}
@@ -1,27 +1,23 @@
-use std::collections::BTreeSet;
-use std::ffi::OsString;
 use std::fmt::Write;
 use std::str::FromStr;
 
 use anyhow::{bail, Context, Result};
-use distribution_types::Name;
 use itertools::Itertools;
 
 use pypi_types::VerbatimParsedUrl;
 use tracing::debug;
 use uv_cache::Cache;
 use uv_client::Connectivity;
-use uv_configuration::{Concurrency, PreviewMode, Reinstall};
-#[cfg(unix)]
-use uv_fs::replace_symlink;
+use uv_configuration::{Concurrency, EntrypointLinkMode, PreviewMode, Reinstall};
 use uv_fs::Simplified;
 use uv_installer::SitePackages;
 use uv_requirements::RequirementsSpecification;
-use uv_tool::{entrypoint_paths, find_executable_directory, InstalledTools, Tool, ToolEntrypoint};
+use uv_tool::{find_executable_directory, InstalledTools, Tool, ToolEntrypoint};
 use uv_toolchain::{EnvironmentPreference, Toolchain, ToolchainPreference, ToolchainRequest};
 use uv_warnings::warn_user_once;
 
 use crate::commands::project::update_environment;
+use crate::commands::tool::{entrypoint_targets, install_entrypoint};
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
 use crate::settings::ResolverInstallerSettings;
@@ -33,6 +29,7 @@ pub(crate) async fn install(
     python: Option<String>,
     with: Vec<String>,
     force: bool,
+    entrypoint_link_mode: Option<EntrypointLinkMode>,
     settings: ResolverInstallerSettings,
     preview: PreviewMode,
     toolchain_preference: ToolchainPreference,
@@ -168,6 +165,13 @@ pub(crate) async fn install(
         return Ok(ExitStatus::Success);
     }
 
+    // Retain the link mode of an existing installation, unless another was requested.
+    let entrypoint_link_mode = entrypoint_link_mode.or_else(|| {
+        existing_tool_receipt
+            .as_ref()
+            .and_then(Tool::entrypoint_link_mode)
+    });
+
     // Find a suitable path to install into
     // TODO(zanieb): Warn if this directory is not on the PATH
     let executable_directory = find_executable_directory()?;
@@ -179,26 +183,8 @@ pub(crate) async fn install(
         executable_directory.user_display()
     );
 
-    let entry_points = entrypoint_paths(
-        &environment,
-        installed_dist.name(),
-        installed_dist.version(),
-    )?;
-
-    // Determine the entry points targets
-    // Use a sorted collection for deterministic output
-    let target_entry_points = entry_points
-        .into_iter()
-        .map(|(name, source_path)| {
-            let target_path = executable_directory.join(
-                source_path
-                    .file_name()
-                    .map(std::borrow::ToOwned::to_owned)
-                    .unwrap_or_else(|| OsString::from(name.clone())),
-            );
-            (name, source_path, target_path)
-        })
-        .collect::<BTreeSet<_>>();
+    let target_entry_points =
+        entrypoint_targets(&environment, installed_dist, &executable_directory)?;
 
     if target_entry_points.is_empty() {
         // Clean up the environment we just created
@@ -241,10 +227,12 @@ pub(crate) async fn install(
 
     for (name, source_path, target_path) in &target_entry_points {
         debug!("Installing `{name}`");
-        #[cfg(unix)]
-        replace_symlink(source_path, target_path).context("Failed to install entrypoint")?;
-        #[cfg(windows)]
-        fs_err::copy(source_path, target_path).context("Failed to install entrypoint")?;
+        install_entrypoint(
+            source_path,
+            target_path,
+            entrypoint_link_mode.unwrap_or_default(),
+        )
+        .context("Failed to install entrypoint")?;
     }
 
     writeln!(
@@ -261,6 +249,7 @@ pub(crate) async fn install(
     let tool = Tool::new(
         requirements,
         python,
+        entrypoint_link_mode,
         target_entry_points
             .into_iter()
             .map(|(name, _, target_path)| ToolEntrypoint::new(name, target_path)),
@@ -1,16 +1,28 @@
 use std::fmt::Write;
+use std::str::FromStr;
 
 use anyhow::Result;
+use serde::Serialize;
 
+use uv_cache::Cache;
 use uv_configuration::PreviewMode;
-use uv_tool::InstalledTools;
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_tool::{InstalledTools, Tool};
 use uv_warnings::warn_user_once;
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
 /// List installed tools.
-pub(crate) async fn list(preview: PreviewMode, printer: Printer) -> Result<ExitStatus> {
+pub(crate) async fn list(
+    versions: bool,
+    json: bool,
+    preview: PreviewMode,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
     if preview.is_disabled() {
         warn_user_once!("`uv tool list` is experimental and may change without warning.");
     }
@@ -20,6 +32,17 @@ pub(crate) async fn list(preview: PreviewMode, printer: Printer) -> Result<ExitS
     let mut tools = installed_tools.tools()?.into_iter().collect::<Vec<_>>();
     tools.sort_by_key(|(name, _)| name.clone());
 
+    if json {
+        let mut entries = Vec::with_capacity(tools.len());
+        for (name, tool) in tools {
+            let version = installed_version(&installed_tools, &name, cache)?;
+            entries.push(Entry::new(name, version, &tool));
+        }
+        let output = serde_json::to_string(&entries)?;
+        writeln!(printer.stdout(), "{output}")?;
+        return Ok(ExitStatus::Success);
+    }
+
     if tools.is_empty() {
         writeln!(printer.stderr(), "No tools installed")?;
         return Ok(ExitStatus::Success);
@@ -27,8 +50,63 @@ pub(crate) async fn list(preview: PreviewMode, printer: Printer) -> Result<ExitS
 
     // TODO(zanieb): Track and display additional metadata, like entry points
     for (name, _tool) in tools {
+        if versions {
+            if let Some(version) = installed_version(&installed_tools, &name, cache)? {
+                writeln!(printer.stdout(), "{name} v{version}")?;
+                continue;
+            }
+        }
         writeln!(printer.stdout(), "{name}")?;
     }
 
     Ok(ExitStatus::Success)
 }
+
+/// Read the installed version of a tool from its environment.
+///
+/// The version is read from the tool's `site-packages` rather than its receipt, to remain
+/// accurate even if the receipt is stale.
+fn installed_version(
+    installed_tools: &InstalledTools,
+    name: &str,
+    cache: &Cache,
+) -> Result<Option<String>> {
+    let Ok(package) = PackageName::from_str(name) else {
+        return Ok(None);
+    };
+
+    let Some(environment) = installed_tools.get_environment(name, cache)? else {
+        warn_user_once!("Ignoring malformed tool `{name}`: missing environment");
+        return Ok(None);
+    };
+
+    let site_packages = SitePackages::from_environment(&environment)?;
+    let version = site_packages
+        .get_packages(&package)
+        .first()
+        .map(|dist| dist.version().to_string());
+
+    Ok(version)
+}
+
+/// An entry in a JSON list of installed tools.
+#[derive(Debug, Serialize)]
+struct Entry {
+    name: String,
+    version: Option<String>,
+    entrypoints: Vec<String>,
+}
+
+impl Entry {
+    fn new(name: String, version: Option<String>, tool: &Tool) -> Self {
+        Self {
+            name,
+            version,
+            entrypoints: tool
+                .entrypoints()
+                .iter()
+                .map(|entrypoint| entrypoint.install_path.simplified_display().to_string())
+                .collect(),
+        }
+    }
+}
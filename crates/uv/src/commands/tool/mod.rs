@@ -1,5 +1,213 @@
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use tracing::debug;
+
+use distribution_types::{InstalledDist, Name};
+use pep440_rs::Version;
+use pypi_types::VerbatimParsedUrl;
+use uv_cache::Cache;
+use uv_configuration::EntrypointLinkMode;
+#[cfg(unix)]
+use uv_fs::replace_symlink;
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_tool::{entrypoint_paths, find_executable_directory, InstalledTools, Tool, ToolEntrypoint};
+use uv_toolchain::{
+    EnvironmentPreference, PythonEnvironment, Toolchain, ToolchainPreference, ToolchainRequest,
+};
+use uv_warnings::warn_user;
+
 pub(crate) mod dir;
+pub(crate) mod inspect;
 pub(crate) mod install;
 pub(crate) mod list;
+pub(crate) mod reinstall;
 pub(crate) mod run;
 pub(crate) mod uninstall;
+pub(crate) mod upgrade;
+
+/// Determine the entry points provided by the given distribution, returning the name, source path,
+/// and target path in the executable directory for each entry point.
+///
+/// Returns a sorted collection, for deterministic output.
+pub(crate) fn entrypoint_targets(
+    environment: &PythonEnvironment,
+    dist: &InstalledDist,
+    executable_directory: &Path,
+) -> Result<BTreeSet<(String, PathBuf, PathBuf)>> {
+    Ok(entrypoint_paths(environment, dist.name(), dist.version())?
+        .into_iter()
+        .map(|(name, source_path)| {
+            let target_path = executable_directory.join(
+                source_path
+                    .file_name()
+                    .map(std::borrow::ToOwned::to_owned)
+                    .unwrap_or_else(|| OsString::from(name.clone())),
+            );
+            (name, source_path, target_path)
+        })
+        .collect())
+}
+
+/// Install an entry point at the target path using the given link mode, replacing any existing
+/// entry point at that path.
+pub(crate) fn install_entrypoint(
+    source: &Path,
+    target: &Path,
+    link_mode: EntrypointLinkMode,
+) -> Result<()> {
+    match link_mode {
+        #[cfg(unix)]
+        EntrypointLinkMode::Symlink => replace_symlink(source, target)?,
+        #[cfg(windows)]
+        EntrypointLinkMode::Symlink => {
+            anyhow::bail!("Symlinked entry points are not supported on Windows")
+        }
+        EntrypointLinkMode::Copy | EntrypointLinkMode::Hardlink => {
+            // Remove the existing entry point first, to avoid copying through an existing symlink
+            // or failing to create the hard link.
+            match fs_err::remove_file(target) {
+                Ok(()) => {}
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+            if link_mode == EntrypointLinkMode::Copy {
+                fs_err::copy(source, target)?;
+            } else {
+                fs_err::hard_link(source, target)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Find the environment for an installed tool, recreating it if it no longer satisfies the Python
+/// request recorded in the receipt (e.g., if its interpreter was removed or replaced).
+///
+/// Returns the environment, along with the packages installed in the existing environment, if any.
+pub(crate) fn tool_environment(
+    name: &str,
+    receipt: &Tool,
+    installed_tools: &InstalledTools,
+    toolchain_preference: ToolchainPreference,
+    cache: &Cache,
+) -> Result<(PythonEnvironment, Option<SitePackages>)> {
+    let python_request = receipt.python().map(ToolchainRequest::parse);
+    let existing = match installed_tools.get_environment(name, cache) {
+        Ok(existing) => existing,
+        Err(err) => {
+            debug!("Ignoring invalid environment for tool `{name}`: {err}");
+            None
+        }
+    };
+    let site_packages = existing
+        .as_ref()
+        .map(SitePackages::from_environment)
+        .transpose()?;
+    let environment = match existing {
+        Some(environment)
+            if python_request.as_ref().map_or(true, |request| {
+                request.satisfied(environment.interpreter(), cache)
+            }) =>
+        {
+            environment
+        }
+        _ => {
+            debug!("Recreating environment for tool `{name}`");
+            let interpreter = Toolchain::find(
+                &python_request.unwrap_or_default(),
+                EnvironmentPreference::OnlySystem,
+                toolchain_preference,
+                cache,
+            )?
+            .into_interpreter();
+            installed_tools.environment(name, true, interpreter, cache)?
+        }
+    };
+    Ok((environment, site_packages))
+}
+
+/// Install the entry points for an installed tool after its environment was updated, and record
+/// the given requirements in its receipt.
+///
+/// Entry points that are no longer provided by the tool are removed, and the entry points are
+/// linked using the mode recorded in the receipt. Returns the installed version of the tool.
+pub(crate) fn update_tool_entrypoints(
+    name: &str,
+    receipt: &Tool,
+    requirements: Vec<pep508_rs::Requirement<VerbatimParsedUrl>>,
+    environment: &PythonEnvironment,
+    installed_tools: &InstalledTools,
+) -> Result<Version> {
+    let Some(from) = requirements.first() else {
+        bail!("Expected at least one requirement")
+    };
+
+    let site_packages = SitePackages::from_environment(environment)?;
+    let installed = site_packages.get_packages(&from.name);
+    let Some(installed_dist) = installed.first().copied() else {
+        bail!("Expected `{}` to be installed", from.name)
+    };
+
+    // Determine the entry points provided by the tool.
+    let executable_directory = find_executable_directory()?;
+    let target_entry_points =
+        entrypoint_targets(environment, installed_dist, &executable_directory)?;
+
+    // Remove any entry points that are no longer provided by the tool.
+    for entrypoint in receipt.entrypoints() {
+        if target_entry_points
+            .iter()
+            .all(|(_, _, target_path)| *target_path != entrypoint.install_path)
+        {
+            debug!("Removing stale entry point `{}`", entrypoint.name);
+            match fs_err::remove_file(&entrypoint.install_path) {
+                Ok(()) => {}
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+
+    // Install the entry points, skipping any new entry points that would overwrite an existing
+    // executable that isn't managed by this tool.
+    let mut entrypoints = Vec::with_capacity(target_entry_points.len());
+    for (entry_name, source_path, target_path) in target_entry_points {
+        let owned = receipt
+            .entrypoints()
+            .iter()
+            .any(|entrypoint| entrypoint.install_path == target_path);
+        if !owned && target_path.exists() {
+            warn_user!(
+                "Skipping entry point `{entry_name}` for tool `{name}`, since an executable already exists at: {}",
+                target_path.user_display()
+            );
+            continue;
+        }
+
+        debug!("Installing `{entry_name}`");
+        install_entrypoint(
+            &source_path,
+            &target_path,
+            receipt.entrypoint_link_mode().unwrap_or_default(),
+        )
+        .context("Failed to install entrypoint")?;
+
+        entrypoints.push(ToolEntrypoint::new(entry_name, target_path));
+    }
+
+    debug!("Updating receipt for tool `{name}`");
+    let version = installed_dist.version().clone();
+    let tool = Tool::new(
+        requirements,
+        receipt.python().map(ToString::to_string),
+        receipt.entrypoint_link_mode(),
+        entrypoints.into_iter(),
+    );
+    installed_tools.add_tool_receipt(name, tool)?;
+
+    Ok(version)
+}
@@ -0,0 +1,113 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use distribution_types::{InstalledDist, Name};
+use pep440_rs::VersionSpecifier;
+use pypi_types::RequirementSource;
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::{Concurrency, PreviewMode, Reinstall, Upgrade};
+use uv_installer::SitePackages;
+use uv_requirements::RequirementsSpecification;
+use uv_tool::InstalledTools;
+use uv_toolchain::ToolchainPreference;
+use uv_warnings::warn_user_once;
+
+use crate::commands::project::update_environment;
+use crate::commands::tool::{tool_environment, update_tool_entrypoints};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
+
+/// Reinstall a tool from the requirements in its receipt, without upgrading it.
+pub(crate) async fn reinstall(
+    name: String,
+    settings: ResolverInstallerSettings,
+    preview: PreviewMode,
+    toolchain_preference: ToolchainPreference,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv tool reinstall` is experimental and may change without warning.");
+    }
+
+    let installed_tools = InstalledTools::from_settings()?;
+
+    let Some(receipt) = installed_tools.get_tool_receipt(&name)? else {
+        bail!("Tool `{}` is not installed", name);
+    };
+
+    // Re-download and reinstall every package in the tool's environment.
+    let settings = ResolverInstallerSettings {
+        upgrade: Upgrade::None,
+        reinstall: Reinstall::All,
+        ..settings
+    };
+
+    let (environment, previous_site_packages) = tool_environment(
+        &name,
+        &receipt,
+        &installed_tools,
+        toolchain_preference,
+        cache,
+    )?;
+
+    // Reinstall from the requirements in the receipt, retaining any version pins.
+    let requirements = receipt.requirements().to_vec();
+    let mut spec = RequirementsSpecification::from_requirements(
+        requirements
+            .iter()
+            .cloned()
+            .map(pypi_types::Requirement::from)
+            .collect(),
+    );
+
+    // Hold every package that was installed from a registry at its installed version, rather than
+    // resolving to a newer version.
+    spec.constraints = previous_site_packages
+        .iter()
+        .flat_map(SitePackages::iter)
+        .filter(|dist| matches!(dist, InstalledDist::Registry(_)))
+        .map(|dist| pypi_types::Requirement {
+            name: dist.name().clone(),
+            extras: vec![],
+            marker: None,
+            source: RequirementSource::Registry {
+                specifier: VersionSpecifier::equals_version(dist.version().clone()).into(),
+                index: None,
+            },
+            origin: None,
+        })
+        .collect();
+
+    let environment = update_environment(
+        environment,
+        spec,
+        &settings,
+        preview,
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?;
+
+    let version = update_tool_entrypoints(
+        &name,
+        &receipt,
+        requirements,
+        &environment,
+        &installed_tools,
+    )?;
+
+    writeln!(printer.stderr(), "Reinstalled `{}` v{version}", name.cyan())?;
+
+    Ok(ExitStatus::Success)
+}
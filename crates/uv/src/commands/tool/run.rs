@@ -30,6 +30,7 @@ pub(crate) async fn run(
     python: Option<String>,
     from: Option<String>,
     with: Vec<String>,
+    with_requirements: Vec<PathBuf>,
     settings: ResolverInstallerSettings,
     _isolated: bool,
     preview: PreviewMode,
@@ -58,6 +59,11 @@ pub(crate) async fn run(
     let requirements = [RequirementsSource::from_package(from.to_string())]
         .into_iter()
         .chain(with.into_iter().map(RequirementsSource::from_package))
+        .chain(
+            with_requirements
+                .into_iter()
+                .map(RequirementsSource::from_requirements_txt),
+        )
         .collect::<Vec<_>>();
 
     let client_builder = BaseClientBuilder::new()
@@ -94,6 +100,7 @@ pub(crate) async fn run(
         uv_virtualenv::Prompt::None,
         false,
         false,
+        false,
     )?;
 
     // Install the ephemeral requirements.
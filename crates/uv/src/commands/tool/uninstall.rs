@@ -6,15 +6,15 @@ use itertools::Itertools;
 use tracing::debug;
 use uv_configuration::PreviewMode;
 use uv_fs::Simplified;
-use uv_tool::InstalledTools;
+use uv_tool::{InstalledTools, Tool};
 use uv_warnings::warn_user_once;
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
-/// Uninstall a tool.
+/// Uninstall a tool, or all tools if no name is provided.
 pub(crate) async fn uninstall(
-    name: String,
+    name: Option<String>,
     preview: PreviewMode,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -23,16 +23,50 @@ pub(crate) async fn uninstall(
     }
 
     let installed_tools = InstalledTools::from_settings()?;
-    let Some(receipt) = installed_tools.get_tool_receipt(&name)? else {
-        bail!("Tool `{}` is not installed", name);
+
+    let tools = if let Some(name) = name {
+        let Some(receipt) = installed_tools.get_tool_receipt(&name)? else {
+            bail!("Tool `{}` is not installed", name);
+        };
+        vec![(name, receipt)]
+    } else {
+        let mut tools = installed_tools.tools()?;
+        if tools.is_empty() {
+            writeln!(printer.stderr(), "No tools installed")?;
+            return Ok(ExitStatus::Success);
+        }
+        tools.sort_by(|(a, _), (b, _)| a.cmp(b));
+        tools
     };
 
+    for (name, receipt) in tools {
+        uninstall_tool(&name, &receipt, &installed_tools).await?;
+
+        writeln!(
+            printer.stderr(),
+            "Uninstalled: {}",
+            receipt
+                .entrypoints()
+                .iter()
+                .map(|entrypoint| &entrypoint.name)
+                .join(", ")
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Remove the environment and entrypoints for a single tool.
+async fn uninstall_tool(
+    name: &str,
+    receipt: &Tool,
+    installed_tools: &InstalledTools,
+) -> Result<()> {
     // Remove the tool itself.
-    installed_tools.remove_environment(&name)?;
+    installed_tools.remove_environment(name)?;
 
     // Remove the tool's entrypoints.
-    let entrypoints = receipt.entrypoints();
-    for entrypoint in entrypoints {
+    for entrypoint in receipt.entrypoints() {
         debug!(
             "Removing entrypoint: {}",
             entrypoint.install_path.user_display()
@@ -51,14 +85,5 @@ pub(crate) async fn uninstall(
         }
     }
 
-    writeln!(
-        printer.stderr(),
-        "Uninstalled: {}",
-        entrypoints
-            .iter()
-            .map(|entrypoint| &entrypoint.name)
-            .join(", ")
-    )?;
-
-    Ok(ExitStatus::Success)
+    Ok(())
 }
@@ -0,0 +1,200 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use pep440_rs::{Operator, VersionSpecifiers};
+use pep508_rs::VersionOrUrl;
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::{Concurrency, PreviewMode, Upgrade};
+use uv_requirements::RequirementsSpecification;
+use uv_tool::InstalledTools;
+use uv_toolchain::ToolchainPreference;
+use uv_warnings::warn_user_once;
+
+use crate::commands::project::update_environment;
+use crate::commands::tool::{tool_environment, update_tool_entrypoints};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
+
+/// Upgrade a tool, or all tools if no name is provided.
+pub(crate) async fn upgrade(
+    name: Option<String>,
+    settings: ResolverInstallerSettings,
+    preview: PreviewMode,
+    toolchain_preference: ToolchainPreference,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv tool upgrade` is experimental and may change without warning.");
+    }
+
+    let installed_tools = InstalledTools::from_settings()?;
+
+    let names = if let Some(name) = name {
+        if installed_tools.get_tool_receipt(&name)?.is_none() {
+            bail!("Tool `{}` is not installed", name);
+        }
+        vec![name]
+    } else {
+        let mut names = installed_tools
+            .tools()?
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        if names.is_empty() {
+            writeln!(printer.stderr(), "No tools installed")?;
+            return Ok(ExitStatus::Success);
+        }
+        names.sort();
+        names
+    };
+
+    // Re-resolve each tool from scratch, ignoring the versions that are already installed.
+    let settings = ResolverInstallerSettings {
+        upgrade: Upgrade::All,
+        ..settings
+    };
+
+    // Upgrade each tool in turn, continuing past any failures.
+    let mut failed = false;
+    for name in names {
+        if let Err(err) = upgrade_tool(
+            &name,
+            &installed_tools,
+            &settings,
+            preview,
+            toolchain_preference,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await
+        {
+            writeln!(
+                printer.stderr(),
+                "{}: Failed to upgrade `{}`: {err:#}",
+                "error".red().bold(),
+                name.cyan()
+            )?;
+            failed = true;
+        }
+    }
+
+    if failed {
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
+
+/// Upgrade the environment, entry points, and receipt for a single tool.
+async fn upgrade_tool(
+    name: &str,
+    installed_tools: &InstalledTools,
+    settings: &ResolverInstallerSettings,
+    preview: PreviewMode,
+    toolchain_preference: ToolchainPreference,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<()> {
+    let Some(receipt) = installed_tools.get_tool_receipt(name)? else {
+        bail!("Tool `{name}` is not installed");
+    };
+
+    let (environment, previous_site_packages) =
+        tool_environment(name, &receipt, installed_tools, toolchain_preference, cache)?;
+
+    // Drop any exact version pins (e.g., `black==24.2.0`) from the requirements, such that
+    // they're resolved to the latest versions. Other specifiers (e.g., `black>=24,<25`) are
+    // respected, and direct URL requirements are retained as-is.
+    let requirements = receipt
+        .requirements()
+        .iter()
+        .cloned()
+        .map(|mut requirement| {
+            if let Some(VersionOrUrl::VersionSpecifier(specifiers)) = &requirement.version_or_url {
+                let specifiers = specifiers
+                    .iter()
+                    .filter(|specifier| {
+                        !matches!(specifier.operator(), Operator::Equal | Operator::ExactEqual)
+                    })
+                    .cloned()
+                    .collect::<VersionSpecifiers>();
+                requirement.version_or_url = if specifiers.is_empty() {
+                    None
+                } else {
+                    Some(VersionOrUrl::VersionSpecifier(specifiers))
+                };
+            }
+            requirement
+        })
+        .collect::<Vec<_>>();
+    let Some(from) = requirements.first() else {
+        bail!("Expected at least one requirement")
+    };
+
+    let previous_version = previous_site_packages.as_ref().and_then(|site_packages| {
+        site_packages
+            .get_packages(&from.name)
+            .first()
+            .map(|dist| dist.version().clone())
+    });
+
+    let spec = RequirementsSpecification::from_requirements(
+        requirements
+            .iter()
+            .cloned()
+            .map(pypi_types::Requirement::from)
+            .collect(),
+    );
+
+    let environment = update_environment(
+        environment,
+        spec,
+        settings,
+        preview,
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?;
+
+    let version =
+        update_tool_entrypoints(name, &receipt, requirements, &environment, installed_tools)?;
+
+    match previous_version {
+        Some(previous_version) if previous_version == version => {
+            writeln!(
+                printer.stderr(),
+                "Tool `{}` is already up-to-date (v{version})",
+                name.cyan()
+            )?;
+        }
+        Some(previous_version) => {
+            writeln!(
+                printer.stderr(),
+                "Upgraded `{}`: v{previous_version} -> v{version}",
+                name.cyan()
+            )?;
+        }
+        None => {
+            writeln!(printer.stderr(), "Upgraded `{}` to v{version}", name.cyan())?;
+        }
+    }
+
+    Ok(())
+}
@@ -1,7 +1,8 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 use futures::StreamExt;
 use itertools::Itertools;
 use std::fmt::Write;
+use std::path::PathBuf;
 use uv_cache::Cache;
 use uv_client::Connectivity;
 use uv_configuration::PreviewMode;
@@ -11,13 +12,15 @@ use uv_toolchain::managed::{InstalledToolchain, InstalledToolchains};
 use uv_toolchain::{requests_from_version_file, ToolchainRequest};
 use uv_warnings::warn_user_once;
 
-use crate::commands::ExitStatus;
+use crate::commands::{elapsed, ExitStatus};
 use crate::printer::Printer;
 
 /// Download and install a Python toolchain.
 pub(crate) async fn install(
     targets: Vec<String>,
     force: bool,
+    verify: bool,
+    from_file: Option<PathBuf>,
     native_tls: bool,
     connectivity: Connectivity,
     preview: PreviewMode,
@@ -33,6 +36,55 @@ pub(crate) async fn install(
     let toolchains = InstalledToolchains::from_settings()?.init()?;
     let toolchain_dir = toolchains.root();
 
+    // Install from a local archive, if provided, rather than downloading.
+    if let Some(archive) = from_file {
+        let filename = archive
+            .file_name()
+            .map(|filename| filename.to_string_lossy())
+            .unwrap_or_default();
+        let Some(download) = PythonDownload::from_filename(&filename) else {
+            bail!(
+                "`{}` is not a known toolchain archive (the filename must match that of one of the available downloads)",
+                archive.user_display()
+            );
+        };
+
+        writeln!(
+            printer.stderr(),
+            "Installing {} from {}",
+            download.key(),
+            archive.user_display()
+        )?;
+        let path = match download
+            .install_from_file(&archive, toolchain_dir, verify, force)
+            .await?
+        {
+            DownloadResult::AlreadyAvailable(_) => {
+                writeln!(printer.stderr(), "Requested toolchain already installed.")?;
+                return Ok(ExitStatus::Success);
+            }
+            DownloadResult::Fetched(path) => path,
+        };
+        writeln!(
+            printer.stderr(),
+            "Installed Python {} to {}",
+            download.python_version(),
+            path.user_display()
+        )?;
+
+        // Ensure the installation has an externally managed marker
+        let installed = InstalledToolchain::new(path)?;
+        installed.ensure_externally_managed()?;
+
+        writeln!(
+            printer.stderr(),
+            "Installed 1 toolchain in {}",
+            elapsed(start.elapsed())
+        )?;
+
+        return Ok(ExitStatus::Success);
+    }
+
     let requests: Vec<_> = if targets.is_empty() {
         if let Some(requests) = requests_from_version_file().await? {
             requests
@@ -118,7 +170,7 @@ pub(crate) async fn install(
     let mut tasks = futures::stream::iter(downloads.iter())
         .map(|download| async {
             let _ = writeln!(printer.stderr(), "Downloading {}", download.key());
-            let result = download.fetch(&client, toolchain_dir).await;
+            let result = download.fetch(&client, toolchain_dir, verify).await;
             (download.python_version(), result)
         })
         .buffered(4);
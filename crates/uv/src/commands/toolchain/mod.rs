@@ -2,3 +2,5 @@ pub(crate) mod dir;
 pub(crate) mod find;
 pub(crate) mod install;
 pub(crate) mod list;
+pub(crate) mod show;
+pub(crate) mod uninstall;
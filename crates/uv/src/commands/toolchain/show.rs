@@ -0,0 +1,104 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::PreviewMode;
+use uv_distribution::Workspace;
+use uv_fs::Simplified;
+use uv_toolchain::managed::InstalledToolchains;
+use uv_toolchain::{Interpreter, ToolchainPreference, ToolchainRequest};
+use uv_warnings::warn_user_once;
+
+use crate::commands::project::FoundInterpreter;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Show the toolchain that would be used for the current project.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn show(
+    python: Option<String>,
+    json: bool,
+    toolchain_preference: ToolchainPreference,
+    preview: PreviewMode,
+    connectivity: Connectivity,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv toolchain show` is experimental and may change without warning.");
+    }
+
+    let workspace = Workspace::discover(&std::env::current_dir()?, None).await?;
+
+    // Select the interpreter in the same way as `uv sync`, respecting the `.python-version` file
+    // and the project's `requires-python`.
+    let interpreter = FoundInterpreter::discover(
+        &workspace,
+        python.as_deref().map(ToolchainRequest::parse),
+        toolchain_preference,
+        connectivity,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?
+    .into_interpreter();
+
+    let managed = is_managed(&interpreter)?;
+
+    if json {
+        let toolchain = ShowToolchain {
+            path: interpreter.sys_executable(),
+            version: interpreter.python_version().to_string(),
+            managed,
+        };
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&toolchain)?)?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stdout(),
+        "Path: {}",
+        interpreter.sys_executable().user_display().cyan()
+    )?;
+    writeln!(
+        printer.stdout(),
+        "Version: {}",
+        interpreter.python_version()
+    )?;
+    writeln!(
+        printer.stdout(),
+        "Managed: {}",
+        if managed { "yes" } else { "no" }
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Returns `true` if the interpreter (or the base interpreter of a virtual environment) is a
+/// toolchain installed by uv.
+fn is_managed(interpreter: &Interpreter) -> Result<bool> {
+    let Ok(base_prefix) = fs_err::canonicalize(interpreter.sys_base_prefix()) else {
+        return Ok(false);
+    };
+    let toolchains = InstalledToolchains::from_settings()?;
+    for toolchain in toolchains.find_all()? {
+        if fs_err::canonicalize(toolchain.path()).is_ok_and(|path| base_prefix.starts_with(path)) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+#[derive(Serialize)]
+struct ShowToolchain<'a> {
+    path: &'a Path,
+    version: String,
+    managed: bool,
+}
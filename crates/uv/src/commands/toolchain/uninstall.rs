@@ -0,0 +1,102 @@
+use std::fmt::Write;
+use std::io::IsTerminal;
+
+use anyhow::Result;
+use console::Term;
+use owo_colors::OwoColorize;
+
+use uv_cache::rm_rf;
+use uv_configuration::PreviewMode;
+use uv_requirements::confirm;
+use uv_toolchain::managed::InstalledToolchains;
+use uv_toolchain::ToolchainRequest;
+use uv_warnings::{warn_user, warn_user_once};
+
+use crate::commands::{elapsed, human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// Uninstall managed Python toolchains.
+pub(crate) async fn uninstall(
+    targets: Vec<String>,
+    all: bool,
+    yes: bool,
+    preview: PreviewMode,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv toolchain uninstall` is experimental and may change without warning.");
+    }
+
+    let start = std::time::Instant::now();
+
+    let toolchains = InstalledToolchains::from_settings()?.init()?;
+    let installed_toolchains: Vec<_> = toolchains.find_all()?.collect();
+
+    // Determine the toolchains to remove.
+    let matching_toolchains = if all {
+        installed_toolchains
+    } else {
+        let requests = targets
+            .iter()
+            .map(|target| ToolchainRequest::parse(target.as_str()))
+            .collect::<Vec<_>>();
+        for request in &requests {
+            if !installed_toolchains
+                .iter()
+                .any(|toolchain| toolchain.satisfies(request))
+            {
+                warn_user!("No installed toolchain found matching `{request}`");
+            }
+        }
+        installed_toolchains
+            .into_iter()
+            .filter(|toolchain| requests.iter().any(|request| toolchain.satisfies(request)))
+            .collect()
+    };
+
+    if matching_toolchains.is_empty() {
+        writeln!(printer.stderr(), "No toolchains to uninstall")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Unless `--yes` was provided, confirm the removal in an interactive terminal.
+    let term = Term::stderr();
+    if !yes && std::io::stdout().is_terminal() && term.is_term() {
+        for toolchain in &matching_toolchains {
+            writeln!(printer.stderr(), " - {}", toolchain.key())?;
+        }
+        let s = if matching_toolchains.len() == 1 {
+            ""
+        } else {
+            "s"
+        };
+        let prompt = format!("Remove {} toolchain{s}?", matching_toolchains.len());
+        if !confirm(&prompt, &term, false)? {
+            writeln!(printer.stderr(), "No toolchains uninstalled")?;
+            return Ok(ExitStatus::Success);
+        }
+    }
+
+    let mut total_bytes = 0;
+    for toolchain in &matching_toolchains {
+        let removal = rm_rf(toolchain.path())?;
+        total_bytes += removal.total_bytes;
+        writeln!(printer.stderr(), "Uninstalled {}", toolchain.key())?;
+    }
+
+    let s = if matching_toolchains.len() == 1 {
+        ""
+    } else {
+        "s"
+    };
+    let (bytes, unit) = human_readable_bytes(total_bytes);
+    writeln!(
+        printer.stderr(),
+        "Uninstalled {} toolchain{s} in {} ({})",
+        matching_toolchains.len(),
+        elapsed(start.elapsed()),
+        format!("freed {bytes:.1}{unit}").green()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
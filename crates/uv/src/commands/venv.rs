@@ -52,6 +52,8 @@ pub(crate) async fn venv(
     connectivity: Connectivity,
     seed: bool,
     allow_existing: bool,
+    relocatable: bool,
+    python_path: bool,
     exclude_newer: Option<ExcludeNewer>,
     native_tls: bool,
     preview: PreviewMode,
@@ -72,6 +74,8 @@ pub(crate) async fn venv(
         preview,
         toolchain_preference,
         allow_existing,
+        relocatable,
+        python_path,
         exclude_newer,
         native_tls,
         cache,
@@ -122,6 +126,8 @@ async fn venv_impl(
     preview: PreviewMode,
     toolchain_preference: ToolchainPreference,
     allow_existing: bool,
+    relocatable: bool,
+    python_path: bool,
     exclude_newer: Option<ExcludeNewer>,
     native_tls: bool,
     cache: &Cache,
@@ -148,6 +154,17 @@ async fn venv_impl(
     .into_diagnostic()?
     .into_interpreter();
 
+    // If only the interpreter path was requested, print it without creating the environment.
+    if python_path {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            interpreter.sys_executable().simplified_display()
+        )
+        .into_diagnostic()?;
+        return Ok(ExitStatus::Success);
+    }
+
     // Add all authenticated sources to the cache.
     for url in index_locations.urls() {
         store_credentials_from_url(url);
@@ -176,6 +193,7 @@ async fn venv_impl(
         prompt,
         system_site_packages,
         allow_existing,
+        relocatable,
     )
     .map_err(VenvError::Creation)?;
 
@@ -0,0 +1,109 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use walkdir::WalkDir;
+
+use uv_cache::rm_rf;
+use uv_configuration::PreviewMode;
+use uv_distribution::Workspace;
+use uv_fs::Simplified;
+use uv_warnings::warn_user_once;
+
+use crate::commands::{human_readable_bytes, ExitStatus};
+use crate::printer::Printer;
+
+/// Remove generated artifacts, like virtual environments and build outputs, from the members of
+/// the current workspace.
+pub(crate) async fn clean(
+    keep_venv: bool,
+    preview: PreviewMode,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv workspace clean` is experimental and may change without warning.");
+    }
+
+    let workspace = Workspace::discover(&std::env::current_dir()?, None).await?;
+
+    // Collect the artifacts across the workspace root and every member. A member may be nested
+    // within another (e.g., within the workspace root), so deduplicate the paths.
+    let mut artifacts = BTreeSet::new();
+    let roots = std::iter::once(workspace.root().as_path()).chain(
+        workspace
+            .packages()
+            .values()
+            .map(|member| member.root().as_path()),
+    );
+    for root in roots {
+        if !keep_venv {
+            let venv = root.join(".venv");
+            if venv.is_dir() {
+                artifacts.insert(venv);
+            }
+        }
+        for name in ["dist", "build"] {
+            let path = root.join(name);
+            if path.is_dir() {
+                artifacts.insert(path);
+            }
+        }
+        artifacts.extend(find_generated_dirs(root));
+    }
+
+    if artifacts.is_empty() {
+        writeln!(printer.stderr(), "No artifacts to remove")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let mut total_bytes = 0;
+    let mut num_removed = 0;
+    for path in &artifacts {
+        // Skip any artifacts that were removed along with an enclosing artifact.
+        if !path.exists() {
+            continue;
+        }
+        let removal = rm_rf(path)?;
+        total_bytes += removal.total_bytes;
+        num_removed += 1;
+        writeln!(printer.stderr(), "Removed `{}`", path.user_display().cyan())?;
+    }
+
+    let s = if num_removed == 1 { "y" } else { "ies" };
+    let (bytes, unit) = human_readable_bytes(total_bytes);
+    writeln!(
+        printer.stderr(),
+        "Removed {num_removed} director{s} ({})",
+        format!("freed {bytes:.1}{unit}").green()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Find the `__pycache__` and `*.egg-info` directories within a project.
+///
+/// Virtual environments and hidden directories (e.g., `.git`) are not searched.
+fn find_generated_dirs(root: &Path) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    let mut walker = WalkDir::new(root).min_depth(1).into_iter();
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy();
+        if name.starts_with('.') || entry.path().join("pyvenv.cfg").is_file() {
+            walker.skip_current_dir();
+            continue;
+        }
+        if name == "__pycache__" || name.ends_with(".egg-info") {
+            paths.push(entry.into_path());
+            walker.skip_current_dir();
+        }
+    }
+    paths
+}
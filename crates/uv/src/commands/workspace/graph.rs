@@ -0,0 +1,181 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::str::FromStr;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cli::GraphFormat;
+use uv_configuration::PreviewMode;
+use uv_distribution::{Workspace, WorkspaceMember};
+use uv_normalize::{ExtraName, PackageName};
+use uv_warnings::warn_user_once;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Display the dependencies between the members of the current workspace.
+pub(crate) async fn graph(
+    output_format: GraphFormat,
+    preview: PreviewMode,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv workspace graph` is experimental and may change without warning.");
+    }
+
+    let workspace = Workspace::discover(&std::env::current_dir()?, None).await?;
+
+    // For each member, collect the dependencies on other members.
+    let graph = workspace
+        .packages()
+        .iter()
+        .map(|(name, member)| (name, member_edges(name, member, &workspace)))
+        .collect::<BTreeMap<_, _>>();
+
+    match output_format {
+        GraphFormat::Text => {
+            for (name, edges) in &graph {
+                writeln!(printer.stdout(), "{}", name.bold())?;
+                for (index, edge) in edges.iter().enumerate() {
+                    let prefix = if index + 1 == edges.len() {
+                        "└──"
+                    } else {
+                        "├──"
+                    };
+                    match &edge.kind {
+                        EdgeKind::Required => {
+                            writeln!(printer.stdout(), "{prefix} {}", edge.to)?;
+                        }
+                        EdgeKind::Extra(extra) => {
+                            writeln!(
+                                printer.stdout(),
+                                "{prefix} {} {}",
+                                edge.to,
+                                format!("(extra: {extra})").dimmed()
+                            )?;
+                        }
+                        EdgeKind::Dev => {
+                            writeln!(
+                                printer.stdout(),
+                                "{prefix} {} {}",
+                                edge.to,
+                                "(dev)".dimmed()
+                            )?;
+                        }
+                    }
+                }
+            }
+        }
+        GraphFormat::Dot => {
+            writeln!(printer.stdout(), "digraph workspace {{")?;
+            for name in graph.keys() {
+                writeln!(printer.stdout(), "    \"{name}\";")?;
+            }
+            for (name, edges) in &graph {
+                for edge in edges {
+                    match &edge.kind {
+                        EdgeKind::Required => {
+                            writeln!(printer.stdout(), "    \"{name}\" -> \"{}\";", edge.to)?;
+                        }
+                        EdgeKind::Extra(extra) => {
+                            writeln!(
+                                printer.stdout(),
+                                "    \"{name}\" -> \"{}\" [label=\"{extra}\", style=dashed];",
+                                edge.to
+                            )?;
+                        }
+                        EdgeKind::Dev => {
+                            writeln!(
+                                printer.stdout(),
+                                "    \"{name}\" -> \"{}\" [label=\"dev\", style=dotted];",
+                                edge.to
+                            )?;
+                        }
+                    }
+                }
+            }
+            writeln!(printer.stdout(), "}}")?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Collect the dependencies of a workspace member on other members of the workspace.
+///
+/// Reads the `project.dependencies`, `project.optional-dependencies`, and
+/// `tool.uv.dev-dependencies` of the member's `pyproject.toml`.
+pub(super) fn member_edges(
+    name: &PackageName,
+    member: &WorkspaceMember,
+    workspace: &Workspace,
+) -> BTreeSet<Edge> {
+    let project = member.project();
+
+    let required = project
+        .dependencies
+        .iter()
+        .flatten()
+        .map(|requirement| (requirement, EdgeKind::Required));
+    let optional =
+        project
+            .optional_dependencies
+            .iter()
+            .flatten()
+            .flat_map(|(extra, requirements)| {
+                requirements
+                    .iter()
+                    .map(|requirement| (requirement, EdgeKind::Extra(extra.clone())))
+            });
+
+    let mut edges = required
+        .chain(optional)
+        .filter_map(|(requirement, kind)| {
+            match pep508_rs::Requirement::<pep508_rs::VerbatimUrl>::from_str(requirement) {
+                Ok(requirement) => Some((requirement.name, kind)),
+                Err(err) => {
+                    debug!("Ignoring invalid requirement `{requirement}` in `{name}`: {err}");
+                    None
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    edges.extend(
+        member
+            .pyproject_toml()
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.dev_dependencies.as_ref())
+            .into_iter()
+            .flatten()
+            .map(|requirement| (requirement.name.clone(), EdgeKind::Dev)),
+    );
+
+    edges
+        .into_iter()
+        .filter(|(to, _)| to != name && workspace.packages().contains_key(to))
+        .map(|(to, kind)| Edge { to, kind })
+        .collect()
+}
+
+/// A dependency of one workspace member on another.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) struct Edge {
+    pub(super) to: PackageName,
+    pub(super) kind: EdgeKind,
+}
+
+/// The kind of a dependency between two workspace members.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum EdgeKind {
+    /// A dependency in `project.dependencies`.
+    Required,
+    /// A dependency in `project.optional-dependencies`, under the given extra.
+    Extra(ExtraName),
+    /// A dependency in `tool.uv.dev-dependencies`.
+    Dev,
+}
@@ -0,0 +1,109 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_configuration::PreviewMode;
+use uv_distribution::Workspace;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_warnings::warn_user_once;
+
+use crate::commands::project::find_requires_python;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Display information about the current workspace.
+pub(crate) async fn info(json: bool, preview: PreviewMode, printer: Printer) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv workspace info` is experimental and may change without warning.");
+    }
+
+    let workspace = Workspace::discover(&std::env::current_dir()?, None).await?;
+
+    let members = workspace
+        .packages()
+        .iter()
+        .map(|(name, member)| Member {
+            name,
+            version: member.project().version.as_deref(),
+            path: relative_path(member.root(), workspace.root()),
+        })
+        .collect::<Vec<_>>();
+    let requires_python =
+        find_requires_python(&workspace)?.map(|requires_python| requires_python.to_string());
+    let lockfile = workspace.root().join("uv.lock");
+
+    if json {
+        let info = Info {
+            root: workspace.root(),
+            members,
+            requires_python,
+            lockfile,
+        };
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&info)?)?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stdout(),
+        "Workspace root: {}",
+        workspace.root().simplified_display().cyan()
+    )?;
+    writeln!(printer.stdout(), "Members:")?;
+    for member in &members {
+        if let Some(version) = &member.version {
+            writeln!(
+                printer.stdout(),
+                "  {} v{version} ({})",
+                member.name.bold(),
+                member.path
+            )?;
+        } else {
+            writeln!(
+                printer.stdout(),
+                "  {} ({})",
+                member.name.bold(),
+                member.path
+            )?;
+        }
+    }
+    if let Some(requires_python) = &requires_python {
+        writeln!(printer.stdout(), "Requires-Python: {requires_python}")?;
+    }
+    writeln!(
+        printer.stdout(),
+        "Lockfile: {}",
+        lockfile.simplified_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return the path of a workspace member relative to the workspace root.
+fn relative_path(path: &Path, root: &Path) -> String {
+    match path.strip_prefix(root) {
+        Ok(relative) if relative.as_os_str().is_empty() => ".".to_string(),
+        Ok(relative) => relative.simplified_display().to_string(),
+        Err(_) => path.simplified_display().to_string(),
+    }
+}
+
+/// The JSON representation of a workspace, as emitted by `uv workspace info --json`.
+#[derive(Debug, Serialize)]
+struct Info<'a> {
+    root: &'a Path,
+    members: Vec<Member<'a>>,
+    requires_python: Option<String>,
+    lockfile: PathBuf,
+}
+
+/// A member of the workspace.
+#[derive(Debug, Serialize)]
+struct Member<'a> {
+    name: &'a PackageName,
+    version: Option<&'a str>,
+    path: String,
+}
@@ -0,0 +1,177 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anstream::eprint;
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::{Concurrency, PreviewMode};
+use uv_distribution::pyproject::PyProjectToml;
+use uv_distribution::pyproject_mut::PyProjectTomlMut;
+use uv_distribution::Workspace;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_toolchain::{ToolchainPreference, ToolchainRequest};
+use uv_warnings::warn_user_once;
+
+use crate::commands::pip;
+use crate::commands::project::init::init_project;
+use crate::commands::project::lock::{do_lock, LockAnnotations, LockedVersions};
+use crate::commands::project::{self, ProjectError};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// Add a project to the members of the current workspace, then lock the workspace.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn add(
+    name: PackageName,
+    path: Option<PathBuf>,
+    python: Option<String>,
+    settings: ResolverSettings,
+    preview: PreviewMode,
+    toolchain_preference: ToolchainPreference,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!(
+            "`uv workspace member add` is experimental and may change without warning."
+        );
+    }
+
+    let workspace = Workspace::discover(&std::env::current_dir()?, None).await?;
+
+    if workspace.packages().contains_key(&name) {
+        writeln!(
+            printer.stderr(),
+            "Project `{}` is already a member of the workspace",
+            name.cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Default to a directory named after the project in the workspace root.
+    let path = match path {
+        Some(path) => uv_fs::absolutize_path(&path)?.to_path_buf(),
+        None => workspace.root().join(name.to_string()),
+    };
+    let Ok(relative) = path.strip_prefix(workspace.root()) else {
+        bail!(
+            "`{}` is not within the workspace root at `{}`",
+            path.user_display(),
+            workspace.root().user_display()
+        );
+    };
+    if relative.as_os_str().is_empty() {
+        bail!("The workspace root cannot be added as a member of itself");
+    }
+
+    // Create the project if it doesn't exist; otherwise, ensure that it's the requested project.
+    let pyproject_path = path.join("pyproject.toml");
+    if pyproject_path.try_exists()? {
+        let pyproject_toml =
+            PyProjectToml::from_string(fs_err::read_to_string(&pyproject_path)?)
+                .with_context(|| format!("Failed to parse `{}`", pyproject_path.user_display()))?;
+        let Some(project) = pyproject_toml.project else {
+            bail!(
+                "`{}` does not contain a `[project]` table",
+                pyproject_path.user_display()
+            );
+        };
+        if project.name != name {
+            bail!(
+                "The project at `{}` is named `{}`, not `{name}`",
+                path.user_display(),
+                project.name
+            );
+        }
+    } else {
+        init_project(&path, &name, None, false, false)?;
+        writeln!(
+            printer.stderr(),
+            "Initialized project `{}` at `{}`",
+            name.cyan(),
+            path.user_display().cyan()
+        )?;
+    }
+
+    // Register the project in `tool.uv.workspace.members`, unless it's already matched by one of
+    // the existing members (e.g., a glob).
+    let workspace = Workspace::discover(&std::env::current_dir()?, None).await?;
+    if workspace.packages().contains_key(&name) {
+        debug!("Project `{name}` is already included by the workspace members");
+    } else {
+        let member = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .join("/");
+        let root_pyproject_path = workspace.root().join("pyproject.toml");
+        let root_pyproject =
+            PyProjectToml::from_string(fs_err::read_to_string(&root_pyproject_path)?)
+                .with_context(|| {
+                    format!("Failed to parse `{}`", root_pyproject_path.user_display())
+                })?;
+        let mut pyproject = PyProjectTomlMut::from_toml(&root_pyproject)?;
+        pyproject.add_workspace_member(&member)?;
+        fs_err::write(&root_pyproject_path, pyproject.to_string())?;
+
+        writeln!(
+            printer.stderr(),
+            "Added `{}` to the workspace members",
+            member.cyan()
+        )?;
+    }
+
+    // Lock the workspace, including the new member.
+    let workspace = Workspace::discover(&std::env::current_dir()?, None).await?;
+    let venv = project::lock_environment(
+        &workspace,
+        python.as_deref().map(ToolchainRequest::parse),
+        toolchain_preference,
+        connectivity,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?;
+    let no_build_isolation = workspace.no_build_isolation_packages();
+
+    match do_lock(
+        &workspace,
+        venv.interpreter(),
+        settings.as_ref(),
+        project::build_isolation(&venv, &no_build_isolation),
+        LockedVersions::default(),
+        LockAnnotations::default(),
+        None,
+        false,
+        None,
+        preview,
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(_) => Ok(ExitStatus::Success),
+        Err(ProjectError::Operation(pip::operations::Error::Resolve(
+            uv_resolver::ResolveError::NoSolution(err),
+        ))) => {
+            let report = miette::Report::msg(format!("{err}"))
+                .context("No solution found when resolving dependencies:");
+            eprint!("{report:?}");
+            Ok(ExitStatus::Failure)
+        }
+        Err(err) => Err(err.into()),
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) mod clean;
+pub(crate) mod graph;
+pub(crate) mod info;
+pub(crate) mod member;
+pub(crate) mod publish;
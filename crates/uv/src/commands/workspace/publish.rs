@@ -0,0 +1,117 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+
+use uv_configuration::PreviewMode;
+use uv_distribution::Workspace;
+use uv_normalize::PackageName;
+use uv_warnings::warn_user_once;
+
+use crate::commands::project::init::PRIVATE_CLASSIFIER;
+use crate::commands::workspace::graph::{member_edges, EdgeKind};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Publish the members of the current workspace, in dependency order.
+pub(crate) async fn publish(
+    dry_run: bool,
+    preview: PreviewMode,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if preview.is_disabled() {
+        warn_user_once!("`uv workspace publish` is experimental and may change without warning.");
+    }
+
+    let workspace = Workspace::discover(&std::env::current_dir()?, None).await?;
+
+    let order = publication_order(&workspace)?;
+
+    // Skip any members that are marked as private.
+    let (private, order): (Vec<_>, Vec<_>) = order.into_iter().partition(|name| {
+        workspace.packages()[*name]
+            .project()
+            .classifiers
+            .iter()
+            .flatten()
+            .any(|classifier| classifier == PRIVATE_CLASSIFIER)
+    });
+    if !private.is_empty() {
+        let s = if private.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "Skipping {} marked as private (`{PRIVATE_CLASSIFIER}`): {}",
+            format!("{} package{s}", private.len()).bold(),
+            private.iter().map(|name| name.cyan()).join(", ")
+        )?;
+    }
+
+    if !dry_run {
+        bail!(
+            "Uploading to a package index is not yet supported; use `--dry-run` to display the publication order"
+        );
+    }
+
+    let s = if order.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "Would publish {} in order:",
+        format!("{} package{s}", order.len()).bold()
+    )?;
+    for name in order {
+        let version = workspace.packages()[name].project().version.as_ref();
+        match version {
+            Some(version) => writeln!(printer.stdout(), "{name}=={version}")?,
+            None => writeln!(printer.stdout(), "{name}")?,
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Order the members of the workspace such that every member is published after the members it
+/// depends on.
+///
+/// Development dependencies are ignored, since they aren't part of the published metadata. Members
+/// that don't depend on each other are ordered by name.
+fn publication_order(workspace: &Workspace) -> Result<Vec<&PackageName>> {
+    let mut remaining = workspace
+        .packages()
+        .iter()
+        .map(|(name, member)| {
+            let dependencies = member_edges(name, member, workspace)
+                .into_iter()
+                .filter(|edge| !matches!(edge.kind, EdgeKind::Dev))
+                .map(|edge| edge.to)
+                .collect::<BTreeSet<_>>();
+            (name, dependencies)
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let mut order = Vec::with_capacity(remaining.len());
+    while !remaining.is_empty() {
+        let ready = remaining
+            .iter()
+            .filter(|(_, dependencies)| {
+                dependencies
+                    .iter()
+                    .all(|dependency| !remaining.contains_key(dependency))
+            })
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+        if ready.is_empty() {
+            bail!(
+                "Workspace members have cyclic dependencies: {}",
+                remaining.keys().map(|name| name.cyan()).join(", ")
+            );
+        }
+        for name in ready {
+            remaining.remove(name);
+            order.push(name);
+        }
+    }
+
+    Ok(order)
+}
@@ -20,6 +20,9 @@ use uv_cli::{
 #[cfg(feature = "self-update")]
 use uv_cli::{SelfCommand, SelfNamespace};
 use uv_cli::{ToolCommand, ToolNamespace, ToolchainCommand, ToolchainNamespace};
+use uv_cli::{
+    WorkspaceCommand, WorkspaceMemberCommand, WorkspaceMemberNamespace, WorkspaceNamespace,
+};
 use uv_configuration::Concurrency;
 use uv_distribution::Workspace;
 use uv_requirements::RequirementsSource;
@@ -390,6 +393,11 @@ async fn run() -> Result<ExitStatus> {
                 .constraint
                 .into_iter()
                 .map(RequirementsSource::from_constraints_txt)
+                .chain(
+                    args.constraint_package
+                        .into_iter()
+                        .map(RequirementsSource::Package),
+                )
                 .collect::<Vec<_>>();
             let overrides = args
                 .r#override
@@ -402,11 +410,18 @@ async fn run() -> Result<ExitStatus> {
                 &constraints,
                 &overrides,
                 args.overrides_from_workspace,
+                &args.constraint_group,
+                &args.constraint_groups,
+                &args.exclude,
+                args.strip_extras,
+                args.marker,
+                args.marker_env,
                 &args.settings.extras,
                 args.settings.resolution,
                 args.settings.prerelease,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
+                args.keep_installed,
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.keyring_provider,
@@ -433,6 +448,29 @@ async fn run() -> Result<ExitStatus> {
                 globals.preview,
                 cache,
                 args.dry_run,
+                args.format,
+                args.log,
+                args.generate_checksums,
+                args.check_build_dependencies,
+                args.isolated_resolution,
+                args.ignore_conflicts,
+                args.no_warn_conflicts,
+                args.report_diff,
+                args.report_unchanged,
+                args.report_skipped,
+                args.skip_locked,
+                args.force_reinstall_deps,
+                args.prefer_binary,
+                args.prefer_sdist,
+                args.retries,
+                args.retry_delay,
+                args.max_retries,
+                args.retry_status_codes,
+                args.connect_timeout,
+                args.read_timeout,
+                args.socks_proxy,
+                args.ssl_ca_bundle,
+                args.resolver_timeout,
                 printer,
             )
             .await
@@ -469,6 +507,7 @@ async fn run() -> Result<ExitStatus> {
                 globals.native_tls,
                 globals.preview,
                 args.settings.keyring_provider,
+                args.yes,
                 printer,
             )
             .await
@@ -531,6 +570,7 @@ async fn run() -> Result<ExitStatus> {
             commands::pip_show(
                 args.package,
                 args.settings.strict,
+                args.json,
                 args.settings.python.as_deref(),
                 args.settings.system,
                 globals.preview,
@@ -625,6 +665,8 @@ async fn run() -> Result<ExitStatus> {
                 globals.connectivity,
                 args.seed,
                 args.allow_existing,
+                args.relocatable,
+                args.python_path,
                 args.settings.exclude_newer,
                 globals.native_tls,
                 globals.preview,
@@ -633,6 +675,34 @@ async fn run() -> Result<ExitStatus> {
             )
             .await
         }
+        Commands::Project(ProjectCommand::Init(args)) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::InitSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(args.refresh);
+
+            commands::init(
+                args.path,
+                args.name,
+                args.gitignore,
+                args.license,
+                args.workspace_root,
+                args.flat_layout,
+                args.private,
+                args.dependency,
+                args.settings,
+                globals.toolchain_preference,
+                globals.preview,
+                globals.connectivity,
+                Concurrency::default(),
+                globals.native_tls,
+                &cache,
+                printer,
+            )
+            .await
+        }
         Commands::Project(ProjectCommand::Run(args)) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::RunSettings::resolve(args, filesystem);
@@ -651,9 +721,13 @@ async fn run() -> Result<ExitStatus> {
                 args.extras,
                 args.dev,
                 args.command,
+                args.list,
                 requirements,
+                args.env,
+                args.clean_env,
                 args.python,
                 args.package,
+                args.no_project,
                 args.settings,
                 globals.isolated,
                 globals.preview,
@@ -678,6 +752,9 @@ async fn run() -> Result<ExitStatus> {
                 args.extras,
                 args.dev,
                 args.modifications,
+                args.check,
+                args.no_editable,
+                args.package,
                 args.python,
                 globals.toolchain_preference,
                 args.settings,
@@ -699,6 +776,11 @@ async fn run() -> Result<ExitStatus> {
             let cache = cache.init()?.with_refresh(args.refresh);
 
             commands::lock(
+                args.annotation_style,
+                args.no_annotate,
+                args.strip_extras,
+                args.check,
+                args.output_file,
                 args.python,
                 args.settings,
                 globals.preview,
@@ -727,7 +809,14 @@ async fn run() -> Result<ExitStatus> {
                 args.rev,
                 args.tag,
                 args.branch,
+                args.index,
                 args.extras,
+                args.python_version,
+                args.no_build_isolation,
+                args.reuse_pinned,
+                args.dry_run,
+                args.format,
+                &std::env::current_dir()?,
                 args.package,
                 args.python,
                 args.settings,
@@ -791,6 +880,7 @@ async fn run() -> Result<ExitStatus> {
                 args.python,
                 args.from,
                 args.with,
+                args.with_requirements,
                 args.settings,
                 globals.isolated,
                 globals.preview,
@@ -819,6 +909,53 @@ async fn run() -> Result<ExitStatus> {
                 args.python,
                 args.with,
                 args.force,
+                args.entrypoint_link_mode,
+                args.settings,
+                globals.preview,
+                globals.toolchain_preference,
+                globals.connectivity,
+                Concurrency::default(),
+                globals.native_tls,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Upgrade(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolUpgradeSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(args.refresh);
+
+            commands::tool_upgrade(
+                args.name,
+                args.settings,
+                globals.preview,
+                globals.toolchain_preference,
+                globals.connectivity,
+                Concurrency::default(),
+                globals.native_tls,
+                &cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Reinstall(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolReinstallSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(args.refresh);
+
+            commands::tool_reinstall(
+                args.name,
                 args.settings,
                 globals.preview,
                 globals.toolchain_preference,
@@ -837,7 +974,22 @@ async fn run() -> Result<ExitStatus> {
             let args = settings::ToolListSettings::resolve(args, filesystem);
             show_settings!(args);
 
-            commands::tool_list(globals.preview, printer).await
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::tool_list(args.versions, args.json, globals.preview, &cache, printer).await
+        }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Inspect(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolInspectSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::tool_inspect(args.name, args.json, globals.preview, &cache, printer).await
         }
         Commands::Tool(ToolNamespace {
             command: ToolCommand::Uninstall(args),
@@ -888,6 +1040,8 @@ async fn run() -> Result<ExitStatus> {
             commands::toolchain_install(
                 args.targets,
                 args.force,
+                args.verify,
+                args.from_file,
                 globals.native_tls,
                 globals.connectivity,
                 globals.preview,
@@ -896,6 +1050,22 @@ async fn run() -> Result<ExitStatus> {
             )
             .await
         }
+        Commands::Toolchain(ToolchainNamespace {
+            command: ToolchainCommand::Uninstall(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolchainUninstallSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::toolchain_uninstall(
+                args.targets,
+                args.all,
+                args.yes,
+                globals.preview,
+                printer,
+            )
+            .await
+        }
         Commands::Toolchain(ToolchainNamespace {
             command: ToolchainCommand::Find(args),
         }) => {
@@ -914,12 +1084,98 @@ async fn run() -> Result<ExitStatus> {
             )
             .await
         }
+        Commands::Toolchain(ToolchainNamespace {
+            command: ToolchainCommand::Show(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolchainShowSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::toolchain_show(
+                args.python,
+                args.json,
+                globals.toolchain_preference,
+                globals.preview,
+                globals.connectivity,
+                globals.native_tls,
+                &cache,
+                printer,
+            )
+            .await
+        }
         Commands::Toolchain(ToolchainNamespace {
             command: ToolchainCommand::Dir,
         }) => {
             commands::toolchain_dir(globals.preview)?;
             Ok(ExitStatus::Success)
         }
+        Commands::Workspace(WorkspaceNamespace {
+            command: WorkspaceCommand::Info(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::WorkspaceInfoSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::workspace_info(args.json, globals.preview, printer).await
+        }
+        Commands::Workspace(WorkspaceNamespace {
+            command: WorkspaceCommand::Graph(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::WorkspaceGraphSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::workspace_graph(args.output_format, globals.preview, printer).await
+        }
+        Commands::Workspace(WorkspaceNamespace {
+            command: WorkspaceCommand::Publish(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::WorkspacePublishSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::workspace_publish(args.dry_run, globals.preview, printer).await
+        }
+        Commands::Workspace(WorkspaceNamespace {
+            command: WorkspaceCommand::Clean(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::WorkspaceCleanSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::workspace_clean(args.keep_venv, globals.preview, printer).await
+        }
+        Commands::Workspace(WorkspaceNamespace {
+            command:
+                WorkspaceCommand::Member(WorkspaceMemberNamespace {
+                    command: WorkspaceMemberCommand::Add(args),
+                }),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::WorkspaceMemberAddSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(args.refresh);
+
+            commands::workspace_member_add(
+                args.name,
+                args.path,
+                args.python,
+                args.settings,
+                globals.preview,
+                globals.toolchain_preference,
+                globals.connectivity,
+                Concurrency::default(),
+                globals.native_tls,
+                &cache,
+                printer,
+            )
+            .await
+        }
     }
 }
 
@@ -1,27 +1,33 @@
+use std::collections::BTreeMap;
 use std::env::VarError;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::process;
 use std::str::FromStr;
+use std::time::Duration;
 
-use distribution_types::IndexLocations;
+use distribution_types::{IndexLocations, IndexUrl};
 use install_wheel_rs::linker::LinkMode;
-use pep508_rs::{ExtraName, RequirementOrigin};
+use pep508_rs::{ExtraName, MarkerTree, RequirementOrigin};
 use pypi_types::Requirement;
+use url::Url;
 use uv_cache::{CacheArgs, Refresh};
 use uv_cli::options::{flag, installer_options, resolver_installer_options, resolver_options};
 use uv_cli::{
-    AddArgs, ColorChoice, Commands, ExternalCommand, GlobalArgs, ListFormat, LockArgs, Maybe,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, RemoveArgs, RunArgs, SyncArgs, ToolInstallArgs,
-    ToolListArgs, ToolRunArgs, ToolUninstallArgs, ToolchainFindArgs, ToolchainInstallArgs,
-    ToolchainListArgs, VenvArgs,
+    AddArgs, ColorChoice, Commands, DiffFormat, DryRunFormat, ExternalCommand, GlobalArgs,
+    GraphFormat, InitArgs, License, ListFormat, LockAnnotationStyle, LockArgs, Maybe, PipCheckArgs,
+    PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs, PipSyncArgs,
+    PipTreeArgs, PipUninstallArgs, RemoveArgs, RunArgs, SyncArgs, ToolInspectArgs, ToolInstallArgs,
+    ToolListArgs, ToolReinstallArgs, ToolRunArgs, ToolUninstallArgs, ToolUpgradeArgs,
+    ToolchainFindArgs, ToolchainInstallArgs, ToolchainListArgs, ToolchainShowArgs,
+    ToolchainUninstallArgs, VenvArgs, WorkspaceCleanArgs, WorkspaceGraphArgs, WorkspaceInfoArgs,
+    WorkspaceMemberAddArgs, WorkspacePublishArgs,
 };
 use uv_client::Connectivity;
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, ExtrasSpecification, IndexStrategy,
-    KeyringProviderType, NoBinary, NoBuild, PreviewMode, Reinstall, SetupPyStrategy, TargetTriple,
-    Upgrade,
+    BuildOptions, Concurrency, ConfigSettings, EntrypointLinkMode, ExtrasSpecification,
+    IndexStrategy, KeyringProviderType, NoBinary, NoBuild, PreviewMode, Reinstall, SetupPyStrategy,
+    TargetTriple, Upgrade,
 };
 use uv_distribution::pyproject::DependencyType;
 use uv_normalize::PackageName;
@@ -141,15 +147,72 @@ impl CacheSettings {
     }
 }
 
+/// The resolved settings to use for an `init` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct InitSettings {
+    pub(crate) path: Option<String>,
+    pub(crate) name: Option<PackageName>,
+    pub(crate) gitignore: bool,
+    pub(crate) license: Option<License>,
+    pub(crate) workspace_root: bool,
+    pub(crate) flat_layout: bool,
+    pub(crate) private: bool,
+    pub(crate) dependency: Vec<String>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl InitSettings {
+    /// Resolve the [`InitSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: InitArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let InitArgs {
+            path,
+            name,
+            gitignore,
+            no_gitignore,
+            license,
+            workspace_root,
+            src_layout: _,
+            flat_layout,
+            private,
+            dependency,
+            installer,
+            build,
+            refresh,
+        } = args;
+
+        Self {
+            path,
+            name,
+            gitignore: flag(gitignore, no_gitignore).unwrap_or(true),
+            license,
+            workspace_root,
+            flat_layout,
+            private,
+            dependency,
+            refresh: Refresh::from(refresh),
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `run` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub(crate) struct RunSettings {
     pub(crate) extras: ExtrasSpecification,
     pub(crate) dev: bool,
-    pub(crate) command: ExternalCommand,
+    pub(crate) command: Option<ExternalCommand>,
+    pub(crate) list: bool,
     pub(crate) with: Vec<String>,
+    pub(crate) env: Vec<(String, String)>,
+    pub(crate) clean_env: bool,
     pub(crate) package: Option<PackageName>,
+    pub(crate) no_project: bool,
     pub(crate) python: Option<String>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
@@ -166,11 +229,16 @@ impl RunSettings {
             dev,
             no_dev,
             command,
+            list,
             with,
+            env,
+            clean_env,
+            inherit_env,
             installer,
             build,
             refresh,
             package,
+            no_project,
             python,
         } = args;
 
@@ -181,8 +249,12 @@ impl RunSettings {
             ),
             dev: flag(dev, no_dev).unwrap_or(true),
             command,
+            list,
             with,
+            env,
+            clean_env: flag(clean_env, inherit_env).unwrap_or(false),
             package,
+            no_project,
             python,
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
@@ -200,6 +272,7 @@ pub(crate) struct ToolRunSettings {
     pub(crate) command: ExternalCommand,
     pub(crate) from: Option<String>,
     pub(crate) with: Vec<String>,
+    pub(crate) with_requirements: Vec<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
@@ -213,6 +286,7 @@ impl ToolRunSettings {
             command,
             from,
             with,
+            with_requirements,
             installer,
             build,
             refresh,
@@ -223,6 +297,7 @@ impl ToolRunSettings {
             command,
             from,
             with,
+            with_requirements,
             python,
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
@@ -244,6 +319,7 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) force: bool,
+    pub(crate) entrypoint_link_mode: Option<EntrypointLinkMode>,
 }
 
 impl ToolInstallSettings {
@@ -256,6 +332,7 @@ impl ToolInstallSettings {
             with,
             installer,
             force,
+            entrypoint_link_mode,
             build,
             refresh,
             python,
@@ -267,6 +344,40 @@ impl ToolInstallSettings {
             with,
             python,
             force,
+            entrypoint_link_mode,
+            refresh: Refresh::from(refresh),
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `tool upgrade` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct ToolUpgradeSettings {
+    /// The tool to upgrade, or `None` to upgrade all tools.
+    pub(crate) name: Option<String>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl ToolUpgradeSettings {
+    /// Resolve the [`ToolUpgradeSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolUpgradeArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolUpgradeArgs {
+            name,
+            all,
+            installer,
+            build,
+            refresh,
+        } = args;
+
+        Self {
+            name: if all { None } else { name },
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
                 resolver_installer_options(installer, build),
@@ -276,18 +387,70 @@ impl ToolInstallSettings {
     }
 }
 
+/// The resolved settings to use for a `tool reinstall` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct ToolReinstallSettings {
+    pub(crate) name: String,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl ToolReinstallSettings {
+    /// Resolve the [`ToolReinstallSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolReinstallArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolReinstallArgs {
+            name,
+            installer,
+            build,
+        } = args;
+
+        Self {
+            name,
+            // Re-download every package, rather than reinstalling from the cache.
+            refresh: Refresh::from_args(Some(true), vec![]),
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `tool list` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
-pub(crate) struct ToolListSettings;
+pub(crate) struct ToolListSettings {
+    pub(crate) versions: bool,
+    pub(crate) json: bool,
+}
 
 impl ToolListSettings {
     /// Resolve the [`ToolListSettings`] from the CLI and filesystem configuration.
     #[allow(clippy::needless_pass_by_value)]
     pub(crate) fn resolve(args: ToolListArgs, _filesystem: Option<FilesystemOptions>) -> Self {
-        let ToolListArgs {} = args;
+        let ToolListArgs { versions, json } = args;
+
+        Self { versions, json }
+    }
+}
+
+/// The resolved settings to use for a `tool inspect` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct ToolInspectSettings {
+    pub(crate) name: String,
+    pub(crate) json: bool,
+}
+
+impl ToolInspectSettings {
+    /// Resolve the [`ToolInspectSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolInspectArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolInspectArgs { name, json } = args;
 
-        Self {}
+        Self { name, json }
     }
 }
 
@@ -295,16 +458,19 @@ impl ToolListSettings {
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub(crate) struct ToolUninstallSettings {
-    pub(crate) name: String,
+    /// The tool to uninstall, or `None` to uninstall all tools.
+    pub(crate) name: Option<String>,
 }
 
 impl ToolUninstallSettings {
     /// Resolve the [`ToolUninstallSettings`] from the CLI and filesystem configuration.
     #[allow(clippy::needless_pass_by_value)]
     pub(crate) fn resolve(args: ToolUninstallArgs, _filesystem: Option<FilesystemOptions>) -> Self {
-        let ToolUninstallArgs { name } = args;
+        let ToolUninstallArgs { name, all } = args;
 
-        Self { name }
+        Self {
+            name: if all { None } else { name },
+        }
     }
 }
 
@@ -354,6 +520,8 @@ impl ToolchainListSettings {
 pub(crate) struct ToolchainInstallSettings {
     pub(crate) targets: Vec<String>,
     pub(crate) force: bool,
+    pub(crate) verify: bool,
+    pub(crate) from_file: Option<PathBuf>,
 }
 
 impl ToolchainInstallSettings {
@@ -363,9 +531,41 @@ impl ToolchainInstallSettings {
         args: ToolchainInstallArgs,
         _filesystem: Option<FilesystemOptions>,
     ) -> Self {
-        let ToolchainInstallArgs { targets, force } = args;
+        let ToolchainInstallArgs {
+            targets,
+            force,
+            verify,
+            from_file,
+        } = args;
 
-        Self { targets, force }
+        Self {
+            targets,
+            force,
+            verify,
+            from_file,
+        }
+    }
+}
+
+/// The resolved settings to use for a `toolchain uninstall` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct ToolchainUninstallSettings {
+    pub(crate) targets: Vec<String>,
+    pub(crate) all: bool,
+    pub(crate) yes: bool,
+}
+
+impl ToolchainUninstallSettings {
+    /// Resolve the [`ToolchainUninstallSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(
+        args: ToolchainUninstallArgs,
+        _filesystem: Option<FilesystemOptions>,
+    ) -> Self {
+        let ToolchainUninstallArgs { targets, all, yes } = args;
+
+        Self { targets, all, yes }
     }
 }
 
@@ -386,6 +586,135 @@ impl ToolchainFindSettings {
     }
 }
 
+/// The resolved settings to use for a `toolchain show` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct ToolchainShowSettings {
+    pub(crate) python: Option<String>,
+    pub(crate) json: bool,
+}
+
+impl ToolchainShowSettings {
+    /// Resolve the [`ToolchainShowSettings`] from the CLI and workspace configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolchainShowArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolchainShowArgs { python, json } = args;
+
+        Self { python, json }
+    }
+}
+
+/// The resolved settings to use for a `workspace info` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct WorkspaceInfoSettings {
+    pub(crate) json: bool,
+}
+
+impl WorkspaceInfoSettings {
+    /// Resolve the [`WorkspaceInfoSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: WorkspaceInfoArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let WorkspaceInfoArgs { json } = args;
+
+        Self { json }
+    }
+}
+
+/// The resolved settings to use for a `workspace graph` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct WorkspaceGraphSettings {
+    pub(crate) output_format: GraphFormat,
+}
+
+impl WorkspaceGraphSettings {
+    /// Resolve the [`WorkspaceGraphSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(
+        args: WorkspaceGraphArgs,
+        _filesystem: Option<FilesystemOptions>,
+    ) -> Self {
+        let WorkspaceGraphArgs { output_format } = args;
+
+        Self { output_format }
+    }
+}
+
+/// The resolved settings to use for a `workspace publish` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct WorkspacePublishSettings {
+    pub(crate) dry_run: bool,
+}
+
+impl WorkspacePublishSettings {
+    /// Resolve the [`WorkspacePublishSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(
+        args: WorkspacePublishArgs,
+        _filesystem: Option<FilesystemOptions>,
+    ) -> Self {
+        let WorkspacePublishArgs { dry_run } = args;
+
+        Self { dry_run }
+    }
+}
+
+/// The resolved settings to use for a `workspace clean` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct WorkspaceCleanSettings {
+    pub(crate) keep_venv: bool,
+}
+
+impl WorkspaceCleanSettings {
+    /// Resolve the [`WorkspaceCleanSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(
+        args: WorkspaceCleanArgs,
+        _filesystem: Option<FilesystemOptions>,
+    ) -> Self {
+        let WorkspaceCleanArgs { keep_venv } = args;
+
+        Self { keep_venv }
+    }
+}
+
+/// The resolved settings to use for a `workspace member add` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct WorkspaceMemberAddSettings {
+    pub(crate) name: PackageName,
+    pub(crate) path: Option<PathBuf>,
+    pub(crate) python: Option<String>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl WorkspaceMemberAddSettings {
+    /// Resolve the [`WorkspaceMemberAddSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(
+        args: WorkspaceMemberAddArgs,
+        filesystem: Option<FilesystemOptions>,
+    ) -> Self {
+        let WorkspaceMemberAddArgs {
+            name,
+            path,
+            resolver,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        Self {
+            name,
+            path,
+            python,
+            refresh: Refresh::from(refresh),
+            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+        }
+    }
+}
+
 /// The resolved settings to use for a `sync` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
 #[derive(Debug, Clone)]
@@ -393,6 +722,9 @@ pub(crate) struct SyncSettings {
     pub(crate) extras: ExtrasSpecification,
     pub(crate) dev: bool,
     pub(crate) modifications: Modifications,
+    pub(crate) check: bool,
+    pub(crate) no_editable: bool,
+    pub(crate) package: Option<PackageName>,
     pub(crate) python: Option<String>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: InstallerSettings,
@@ -409,6 +741,9 @@ impl SyncSettings {
             dev,
             no_dev,
             no_clean,
+            check,
+            no_editable,
+            package,
             installer,
             build,
             refresh,
@@ -428,6 +763,9 @@ impl SyncSettings {
             ),
             dev: flag(dev, no_dev).unwrap_or(true),
             modifications,
+            check,
+            no_editable,
+            package,
             python,
             refresh: Refresh::from(refresh),
             settings: InstallerSettings::combine(installer_options(installer, build), filesystem),
@@ -439,6 +777,11 @@ impl SyncSettings {
 #[allow(clippy::struct_excessive_bools, dead_code)]
 #[derive(Debug, Clone)]
 pub(crate) struct LockSettings {
+    pub(crate) annotation_style: Option<AnnotationStyle>,
+    pub(crate) no_annotate: bool,
+    pub(crate) strip_extras: Option<bool>,
+    pub(crate) check: bool,
+    pub(crate) output_file: Option<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
@@ -452,10 +795,28 @@ impl LockSettings {
             resolver,
             build,
             refresh,
+            annotation_style,
+            no_annotate,
+            strip_extras,
+            no_strip_extras,
+            check,
+            output_file,
             python,
         } = args;
 
+        let (annotation_style, no_annotate) = match annotation_style {
+            Some(LockAnnotationStyle::Line) => (Some(AnnotationStyle::Line), no_annotate),
+            Some(LockAnnotationStyle::Split) => (Some(AnnotationStyle::Split), no_annotate),
+            Some(LockAnnotationStyle::None) => (None, true),
+            None => (None, no_annotate),
+        };
+
         Self {
+            annotation_style,
+            no_annotate,
+            strip_extras: flag(strip_extras, no_strip_extras),
+            check,
+            output_file,
             python,
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
@@ -471,10 +832,16 @@ pub(crate) struct AddSettings {
     pub(crate) dependency_type: DependencyType,
     pub(crate) editable: Option<bool>,
     pub(crate) extras: Vec<ExtraName>,
+    pub(crate) python_version: Option<String>,
     pub(crate) raw_sources: bool,
     pub(crate) rev: Option<String>,
     pub(crate) tag: Option<String>,
     pub(crate) branch: Option<String>,
+    pub(crate) index: Option<IndexUrl>,
+    pub(crate) no_build_isolation: Vec<PackageName>,
+    pub(crate) reuse_pinned: bool,
+    pub(crate) dry_run: bool,
+    pub(crate) format: DiffFormat,
     pub(crate) package: Option<PackageName>,
     pub(crate) python: Option<String>,
     pub(crate) refresh: Refresh,
@@ -488,13 +855,20 @@ impl AddSettings {
         let AddArgs {
             requirements,
             dev,
+            script,
             optional,
             editable,
             extra,
+            python_version,
             raw_sources,
             rev,
             tag,
             branch,
+            index,
+            no_build_isolation,
+            reuse_pinned,
+            dry_run,
+            format,
             installer,
             build,
             refresh,
@@ -511,6 +885,8 @@ impl AddSettings {
             DependencyType::Optional(group)
         } else if dev {
             DependencyType::Dev
+        } else if script {
+            DependencyType::DevScript
         } else {
             DependencyType::Production
         };
@@ -523,9 +899,15 @@ impl AddSettings {
             rev,
             tag,
             branch,
+            index,
+            no_build_isolation: no_build_isolation.unwrap_or_default(),
+            reuse_pinned,
+            dry_run,
+            format,
             package,
             python,
             extras: extra.unwrap_or_default(),
+            python_version,
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
                 resolver_installer_options(installer, build),
@@ -551,6 +933,7 @@ impl RemoveSettings {
     pub(crate) fn resolve(args: RemoveArgs, _filesystem: Option<FilesystemOptions>) -> Self {
         let RemoveArgs {
             dev,
+            script,
             optional,
             requirements,
             package,
@@ -561,6 +944,8 @@ impl RemoveSettings {
             DependencyType::Optional(group)
         } else if dev {
             DependencyType::Dev
+        } else if script {
+            DependencyType::DevScript
         } else {
             DependencyType::Production
         };
@@ -794,9 +1179,40 @@ pub(crate) struct PipInstallSettings {
     pub(crate) requirement: Vec<PathBuf>,
     pub(crate) editable: Vec<String>,
     pub(crate) constraint: Vec<PathBuf>,
+    pub(crate) constraint_package: Vec<String>,
+    pub(crate) constraint_group: Vec<String>,
     pub(crate) r#override: Vec<PathBuf>,
+    pub(crate) exclude: Vec<PackageName>,
+    pub(crate) strip_extras: bool,
+    pub(crate) marker: Option<MarkerTree>,
+    pub(crate) marker_env: Vec<(String, String)>,
     pub(crate) dry_run: bool,
+    pub(crate) format: DryRunFormat,
+    pub(crate) log: Option<PathBuf>,
+    pub(crate) generate_checksums: Option<PathBuf>,
+    pub(crate) check_build_dependencies: bool,
+    pub(crate) isolated_resolution: bool,
+    pub(crate) ignore_conflicts: bool,
+    pub(crate) no_warn_conflicts: bool,
+    pub(crate) report_diff: bool,
+    pub(crate) report_unchanged: bool,
+    pub(crate) report_skipped: bool,
+    pub(crate) skip_locked: bool,
+    pub(crate) keep_installed: bool,
+    pub(crate) force_reinstall_deps: bool,
+    pub(crate) prefer_binary: bool,
+    pub(crate) prefer_sdist: bool,
+    pub(crate) retries: u32,
+    pub(crate) retry_delay: Option<Duration>,
+    pub(crate) max_retries: Option<u32>,
+    pub(crate) retry_status_codes: Option<Vec<u16>>,
+    pub(crate) connect_timeout: Option<Duration>,
+    pub(crate) read_timeout: Option<Duration>,
+    pub(crate) socks_proxy: Option<Url>,
+    pub(crate) ssl_ca_bundle: Option<PathBuf>,
+    pub(crate) resolver_timeout: Option<Duration>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
+    pub(crate) constraint_groups: BTreeMap<String, Vec<Requirement>>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -809,7 +1225,13 @@ impl PipInstallSettings {
             requirement,
             editable,
             constraint,
+            constraint_package,
+            constraint_group,
             r#override,
+            exclude,
+            strip_extras,
+            marker,
+            marker_env,
             extra,
             all_extras,
             no_all_extras,
@@ -834,11 +1256,36 @@ impl PipInstallSettings {
             build,
             no_binary,
             only_binary,
+            prefer_binary,
+            prefer_sdist,
             python_version,
             python_platform,
+            platform,
             strict,
             no_strict,
             dry_run,
+            format,
+            log,
+            generate_checksums,
+            check_build_dependencies,
+            isolated_resolution,
+            ignore_conflicts,
+            no_warn_conflicts,
+            report_diff,
+            report_unchanged,
+            report_skipped,
+            skip_locked,
+            keep_installed,
+            force_reinstall_deps,
+            retries,
+            retry_delay,
+            max_retries,
+            retry_status_codes,
+            connect_timeout,
+            read_timeout,
+            socks_proxy,
+            ssl_ca_bundle,
+            resolver_timeout,
             compat_args: _,
         } = args;
 
@@ -856,6 +1303,26 @@ impl PipInstallSettings {
             Vec::new()
         };
 
+        let constraint_groups = if let Some(configuration) = &filesystem {
+            configuration
+                .constraint_groups
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(group, requirements)| {
+                    let requirements = requirements
+                        .into_iter()
+                        .map(|requirement| {
+                            Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
+                        })
+                        .collect();
+                    (group, requirements)
+                })
+                .collect()
+        } else {
+            BTreeMap::new()
+        };
+
         Self {
             package,
             requirement,
@@ -864,9 +1331,40 @@ impl PipInstallSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            constraint_package,
+            constraint_group,
             r#override,
+            exclude,
+            strip_extras,
+            marker,
+            marker_env,
             dry_run,
+            format,
+            log,
+            generate_checksums,
+            check_build_dependencies,
+            isolated_resolution,
+            ignore_conflicts,
+            no_warn_conflicts,
+            report_diff,
+            report_unchanged,
+            report_skipped,
+            skip_locked,
+            keep_installed,
+            force_reinstall_deps,
+            prefer_binary,
+            prefer_sdist,
+            retries,
+            retry_delay: retry_delay.map(Duration::from_millis),
+            max_retries,
+            retry_status_codes,
+            connect_timeout: connect_timeout.map(Duration::from_millis),
+            read_timeout: read_timeout.map(Duration::from_millis),
+            socks_proxy,
+            ssl_ca_bundle,
+            resolver_timeout: resolver_timeout.map(Duration::from_secs),
             overrides_from_workspace,
+            constraint_groups,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -885,7 +1383,7 @@ impl PipInstallSettings {
                     no_deps: flag(no_deps, deps),
                     legacy_setup_py: flag(legacy_setup_py, no_legacy_setup_py),
                     python_version,
-                    python_platform,
+                    python_platform: python_platform.or(platform),
                     require_hashes: flag(require_hashes, no_require_hashes),
                     concurrent_builds: env(env::CONCURRENT_BUILDS),
                     concurrent_downloads: env(env::CONCURRENT_DOWNLOADS),
@@ -904,6 +1402,7 @@ impl PipInstallSettings {
 pub(crate) struct PipUninstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirement: Vec<PathBuf>,
+    pub(crate) yes: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -921,12 +1420,14 @@ impl PipUninstallSettings {
             no_break_system_packages,
             target,
             prefix,
+            yes,
             compat_args: _,
         } = args;
 
         Self {
             package,
             requirement,
+            yes,
             settings: PipSettings::combine(
                 PipOptions {
                     python,
@@ -1029,6 +1530,7 @@ impl PipListSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct PipShowSettings {
     pub(crate) package: Vec<PackageName>,
+    pub(crate) json: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -1039,6 +1541,7 @@ impl PipShowSettings {
             package,
             strict,
             no_strict,
+            json,
             python,
             system,
             no_system,
@@ -1047,6 +1550,7 @@ impl PipShowSettings {
 
         Self {
             package,
+            json,
             settings: PipSettings::combine(
                 PipOptions {
                     python,
@@ -1142,6 +1646,8 @@ impl PipCheckSettings {
 pub(crate) struct VenvSettings {
     pub(crate) seed: bool,
     pub(crate) allow_existing: bool,
+    pub(crate) relocatable: bool,
+    pub(crate) python_path: bool,
     pub(crate) name: PathBuf,
     pub(crate) prompt: Option<String>,
     pub(crate) system_site_packages: bool,
@@ -1157,6 +1663,8 @@ impl VenvSettings {
             no_system,
             seed,
             allow_existing,
+            relocatable,
+            python_path,
             name,
             prompt,
             system_site_packages,
@@ -1171,6 +1679,8 @@ impl VenvSettings {
         Self {
             seed,
             allow_existing,
+            relocatable,
+            python_path,
             name,
             prompt,
             system_site_packages,
@@ -355,6 +355,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv init` command with options shared across scenarios.
+    pub fn init(&self) -> Command {
+        let mut command = Command::new(get_bin());
+        command.arg("init");
+        self.add_shared_args(&mut command);
+        command
+    }
+
     /// Create a `uv sync` command with options shared across scenarios.
     pub fn sync(&self) -> Command {
         let mut command = Command::new(get_bin());
@@ -371,6 +379,46 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv workspace info` command with options shared across scenarios.
+    pub fn workspace_info(&self) -> Command {
+        let mut command = Command::new(get_bin());
+        command.arg("workspace").arg("info");
+        self.add_shared_args(&mut command);
+        command
+    }
+
+    /// Create a `uv workspace graph` command with options shared across scenarios.
+    pub fn workspace_graph(&self) -> Command {
+        let mut command = Command::new(get_bin());
+        command.arg("workspace").arg("graph");
+        self.add_shared_args(&mut command);
+        command
+    }
+
+    /// Create a `uv workspace publish` command with options shared across scenarios.
+    pub fn workspace_publish(&self) -> Command {
+        let mut command = Command::new(get_bin());
+        command.arg("workspace").arg("publish");
+        self.add_shared_args(&mut command);
+        command
+    }
+
+    /// Create a `uv workspace clean` command with options shared across scenarios.
+    pub fn workspace_clean(&self) -> Command {
+        let mut command = Command::new(get_bin());
+        command.arg("workspace").arg("clean");
+        self.add_shared_args(&mut command);
+        command
+    }
+
+    /// Create a `uv workspace member add` command with options shared across scenarios.
+    pub fn workspace_member_add(&self) -> Command {
+        let mut command = Command::new(get_bin());
+        command.arg("workspace").arg("member").arg("add");
+        self.add_shared_args(&mut command);
+        command
+    }
+
     /// Create a `uv toolchain find` command with options shared across scenarios.
     pub fn toolchain_find(&self) -> Command {
         let mut command = Command::new(get_bin());
@@ -384,6 +432,19 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv toolchain show` command with options shared across scenarios.
+    pub fn toolchain_show(&self) -> Command {
+        let mut command = Command::new(get_bin());
+        command
+            .arg("toolchain")
+            .arg("show")
+            .env("UV_PREVIEW", "1")
+            .env("UV_TOOLCHAIN_DIR", "")
+            .current_dir(&self.temp_dir);
+        self.add_shared_args(&mut command);
+        command
+    }
+
     /// Create a `uv toolchain dir` command with options shared across scenarios.
     pub fn toolchain_dir(&self) -> Command {
         let mut command = Command::new(get_bin());
@@ -392,6 +453,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv toolchain install` command with options shared across scenarios.
+    pub fn toolchain_install(&self) -> Command {
+        let mut command = Command::new(get_bin());
+        command.arg("toolchain").arg("install");
+        self.add_shared_args(&mut command);
+        command
+    }
+
+    /// Create a `uv toolchain uninstall` command with options shared across scenarios.
+    pub fn toolchain_uninstall(&self) -> Command {
+        let mut command = Command::new(get_bin());
+        command.arg("toolchain").arg("uninstall");
+        self.add_shared_args(&mut command);
+        command
+    }
+
     /// Create a `uv run` command with options shared across scenarios.
     pub fn run(&self) -> Command {
         let mut command = Command::new(get_bin());
@@ -428,6 +505,24 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv tool upgrade` command with options shared across scenarios.
+    pub fn tool_upgrade(&self) -> std::process::Command {
+        let mut command = std::process::Command::new(get_bin());
+        command.arg("tool").arg("upgrade");
+        self.add_shared_args(&mut command);
+        command.arg("--exclude-newer").arg(EXCLUDE_NEWER);
+        command
+    }
+
+    /// Create a `uv tool reinstall` command with options shared across scenarios.
+    pub fn tool_reinstall(&self) -> std::process::Command {
+        let mut command = std::process::Command::new(get_bin());
+        command.arg("tool").arg("reinstall");
+        self.add_shared_args(&mut command);
+        command.arg("--exclude-newer").arg(EXCLUDE_NEWER);
+        command
+    }
+
     /// Create a `uv tool list` command with options shared across scenarios.
     pub fn tool_list(&self) -> std::process::Command {
         let mut command = std::process::Command::new(get_bin());
@@ -436,6 +531,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv tool inspect` command with options shared across scenarios.
+    pub fn tool_inspect(&self) -> std::process::Command {
+        let mut command = std::process::Command::new(get_bin());
+        command.arg("tool").arg("inspect");
+        self.add_shared_args(&mut command);
+        command
+    }
+
     /// Create a `uv tool dir` command with options shared across scenarios.
     pub fn tool_dir(&self) -> Command {
         let mut command = Command::new(get_bin());
@@ -1,9 +1,11 @@
 #![cfg(all(feature = "python", feature = "pypi"))]
 
 use anyhow::Result;
+use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
 use indoc::indoc;
 use insta::assert_snapshot;
+use predicates::prelude::predicate;
 
 use common::{uv_snapshot, TestContext};
 
@@ -283,6 +285,259 @@ fn add_git() -> Result<()> {
     Ok(())
 }
 
+/// Reject a Git reference for a requirement that is neither a Git URL nor a GitHub shorthand.
+#[test]
+fn add_git_reference_without_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add(&["anyio"]).arg("--branch=main").arg("--preview"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `anyio` is not a Git URL or GitHub shorthand (e.g., `owner/repo`); a Git reference (`--rev`, `--tag`, or `--branch`) can only be used with Git requirements
+    "###);
+
+    Ok(())
+}
+
+/// Add a GitHub shorthand requirement at a branch, which is expanded to a Git URL.
+#[test]
+fn add_git_shorthand_branch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add(&["astral-test/uv-public-pypackage"]).arg("--branch=test-branch").arg("--preview"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979?branch=test-branch#0dacfd662c64cb4ceb16e6cf65a157a8b715b979)
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "uv-public-pypackage",
+        ]
+
+        [tool.uv.sources]
+        uv-public-pypackage = { git = "https://github.com/astral-test/uv-public-pypackage", branch = "test-branch" }
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Add a GitHub shorthand requirement with a tag given as a bare version, which is matched against
+/// the tags of the repository with and without a `v` prefix.
+#[test]
+fn add_git_shorthand_version_tag() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    // Neither `9.9.9` nor `v9.9.9` exist.
+    uv_snapshot!(context.filters(), context.add(&["astral-test/uv-public-pypackage"]).arg("--tag=9.9.9").arg("--preview"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Neither `9.9.9` nor `v9.9.9` is a tag of `https://github.com/astral-test/uv-public-pypackage`
+    "###);
+
+    uv_snapshot!(context.filters(), context.add(&["astral-test/uv-public-pypackage"]).arg("--tag=0.0.1").arg("--preview"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979?tag=0.0.1#0dacfd662c64cb4ceb16e6cf65a157a8b715b979)
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "uv-public-pypackage",
+        ]
+
+        [tool.uv.sources]
+        uv-public-pypackage = { git = "https://github.com/astral-test/uv-public-pypackage", tag = "0.0.1" }
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Add a GitHub shorthand requirement with a tag given as a bare version, which only exists in the
+/// repository with a `v` prefix.
+#[test]
+fn add_git_shorthand_version_tag_prefix() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    // `2.32.3` is tagged as `v2.32.3`.
+    uv_snapshot!(context.filters(), context.add(&["psf/requests"]).arg("--tag=2.32.3").arg("--preview"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Prepared 6 packages in [TIME]
+    Installed 6 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + requests==2.32.3 (from git+https://github.com/psf/requests@0e322af87745eff34caffe4df68456ebc20d9068?tag=v2.32.3#0e322af87745eff34caffe4df68456ebc20d9068)
+     + urllib3==2.2.1
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "requests",
+        ]
+
+        [tool.uv.sources]
+        requests = { git = "https://github.com/psf/requests", tag = "v2.32.3" }
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Add an explicit Git URL requirement with a tag given as a bare version, which only exists in
+/// the repository with a `v` prefix.
+#[test]
+fn add_git_url_version_tag_prefix() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    // `2.32.3` is tagged as `v2.32.3`.
+    uv_snapshot!(context.filters(), context.add(&["requests @ git+https://github.com/psf/requests"]).arg("--tag=2.32.3").arg("--preview"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Prepared 6 packages in [TIME]
+    Installed 6 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + requests==2.32.3 (from git+https://github.com/psf/requests@0e322af87745eff34caffe4df68456ebc20d9068?tag=v2.32.3#0e322af87745eff34caffe4df68456ebc20d9068)
+     + urllib3==2.2.1
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "requests",
+        ]
+
+        [tool.uv.sources]
+        requests = { git = "https://github.com/psf/requests", tag = "v2.32.3" }
+        "###
+        );
+    });
+
+    Ok(())
+}
+
 /// Add a Git requirement using the `--raw-sources` API.
 #[test]
 fn add_git_raw() -> Result<()> {
@@ -718,9 +973,9 @@ fn add_remove_dev() -> Result<()> {
     Ok(())
 }
 
-/// Add and remove an optional dependency.
+/// Add and remove a development script.
 #[test]
-fn add_remove_optional() -> Result<()> {
+fn add_remove_dev_script() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -732,17 +987,21 @@ fn add_remove_optional() -> Result<()> {
         dependencies = []
     "#})?;
 
-    uv_snapshot!(context.filters(), context.add(&["anyio==3.7.0"]).arg("--optional=io"), @r###"
+    // Development scripts are locked and installed alongside the development dependencies.
+    uv_snapshot!(context.filters(), context.add(&["anyio==3.7.0"]).arg("--script"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     warning: `uv add` is experimental and may change without warning.
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
      + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
     "###);
 
     let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
@@ -758,24 +1017,125 @@ fn add_remove_optional() -> Result<()> {
         requires-python = ">=3.12"
         dependencies = []
 
-        [project.optional-dependencies]
-        io = [
+        [tool.uv]
+        dev-scripts = [
             "anyio==3.7.0",
         ]
         "###
         );
     });
 
-    // `uv add` implies a full lock and sync, including development dependencies.
-    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    // This should fail without --script.
+    uv_snapshot!(context.filters(), context.remove(&["anyio"]).arg("--dev"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
 
-    insta::with_settings!({
-        filters => context.filters(),
-    }, {
-        assert_snapshot!(
-            lock, @r###"
-        version = 1
-        requires-python = ">=3.12"
+    ----- stderr -----
+    warning: `uv remove` is experimental and may change without warning.
+    warning: `anyio` is a development script; try calling `uv remove --script`
+    error: The dependency `anyio` could not be found in `dev-dependencies`
+    "###);
+
+    // Remove the script.
+    uv_snapshot!(context.filters(), context.remove(&["anyio"]).arg("--script"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv remove` is experimental and may change without warning.
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 4 packages in [TIME]
+    Installed 1 package in [TIME]
+     - anyio==3.7.0
+     - idna==3.6
+     - project==0.1.0 (from file://[TEMP_DIR]/)
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     - sniffio==1.3.1
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        dev-scripts = []
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Add and remove an optional dependency.
+#[test]
+fn add_remove_optional() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add(&["anyio==3.7.0"]).arg("--optional=io"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning.
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        io = [
+            "anyio==3.7.0",
+        ]
+        "###
+        );
+    });
+
+    // `uv add` implies a full lock and sync, including development dependencies.
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
 
         [[distribution]]
         name = "project"
@@ -1784,3 +2144,466 @@ fn remove_registry() -> Result<()> {
 
     Ok(())
 }
+
+/// Add a requirement that's built without build isolation.
+#[test]
+fn add_no_build_isolation() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    // Install the build dependencies of `anyio` into the project environment, since `anyio` will
+    // be built there.
+    context
+        .pip_install()
+        .arg("setuptools")
+        .arg("setuptools-scm")
+        .arg("wheel")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.add(&["anyio @ https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz"])
+        .arg("--no-build-isolation")
+        .arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning.
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==4.3.0 (from https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz)
+     + idna==3.6
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio",
+        ]
+
+        [tool.uv]
+        no-build-isolation-package = ["anyio"]
+
+        [tool.uv.sources]
+        anyio = { url = "https://files.pythonhosted.org/packages/db/4d/3970183622f0330d3c23d9b8a5f52e365e50381fd484d08e3285104333d3/anyio-4.3.0.tar.gz" }
+        "###
+        );
+    });
+
+    // Subsequent commands should continue to build `anyio` in the project environment, and so
+    // fail once its build dependencies are removed from the environment.
+    context.pip_uninstall().arg("setuptools").assert().success();
+
+    context
+        .lock()
+        .arg("--refresh")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No module named 'setuptools'"));
+
+    Ok(())
+}
+
+/// `:all:` and `:none:` can't be recorded in `tool.uv.no-build-isolation-package`, so `uv add`
+/// rejects them.
+#[test]
+fn add_no_build_isolation_all() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add(&["iniconfig"])
+        .arg("--no-build-isolation")
+        .arg(":all:"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value ':all:' for '--no-build-isolation <NO_BUILD_ISOLATION>': `:all:` is not supported by `uv add`; provide the packages to build without isolation by name
+
+    For more information, try '--help'.
+    "###);
+
+    uv_snapshot!(context.filters(), context.add(&["iniconfig"])
+        .arg("--no-build-isolation")
+        .arg(":none:"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value ':none:' for '--no-build-isolation <NO_BUILD_ISOLATION>': `:none:` is not supported by `uv add`; provide the packages to build without isolation by name
+
+    For more information, try '--help'.
+    "###);
+
+    Ok(())
+}
+
+/// Add a requirement while keeping the existing locked versions fixed.
+#[test]
+fn add_reuse_pinned() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+    "#})?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.add(&["iniconfig"]).arg("--reuse-pinned"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning.
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio==3.7.0",
+            "iniconfig",
+        ]
+        "###
+        );
+    });
+
+    // `iniconfig<2` is incompatible with the locked `iniconfig==2.0.0`, so `--reuse-pinned` should
+    // fail, rather than downgrading `iniconfig`.
+    context
+        .add(&["iniconfig<2"])
+        .arg("--reuse-pinned")
+        .assert()
+        .failure();
+
+    // Without `--reuse-pinned`, `iniconfig` should be downgraded.
+    uv_snapshot!(context.filters(), context.add(&["iniconfig<2"]), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning.
+    Resolved 5 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Uninstalled 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     - iniconfig==2.0.0
+     + iniconfig==1.1.1
+     - project==0.1.0 (from file://[TEMP_DIR]/)
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    Ok(())
+}
+
+/// Add a requirement with a `python_version` marker via `--python-version`.
+#[test]
+fn add_python_version_marker() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add(&["iniconfig ; implementation_name == 'cpython'"]).arg("--python-version").arg(">=3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning.
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig ; implementation_name == 'cpython' and python_version >= '3.12'",
+        ]
+        "###
+        );
+    });
+
+    // A patch version can't be expressed as a `python_version` marker.
+    uv_snapshot!(context.filters(), context.add(&["anyio"]).arg("--python-version").arg(">=3.12.1"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning.
+    error: `>=3.12.1` can't be expressed as a `python_version` marker; use a major and minor version (e.g., `>=3.12`), or write the marker on the requirement directly
+    "###);
+
+    Ok(())
+}
+
+/// Preview the changes to the `pyproject.toml` with `--dry-run`, without writing them.
+#[test]
+fn add_dry_run() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add(&["anyio==3.7.0"]).arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    --- pyproject.toml
+    +++ pyproject.toml
+    @@ -2,4 +2,6 @@
+     name = "project"
+     version = "0.1.0"
+     requires-python = ">=3.12"
+    -dependencies = []
+    +dependencies = [
+    +    "anyio==3.7.0",
+    +]
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning.
+    Resolved 4 packages in [TIME]
+    "###);
+
+    uv_snapshot!(context.filters(), context.add(&["anyio==3.7.0"]).arg("--dry-run").arg("--format").arg("full"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = [
+        "anyio==3.7.0",
+    ]
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning.
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // Neither the `pyproject.toml` nor the lockfile should be written.
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+    assert_snapshot!(
+        pyproject_toml, @r###"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = []
+    "###
+    );
+    assert!(!context.temp_dir.join("uv.lock").exists());
+
+    Ok(())
+}
+
+/// Pin an added package to an index with `uv add --index`.
+#[test]
+fn add_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add(&["iniconfig==2.0.0"])
+        .arg("--index")
+        .arg("https://pypi.org/simple")
+        .arg("--dry-run")
+        .arg("--format")
+        .arg("full"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = [
+        "iniconfig==2.0.0",
+    ]
+
+    [tool.uv]
+    extra-index-url = ["https://pypi.org/simple"]
+
+    [tool.uv.sources]
+    iniconfig = { index = "https://pypi.org/simple" }
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning.
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // Add the package, rather than printing the changes.
+    uv_snapshot!(context.filters(), context.add(&["iniconfig==2.0.0"])
+        .arg("--index")
+        .arg("https://pypi.org/simple"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning.
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig==2.0.0",
+        ]
+
+        [tool.uv]
+        extra-index-url = ["https://pypi.org/simple"]
+
+        [tool.uv.sources]
+        iniconfig = { index = "https://pypi.org/simple" }
+        "###
+        );
+    });
+
+    // Adding a second package from the same index shouldn't duplicate the index.
+    uv_snapshot!(context.filters(), context.add(&["idna==3.6"])
+        .arg("--index")
+        .arg("https://pypi.org/simple"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv add` is experimental and may change without warning.
+    Resolved 3 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 2 packages in [TIME]
+     + idna==3.6
+     - project==0.1.0 (from file://[TEMP_DIR]/)
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig==2.0.0",
+            "idna==3.6",
+        ]
+
+        [tool.uv]
+        extra-index-url = ["https://pypi.org/simple"]
+
+        [tool.uv.sources]
+        iniconfig = { index = "https://pypi.org/simple" }
+        idna = { index = "https://pypi.org/simple" }
+        "###
+        );
+    });
+
+    Ok(())
+}
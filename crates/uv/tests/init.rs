@@ -0,0 +1,337 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use insta::assert_snapshot;
+use predicates::prelude::predicate;
+
+use common::{uv_snapshot, TestContext};
+
+mod common;
+
+/// Initialize a new project, including a `.gitignore`.
+#[test]
+fn init() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning.
+    Initialized project `foo` at `foo`
+    "###);
+
+    let project = context.temp_dir.child("foo");
+    project
+        .child("src")
+        .child("foo")
+        .child("__init__.py")
+        .assert(predicate::path::is_file());
+    project
+        .child("README.md")
+        .assert(predicate::path::missing());
+
+    let pyproject = fs_err::read_to_string(project.child("pyproject.toml"))?;
+    assert_snapshot!(pyproject, @r###"
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    dependencies = []
+    "###
+    );
+
+    let gitignore = fs_err::read_to_string(project.child(".gitignore"))?;
+    assert_snapshot!(gitignore, @r###"
+    # Python-generated files
+    __pycache__/
+    *.pyc
+    build/
+    dist/
+    wheels/
+    *.egg-info
+
+    # Virtual environments
+    .venv/
+    "###
+    );
+
+    Ok(())
+}
+
+/// Initialize a new project with the package at the project root, rather than under `src/`.
+#[test]
+fn init_flat_layout() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo").arg("--flat-layout"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning.
+    Initialized project `foo` at `foo`
+    "###);
+
+    let project = context.temp_dir.child("foo");
+    project
+        .child("foo")
+        .child("__init__.py")
+        .assert(predicate::path::is_file());
+    project.child("src").assert(predicate::path::missing());
+}
+
+/// Initialize a new project without a `.gitignore`.
+#[test]
+fn init_no_gitignore() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo").arg("--no-gitignore"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning.
+    Initialized project `foo` at `foo`
+    "###);
+
+    context
+        .temp_dir
+        .child("foo")
+        .child(".gitignore")
+        .assert(predicate::path::missing());
+}
+
+/// Do not overwrite an existing `.gitignore`.
+#[test]
+fn init_existing_gitignore() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let gitignore = context.temp_dir.child("foo").child(".gitignore");
+    gitignore.write_str(indoc! {r"
+        *.log
+    "})?;
+
+    uv_snapshot!(context.filters(), context.init().arg("foo"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning.
+    Initialized project `foo` at `foo`
+    "###);
+
+    assert_snapshot!(fs_err::read_to_string(gitignore)?, @r###"
+    *.log
+    "###);
+
+    Ok(())
+}
+
+/// Refuse to initialize a project in a directory that already contains one.
+#[test]
+fn init_existing_project() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("foo")
+        .child("pyproject.toml")
+        .touch()?;
+
+    uv_snapshot!(context.filters(), context.init().arg("foo"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning.
+    error: Project is already initialized in `foo`
+    "###);
+
+    Ok(())
+}
+
+/// Initialize a new project with a license.
+#[test]
+fn init_license() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo").arg("--license").arg("mit"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning.
+    Initialized project `foo` at `foo`
+    "###);
+
+    let project = context.temp_dir.child("foo");
+
+    let pyproject = fs_err::read_to_string(project.child("pyproject.toml"))?;
+    assert_snapshot!(pyproject, @r###"
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    license = { text = "MIT" }
+    dependencies = []
+    "###
+    );
+
+    let license = fs_err::read_to_string(project.child("LICENSE"))?;
+    insta::with_settings!({
+        filters => vec![(r"Copyright \(c\) \d{4}", "Copyright (c) [YEAR]")],
+    }, {
+        assert_snapshot!(license.lines().take(3).collect::<Vec<_>>().join("\n"), @r###"
+        MIT License
+
+        Copyright (c) [YEAR] <author>
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Initialize a new project with the deprecated `GPL-3.0` identifier, which is written as
+/// `GPL-3.0-only`.
+#[test]
+fn init_license_gpl3() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo").arg("--license").arg("GPL-3.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning.
+    Initialized project `foo` at `foo`
+    "###);
+
+    let pyproject = fs_err::read_to_string(context.temp_dir.child("foo").child("pyproject.toml"))?;
+    assert_snapshot!(pyproject, @r###"
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    license = { text = "GPL-3.0-only" }
+    dependencies = []
+    "###
+    );
+
+    Ok(())
+}
+
+/// Initialize a new project that's marked as private.
+#[test]
+fn init_private() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo").arg("--private"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning.
+    Initialized project `foo` at `foo`
+    warning: `foo` is marked as private (`Private :: Do Not Upload`), so `uv workspace publish` will skip it
+    "###);
+
+    let pyproject = fs_err::read_to_string(context.temp_dir.child("foo").child("pyproject.toml"))?;
+    assert_snapshot!(pyproject, @r###"
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    classifiers = ["Private :: Do Not Upload"]
+    dependencies = []
+    "###
+    );
+
+    Ok(())
+}
+
+/// Initialize a virtual workspace root, without a `[project]` table or source files.
+#[test]
+fn init_workspace_root() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo").arg("--workspace-root"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning.
+    Initialized workspace root at `foo`
+    "###);
+
+    let project = context.temp_dir.child("foo");
+    project.child("src").assert(predicate::path::missing());
+    project
+        .child("README.md")
+        .assert(predicate::path::missing());
+    project
+        .child(".gitignore")
+        .assert(predicate::path::is_file());
+
+    let pyproject = fs_err::read_to_string(project.child("pyproject.toml"))?;
+    assert_snapshot!(pyproject, @r###"
+    [tool.uv.workspace]
+    members = []
+    "###
+    );
+
+    Ok(())
+}
+
+/// Initialize a new project with initial dependencies, which are added, locked, and synced.
+#[test]
+fn init_dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init().arg("foo").arg("--dependency").arg("iniconfig==2.0.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv init` is experimental and may change without warning.
+    Initialized project `foo` at `foo`
+    warning: `uv add` is experimental and may change without warning.
+    Using Python 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtualenv at: foo/.venv
+    warning: No `requires-python` field found in the workspace. Defaulting to `>=3.12`.
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + foo==0.1.0 (from file://[TEMP_DIR]/foo)
+     + iniconfig==2.0.0
+    "###);
+
+    let pyproject = fs_err::read_to_string(context.temp_dir.child("foo").child("pyproject.toml"))?;
+    assert_snapshot!(pyproject, @r###"
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    dependencies = [
+        "iniconfig==2.0.0",
+    ]
+    "###
+    );
+
+    context
+        .temp_dir
+        .child("foo")
+        .child("uv.lock")
+        .assert(predicate::path::is_file());
+
+    Ok(())
+}
@@ -1,14 +1,64 @@
 #![cfg(all(feature = "python", feature = "pypi"))]
 
 use anyhow::Result;
+use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
 use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
+use predicates::prelude::predicate;
 
 use common::{uv_snapshot, TestContext};
 
 mod common;
 
+/// Lock a requirement from PyPI as of a historical date, with `--exclude-newer`.
+#[test]
+fn lock_exclude_newer() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .env_remove("UV_EXCLUDE_NEWER")
+        .arg("--exclude-newer")
+        .arg("2023-01-01"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // Only versions published before the given date should be locked.
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning.
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==3.6.2
+     + idna==3.4
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.0
+    "###);
+
+    Ok(())
+}
+
 /// Lock a requirement from PyPI.
 #[test]
 fn lock_wheel_registry() -> Result<()> {
@@ -435,6 +485,57 @@ fn lock_sdist_url() -> Result<()> {
     Ok(())
 }
 
+/// Sync a project with a comma-separated list of extras, warning on any unknown extras.
+#[test]
+fn sync_extras_comma_separated() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [project.optional-dependencies]
+        test = ["iniconfig"]
+        types = ["typing-extensions"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 6 packages in [TIME]
+    "###);
+
+    uv_snapshot!(context.filters(), context.sync().arg("--extras").arg("test,types,missing"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning.
+    warning: The extra `missing` is not defined by the project; ignoring it
+    Prepared 6 packages in [TIME]
+    Installed 6 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+     + typing-extensions==4.10.0
+    "###);
+
+    Ok(())
+}
+
 /// Lock a project with an extra. When resolving, all extras should be included.
 #[test]
 fn lock_project_extra() -> Result<()> {
@@ -1609,6 +1710,64 @@ fn lock_git_sha() -> Result<()> {
     Ok(())
 }
 
+/// Upgrade a single package with `--upgrade-package`, leaving the other locked versions as-is.
+#[test]
+fn lock_upgrade_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig<2", "sniffio<1.3"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Loosen both requirements.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "sniffio"]
+        "#,
+    )?;
+
+    // Upgrade `iniconfig`, but not `sniffio`.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-package").arg("iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 3 packages in [TIME]
+    "###);
+
+    // `iniconfig` should be upgraded, while `sniffio` should remain at its locked version.
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning.
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.2.0
+    "###);
+
+    Ok(())
+}
+
 /// Lock a requirement from PyPI, respecting the `Requires-Python` metadata.
 #[test]
 fn lock_requires_python() -> Result<()> {
@@ -2833,3 +2992,646 @@ fn lock_cycles() -> Result<()> {
 
     Ok(())
 }
+
+/// Annotate the lockfile with the dependents of each distribution.
+#[test]
+fn lock_annotation_style() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [project.optional-dependencies]
+        idna = ["idna"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--annotation-style").arg("line"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 4 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+        annotation-style = "line"
+
+        # via project
+        [[distribution]]
+        name = "anyio"
+        version = "3.7.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/c6/b3/fefbf7e78ab3b805dec67d698dc18dd505af7a18a8dd08868c9b4fa736b5/anyio-3.7.0.tar.gz", hash = "sha256:275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce", size = 142737 }
+        dependencies = [
+            { name = "idna" },
+            { name = "sniffio" },
+        ]
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/68/fe/7ce1926952c8a403b35029e194555558514b365ad77d75125f521a2bec62/anyio-3.7.0-py3-none-any.whl", hash = "sha256:eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0", size = 80873 },
+        ]
+
+        # via anyio, project[idna]
+        [[distribution]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567 },
+        ]
+
+        [[distribution]]
+        name = "project"
+        version = "0.1.0"
+        source = { editable = "." }
+        dependencies = [
+            { name = "anyio" },
+        ]
+
+        [distribution.optional-dependencies]
+        idna = [
+            { name = "idna" },
+        ]
+
+        # via anyio
+        [[distribution]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235 },
+        ]
+        "###
+        );
+    });
+
+    // Re-lock with one annotation per line.
+    uv_snapshot!(context.filters(), context.lock().arg("--annotation-style").arg("split"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 4 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock.lines().filter(|line| line.starts_with('#')).collect::<Vec<_>>().join("\n"), @r###"
+        # via
+        #   project
+        # via
+        #   anyio
+        #   project[idna]
+        # via
+        #   anyio
+        "###
+        );
+    });
+
+    // Re-locking without `--annotation-style` should retain the recorded style.
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 4 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock.lines().filter(|line| line.starts_with('#') || line.starts_with("annotation-style")).collect::<Vec<_>>().join("\n"), @r###"
+        annotation-style = "split"
+        # via
+        #   project
+        # via
+        #   anyio
+        #   project[idna]
+        # via
+        #   anyio
+        "###
+        );
+    });
+
+    // Removing the annotations should also remove the recorded style.
+    uv_snapshot!(context.filters(), context.lock().arg("--no-annotate"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 4 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(!lock.contains("annotation-style"));
+    assert!(!lock.lines().any(|line| line.starts_with('#')));
+
+    // `--annotation-style none` is equivalent to `--no-annotate`.
+    context
+        .lock()
+        .arg("--annotation-style")
+        .arg("line")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.lock().arg("--annotation-style").arg("none"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 4 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(!lock.contains("annotation-style"));
+    assert!(!lock.lines().any(|line| line.starts_with('#')));
+
+    Ok(())
+}
+
+/// Lock a project with `--strip-extras`, collapsing its optional dependencies into its base
+/// dependencies.
+#[test]
+fn lock_strip_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [project.optional-dependencies]
+        idna = ["idna==3.6"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--strip-extras"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 3 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+        strip-extras = true
+
+        [[distribution]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567 },
+        ]
+
+        [[distribution]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[distribution]]
+        name = "project"
+        version = "0.1.0"
+        source = { editable = "." }
+        dependencies = [
+            { name = "idna" },
+            { name = "iniconfig" },
+        ]
+        "###
+        );
+    });
+
+    // Re-locking without `--strip-extras` should continue to strip extras.
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 3 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(lock.contains("strip-extras = true"));
+    assert!(!lock.contains("[distribution.optional-dependencies]"));
+
+    // `--no-strip-extras` should restore the optional dependencies.
+    uv_snapshot!(context.filters(), context.lock().arg("--no-strip-extras"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 3 packages in [TIME]
+    "###);
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+    assert!(!lock.contains("strip-extras"));
+    assert!(lock.contains("[distribution.optional-dependencies]"));
+
+    Ok(())
+}
+
+/// Lock a project with `--output-file`, writing the lockfile to a custom location.
+#[test]
+fn lock_output_file() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.temp_dir.child("locks").create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--output-file").arg("locks/prod.lock"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // The lockfile is written to the requested path, rather than `uv.lock`.
+    context
+        .temp_dir
+        .child("locks/prod.lock")
+        .assert(predicate::str::contains(r#"name = "iniconfig""#));
+    context
+        .temp_dir
+        .child("uv.lock")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// Check whether the lockfile is up-to-date with `uv lock --check`.
+#[test]
+fn lock_check() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    // Without a lockfile, the check fails.
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 2 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--check` was provided
+    "###);
+
+    context
+        .temp_dir
+        .child("uv.lock")
+        .assert(predicate::path::missing());
+
+    context.lock().assert().success();
+
+    // The lockfile is up-to-date.
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // Add a dependency, such that the lockfile is stale.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0", "sniffio==1.3.1"]
+        "#,
+    )?;
+
+    let lock = fs_err::read_to_string(context.temp_dir.join("uv.lock"))?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 3 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--check` was provided
+    "###);
+
+    // The lockfile is left unchanged.
+    assert_eq!(
+        fs_err::read_to_string(context.temp_dir.join("uv.lock"))?,
+        lock
+    );
+
+    Ok(())
+}
+
+/// Check whether the environment is in sync with the lockfile with `uv sync --check`.
+#[test]
+fn sync_check() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+    context.sync().assert().success();
+
+    // The environment is in sync with the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--check"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning.
+    Audited 4 packages in [TIME]
+    Would make no changes
+    "###);
+
+    // Remove a package from the environment.
+    context.pip_uninstall().arg("sniffio").assert().success();
+
+    // The missing package is reported, but not installed.
+    uv_snapshot!(context.filters(), context.sync().arg("--check"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning.
+    Would install 1 package
+     + sniffio==1.3.1
+    "###);
+
+    // Since nothing was installed, the check continues to fail.
+    uv_snapshot!(context.filters(), context.sync().arg("--check"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning.
+    Would install 1 package
+     + sniffio==1.3.1
+    "###);
+
+    Ok(())
+}
+
+/// `uv sync --check` never creates or replaces the project environment.
+#[test]
+fn sync_check_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+    context.sync().assert().success();
+
+    // The existing environment doesn't satisfy the requested interpreter, but it isn't replaced.
+    uv_snapshot!(context.filters(), context.sync().arg("--check").arg("--python").arg("3.11"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning.
+    Would replace virtual environment at: .venv
+    "###);
+
+    context
+        .temp_dir
+        .child(".venv")
+        .child("pyvenv.cfg")
+        .assert(predicate::path::is_file());
+
+    // Without an environment, one isn't created.
+    fs_err::remove_dir_all(&context.venv)?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--check"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning.
+    Would create virtualenv at: .venv
+    "###);
+
+    context
+        .temp_dir
+        .child(".venv")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// Sync the project as a regular, non-editable package with `uv sync --no-editable`.
+#[test]
+fn sync_no_editable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.sync().arg("--no-editable"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning.
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    // The project should not be marked as editable.
+    let direct_url = fs_err::read_to_string(
+        context
+            .site_packages()
+            .join("project-0.1.0.dist-info")
+            .join("direct_url.json"),
+    )?;
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(direct_url, @r###"{"url":"file://[TEMP_DIR]/","dir_info":{}}"###);
+    });
+
+    Ok(())
+}
+
+/// Sync a single member of a workspace with `--package`, omitting the other members.
+#[test]
+fn sync_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [tool.uv.workspace]
+        members = ["child1", "child2"]
+        "#,
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("child1/pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "child1"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("child2/pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "child2"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Only `child2` and its dependencies are installed.
+    uv_snapshot!(context.filters(), context.sync().arg("--package").arg("child2"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning.
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + child2==0.1.0 (from file://[TEMP_DIR]/child2)
+     + iniconfig==2.0.0
+    "###);
+
+    // Requesting a package outside the workspace is an error.
+    uv_snapshot!(context.filters(), context.sync().arg("--package").arg("child3"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv sync` is experimental and may change without warning.
+    error: Package `child3` not found in workspace
+    "###);
+
+    Ok(())
+}
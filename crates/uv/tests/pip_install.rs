@@ -1,6 +1,10 @@
 #![cfg(all(feature = "python", feature = "pypi"))]
 
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
 use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 use anyhow::Result;
 use assert_cmd::prelude::*;
@@ -8,6 +12,7 @@ use assert_fs::prelude::*;
 use base64::{prelude::BASE64_STANDARD as base64, Engine};
 use indoc::indoc;
 use itertools::Itertools;
+use predicates::prelude::predicate;
 use url::Url;
 
 use common::{uv_snapshot, TestContext};
@@ -595,63 +600,1223 @@ fn respect_installed_and_reinstall() -> Result<()> {
     Ok(())
 }
 
+/// Reinstall the dependencies of a package, but not the package itself, with
+/// `--force-reinstall-deps`.
+#[test]
+fn reinstall_deps() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==3.7.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    // The requirements are already satisfied, but the dependencies should be reinstalled.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==3.7.0")
+        .arg("--force-reinstall-deps"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Uninstalled 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     - idna==3.6
+     + idna==3.6
+     - sniffio==1.3.1
+     + sniffio==1.3.1
+    "###
+    );
+
+    context.assert_command("import anyio").success();
+}
+
+/// Abort the installation if the resolution exceeds `--resolver-timeout`, reporting the packages
+/// that the resolver was still waiting on.
+#[test]
+fn install_resolver_timeout() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Serve an index that accepts connections, but never responds, such that the resolution can't
+    // complete.
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let address = listener.local_addr()?;
+    std::thread::spawn(move || {
+        let mut connections = Vec::new();
+        for stream in listener.incoming().flatten() {
+            connections.push(stream);
+        }
+    });
+
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==3.7.0")
+        .arg("--index-url")
+        .arg(format!("http://{address}/simple"))
+        .arg("--resolver-timeout")
+        .arg("1"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Resolution did not complete within [TIME] while waiting on metadata for: `anyio` (increase `--resolver-timeout` to allow more time)
+    "###
+    );
+
+    context.assert_command("import anyio").failure();
+
+    Ok(())
+}
+
+/// Suggest the timeout setting that was used when a download times out.
+#[test]
+fn install_read_timeout() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Serve a wheel whose headers are sent immediately, but whose body never arrives.
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let address = listener.local_addr()?;
+    std::thread::spawn(move || {
+        let mut connections = Vec::new();
+        for mut stream in listener.incoming().flatten() {
+            // Read the request, up to the end of the headers.
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                match stream.read(&mut buffer) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => request.extend_from_slice(&buffer[..n]),
+                }
+            }
+            let _ = stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 1024\r\n\r\n");
+            connections.push(stream);
+        }
+    });
+    let requirement = format!("iniconfig @ http://{address}/iniconfig-2.0.0-py3-none-any.whl");
+
+    // The timeout is read from `--read-timeout`.
+    context
+        .pip_install()
+        .arg(&requirement)
+        .arg("--read-timeout")
+        .arg("1000")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Try increasing --read-timeout (current value: 1s).",
+        ));
+
+    // Otherwise, the timeout is read from `UV_HTTP_TIMEOUT`.
+    context
+        .pip_install()
+        .arg(&requirement)
+        .env("UV_HTTP_TIMEOUT", "1")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Try increasing UV_HTTP_TIMEOUT (current value: 1s).",
+        ));
+
+    context.assert_command("import iniconfig").failure();
+
+    Ok(())
+}
+
+/// Exclude a transitive dependency from the resolution with `--exclude`.
+#[test]
+fn install_exclude() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==3.7.0")
+        .arg("--exclude")
+        .arg("idna"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + anyio==3.7.0
+     + sniffio==1.3.1
+    "###
+    );
+
+    context.assert_command("import idna").failure();
+}
+
+/// Discard the extras from the requirements with `--strip-extras`.
+#[test]
+fn install_strip_extras() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("anyio[trio]==3.7.0")
+        .arg("--strip-extras"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    context.assert_command("import trio").failure();
+}
+
+/// Only install the requirements if the `--marker` expression applies to the environment.
+#[test]
+fn install_marker() {
+    let context = TestContext::new("3.12");
+
+    // The marker doesn't apply, so nothing should be installed.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--marker")
+        .arg("python_version < '3.12'")
+        .assert()
+        .success();
+
+    context.assert_command("import iniconfig").failure();
+
+    // The marker applies, so the requirement should be installed.
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--marker")
+        .arg("python_version >= '3.12'"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+}
+
 /// Respect installed versions when resolving.
 #[test]
-fn reinstall_extras() -> Result<()> {
+fn reinstall_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Install httpx.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("httpx")?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    Prepared 7 packages in [TIME]
+    Installed 7 packages in [TIME]
+     + anyio==4.3.0
+     + certifi==2024.2.2
+     + h11==0.14.0
+     + httpcore==1.0.4
+     + httpx==0.27.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    context.assert_command("import httpx").success();
+
+    // Re-install httpx, with an extra.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("httpx[http2]")?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 10 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + h2==4.1.0
+     + hpack==4.0.0
+     + hyperframe==6.0.1
+    "###
+    );
+
+    context.assert_command("import httpx").success();
+
+    Ok(())
+}
+
+/// Reinstall all packages in the resolution with `--force-reinstall`, or only the named packages
+/// when combined with `--no-deps`.
+#[test]
+fn force_reinstall() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    // Reinstall `anyio` and all of its dependencies, even though they're already installed.
+    let context = context.with_filtered_counts();
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--force-reinstall")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Uninstalled [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     - anyio==4.3.0
+     + anyio==4.3.0
+     - idna==3.6
+     + idna==3.6
+     - sniffio==1.3.1
+     + sniffio==1.3.1
+    "###
+    );
+
+    // Reinstall `anyio` alone.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--force-reinstall")
+        .arg("--no-deps")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Uninstalled [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     - anyio==4.3.0
+     + anyio==4.3.0
+    "###
+    );
+
+    context.assert_command("import anyio").success();
+
+    Ok(())
+}
+
+/// pip's `--src` is accepted, but has no effect, since editables are always installed in-place.
+#[test]
+fn install_src_compat() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--src")
+        .arg("src"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: pip's `--src` has no effect (uv only supports editable installs from local directories, which are used in-place).
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+}
+
+/// pip's `--no-warn-script-location` is accepted, but has no effect, since uv doesn't warn about
+/// the script location.
+#[test]
+fn install_no_warn_script_location_compat() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--no-warn-script-location"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: pip's `--no-warn-script-location` has no effect (uv doesn't warn when scripts are installed outside the `PATH`).
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+}
+
+/// pip's `--no-use-pep517` is accepted as an alias for `--legacy-setup-py`, and `--use-pep517` as
+/// an alias for `--no-legacy-setup-py`.
+#[test]
+fn install_use_pep517_compat() {
+    let context = TestContext::new("3.12");
+
+    // Build a source distribution without a `pyproject.toml` using `setuptools` directly.
+    uv_snapshot!(context.pip_install()
+        .arg("flake8 @ https://files.pythonhosted.org/packages/66/53/3ad4a3b74d609b3b9008a10075c40e7c8909eae60af53623c3888f7a529a/flake8-6.0.0.tar.gz")
+        .arg("--no-deps")
+        .arg("--no-use-pep517"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + flake8==6.0.0 (from https://files.pythonhosted.org/packages/66/53/3ad4a3b74d609b3b9008a10075c40e7c8909eae60af53623c3888f7a529a/flake8-6.0.0.tar.gz)
+    "###
+    );
+
+    // `--use-pep517` overrides an earlier `--no-use-pep517`, building with PEP 517 instead.
+    uv_snapshot!(context.pip_install()
+        .arg("flake8 @ https://files.pythonhosted.org/packages/66/53/3ad4a3b74d609b3b9008a10075c40e7c8909eae60af53623c3888f7a529a/flake8-6.0.0.tar.gz")
+        .arg("--no-deps")
+        .arg("--reinstall")
+        .arg("--no-use-pep517")
+        .arg("--use-pep517"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - flake8==6.0.0 (from https://files.pythonhosted.org/packages/66/53/3ad4a3b74d609b3b9008a10075c40e7c8909eae60af53623c3888f7a529a/flake8-6.0.0.tar.gz)
+     + flake8==6.0.0 (from https://files.pythonhosted.org/packages/66/53/3ad4a3b74d609b3b9008a10075c40e7c8909eae60af53623c3888f7a529a/flake8-6.0.0.tar.gz)
+    "###
+    );
+}
+
+/// Write a structured log of the installation with `--log`.
+#[test]
+fn install_log() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==4.2.0")
+        .arg("--log")
+        .arg("install.jsonl"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.2.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    // Upgrading a package should append to the existing log.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--log")
+        .arg("install.jsonl"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - anyio==4.2.0
+     + anyio==4.3.0
+    "###
+    );
+
+    let log = fs_err::read_to_string(context.temp_dir.join("install.jsonl"))?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r#""timestamp":"[^"]+""#, r#""timestamp":"[TIMESTAMP]""#)])
+        .collect::<Vec<_>>();
+    insta::with_settings!({
+        filters => filters,
+    }, {
+        insta::assert_snapshot!(log, @r###"
+        {"action":"installed","name":"anyio","version":"4.2.0","previous_version":null,"filename":"anyio-4.2.0-py3-none-any.whl","timestamp":"[TIMESTAMP]"}
+        {"action":"installed","name":"idna","version":"3.6","previous_version":null,"filename":"idna-3.6-py3-none-any.whl","timestamp":"[TIMESTAMP]"}
+        {"action":"installed","name":"sniffio","version":"1.3.1","previous_version":null,"filename":"sniffio-1.3.1-py3-none-any.whl","timestamp":"[TIMESTAMP]"}
+        {"action":"upgraded","name":"anyio","version":"4.3.0","previous_version":"4.2.0","filename":"anyio-4.3.0-py3-none-any.whl","timestamp":"[TIMESTAMP]"}
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Write the checksums of the installed files with `--generate-checksums`.
+#[test]
+fn install_generate_checksums() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--generate-checksums")
+        .arg("checksums.json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // The checksums are written as a JSON object, keyed by the path within `site-packages`.
+    context
+        .temp_dir
+        .child("checksums.json")
+        .assert(predicates::str::is_match(
+            r#""iniconfig/__init__.py": "[0-9a-f]{64}""#,
+        )?)
+        .assert(predicates::str::contains(
+            "iniconfig-2.0.0.dist-info/METADATA",
+        ));
+    let checksums = fs_err::read_to_string(context.temp_dir.join("checksums.json"))?;
+    assert!(!checksums.contains("RECORD"));
+
+    // With a `.csv` extension, the checksums are written as CSV, including those of packages that
+    // were already installed.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--generate-checksums")
+        .arg("checksums.csv")
+        .assert()
+        .success();
+
+    context
+        .temp_dir
+        .child("checksums.csv")
+        .assert(predicates::str::starts_with("path,sha256\n"))
+        .assert(predicates::str::is_match(
+            r"(?m)^iniconfig/__init__\.py,[0-9a-f]{64}$",
+        )?);
+
+    Ok(())
+}
+
+/// Resolve the build dependencies of a local source tree up front with
+/// `--check-build-dependencies`.
+#[test]
+fn install_check_build_dependencies() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+name = "project"
+version = "0.1.0"
+dependencies = []
+
+[build-system]
+requires = ["build-backend-does-not-exist"]
+build-backend = "setuptools.build_meta"
+"#,
+    )?;
+
+    // The build dependencies can't be resolved, so the installation should fail before building.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(".")
+        .arg("--check-build-dependencies"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: Failed to resolve build dependencies for `project`
+      Caused by: Failed to install requirements from build-system.requires (resolve)
+      Caused by: No solution found when resolving: build-backend-does-not-exist
+      Caused by: Because build-backend-does-not-exist was not found in the package registry and you require build-backend-does-not-exist, we can conclude that your requirements are unsatisfiable.
+    "###
+    );
+
+    // Without build isolation, the build dependencies aren't installed, so they aren't checked.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(".")
+        .arg("--check-build-dependencies")
+        .arg("--no-build-isolation")
+        .arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + project @ file://[TEMP_DIR]/
+    "###
+    );
+
+    Ok(())
+}
+
+/// Ignore installed packages during resolution with `--isolated-resolution`.
+#[test]
+fn install_isolated_resolution() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Install an older version of anyio.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==3.7.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    // By default, the installed version satisfies the requirement.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    // With `--isolated-resolution`, the installed version is ignored.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio")
+        .arg("--isolated-resolution"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - anyio==3.7.0
+     + anyio==4.3.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Install conflicting requirements with `--ignore-conflicts`.
+#[test]
+fn install_ignore_conflicts() {
+    let context = TestContext::new("3.12");
+
+    // `flask==3.0.2` requires `werkzeug>=3.0.0`.
+    uv_snapshot!(context.pip_install()
+        .arg("flask==3.0.2")
+        .arg("werkzeug<3")
+        .arg("--ignore-conflicts"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Ignoring conflicting requirements for `werkzeug`; the environment may be broken
+    Resolved 7 packages in [TIME]
+    Prepared 7 packages in [TIME]
+    Installed 7 packages in [TIME]
+     + blinker==1.7.0
+     + click==8.1.7
+     + flask==3.0.2
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + werkzeug==3.0.1
+    "###
+    );
+}
+
+/// Suppress the conflict warnings from `--ignore-conflicts` with `--no-warn-conflicts`.
+#[test]
+fn install_ignore_conflicts_no_warn_conflicts() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("flask==3.0.2")
+        .arg("werkzeug<3")
+        .arg("--ignore-conflicts")
+        .arg("--no-warn-conflicts"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    Prepared 7 packages in [TIME]
+    Installed 7 packages in [TIME]
+     + blinker==1.7.0
+     + click==8.1.7
+     + flask==3.0.2
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + werkzeug==3.0.1
+    "###
+    );
+}
+
+/// Report the changes to the environment with `--report-diff`.
+#[test]
+fn install_report_diff() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==3.7.0")
+        .arg("--report-diff"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    Changes to the environment: 3 added
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    // Upgrading `anyio` is reported as an upgrade, rather than a removal and an addition.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--report-diff"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - anyio==3.7.0
+     + anyio==4.3.0
+    Changes to the environment: 1 upgraded
+     ~ anyio 3.7.0 -> 4.3.0
+    "###
+    );
+
+    // If the requirements are already satisfied, there are no changes.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==4.3.0")
+        .arg("--report-diff"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    No changes to the environment
+    "###
+    );
+}
+
+/// List the packages that required no changes with `--report-unchanged`.
+#[test]
+fn install_report_unchanged() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==3.7.0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "###
+    );
+
+    // Adding a package should list the existing packages as unchanged.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==3.7.0")
+        .arg("iniconfig")
+        .arg("--report-unchanged"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+     ✓ anyio==3.7.0
+     ✓ idna==3.6
+     ✓ sniffio==1.3.1
+    "###
+    );
+
+    // If the environment is already satisfied, every package should be listed as unchanged.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==3.7.0")
+        .arg("--report-unchanged"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+     ✓ anyio==3.7.0
+     ✓ idna==3.6
+     ✓ sniffio==1.3.1
+    "###
+    );
+}
+
+/// Avoid upgrading installed packages with `--keep-installed`.
+#[test]
+fn install_keep_installed() {
+    let context = TestContext::new("3.12");
+
+    context.pip_install().arg("idna==3.4").assert().success();
+
+    // Missing packages are installed, but the installed packages are left as-is.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio")
+        .arg("--keep-installed"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + anyio==4.3.0
+     + sniffio==1.3.1
+    "###
+    );
+
+    // Requirements that would require an upgrade are rejected.
+    uv_snapshot!(context.pip_install()
+        .arg("idna>=3.5")
+        .arg("--keep-installed"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because you require idna>=3.5 and idna==3.4, we can conclude that the requirements are unsatisfiable.
+    "###
+    );
+
+    // Packages passed to `--upgrade-package` are allowed to change.
+    uv_snapshot!(context.pip_install()
+        .arg("idna>=3.5")
+        .arg("--keep-installed")
+        .arg("--upgrade-package")
+        .arg("idna"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - idna==3.4
+     + idna==3.6
+    "###
+    );
+}
+
+/// List the packages that were already satisfied with `--report-skipped`.
+#[test]
+fn install_report_skipped() {
+    let context = TestContext::new("3.12");
+
+    context.pip_install().arg("anyio==3.7.0").assert().success();
+
+    // Adding a package should list the existing packages as already satisfied.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==3.7.0")
+        .arg("iniconfig")
+        .arg("--report-skipped"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Requirement already satisfied: anyio==3.7.0
+    Requirement already satisfied: idna==3.6
+    Requirement already satisfied: sniffio==1.3.1
+    "###
+    );
+
+    // If the environment is already satisfied, every package should be listed.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==3.7.0")
+        .arg("--report-skipped"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    Requirement already satisfied: anyio==3.7.0
+    Requirement already satisfied: idna==3.6
+    Requirement already satisfied: sniffio==1.3.1
+    "###
+    );
+}
+
+/// Install a package through a local SOCKS5 proxy with `--socks-proxy`.
+#[test]
+fn install_socks_proxy() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Start a SOCKS5 proxy that counts the connections it receives.
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let proxy = format!("socks5h://{}", listener.local_addr()?);
+    let connections = Arc::new(AtomicUsize::new(0));
+    {
+        let connections = connections.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                connections.fetch_add(1, Ordering::SeqCst);
+                std::thread::spawn(move || socks5_relay(stream));
+            }
+        });
+    }
+
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig")
+        .arg("--socks-proxy")
+        .arg(&proxy), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // The requests should have been routed through the proxy.
+    assert!(connections.load(Ordering::SeqCst) > 0);
+
+    // Reject proxies that aren't SOCKS5.
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig")
+        .arg("--socks-proxy")
+        .arg("http://localhost:8080"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'http://localhost:8080' for '--socks-proxy <URL>': expected a `socks5://` or `socks5h://` URL, found scheme: `http`
+
+    For more information, try '--help'.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Serve a single unauthenticated SOCKS5 `CONNECT` request, then relay traffic between the client
+/// and the requested target.
+fn socks5_relay(mut client: TcpStream) -> std::io::Result<()> {
+    // Greeting: the version, followed by the supported authentication methods.
+    let mut greeting = [0u8; 2];
+    client.read_exact(&mut greeting)?;
+    let mut methods = vec![0u8; usize::from(greeting[1])];
+    client.read_exact(&mut methods)?;
+    client.write_all(&[0x05, 0x00])?;
+
+    // Request: the version, command, and address type, followed by the address and port.
+    let mut request = [0u8; 4];
+    client.read_exact(&mut request)?;
+    let host = match request[3] {
+        0x01 => {
+            let mut address = [0u8; 4];
+            client.read_exact(&mut address)?;
+            std::net::Ipv4Addr::from(address).to_string()
+        }
+        0x03 => {
+            let mut len = [0u8; 1];
+            client.read_exact(&mut len)?;
+            let mut domain = vec![0u8; usize::from(len[0])];
+            client.read_exact(&mut domain)?;
+            String::from_utf8_lossy(&domain).to_string()
+        }
+        0x04 => {
+            let mut address = [0u8; 16];
+            client.read_exact(&mut address)?;
+            std::net::Ipv6Addr::from(address).to_string()
+        }
+        _ => return Err(std::io::Error::other("unsupported address type")),
+    };
+    let mut port = [0u8; 2];
+    client.read_exact(&mut port)?;
+    let target = TcpStream::connect((host.as_str(), u16::from_be_bytes(port)))?;
+
+    // Reply with success, bound to `0.0.0.0:0`.
+    client.write_all(&[0x05, 0x00, 0x00, 0x01, 0, 0, 0, 0, 0, 0])?;
+
+    let mut client_reader = client.try_clone()?;
+    let mut target_writer = target.try_clone()?;
+    std::thread::spawn(move || std::io::copy(&mut client_reader, &mut target_writer));
+    let mut target_reader = target;
+    std::io::copy(&mut target_reader, &mut client)?;
+    Ok(())
+}
+
+/// Trust the certificates in the CA bundle passed to `--ssl-ca-bundle`.
+#[test]
+fn install_ssl_ca_bundle() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // A self-signed certificate, which is trusted in addition to the default root certificates.
+    let bundle = context.temp_dir.child("bundle.pem");
+    bundle.write_str(indoc! {"
+        -----BEGIN CERTIFICATE-----
+        MIIBgTCCASegAwIBAgIUR7mT5gA3KsHPmKRNH/lNE0nthUowCgYIKoZIzj0EAwIw
+        FTETMBEGA1UEAwwKdXYtdGVzdC1jYTAgFw0yNjEwMTYxMTQ4NDNaGA8yMTI2MDky
+        MjExNDg0M1owFTETMBEGA1UEAwwKdXYtdGVzdC1jYTBZMBMGByqGSM49AgEGCCqG
+        SM49AwEHA0IABGYBDo471CIbqusN1VzKso88uR3AgZaa7kih5usA3Jq5xV2h0DQB
+        UaJevYTdA2TgHprcMQdmiRIXcspR4F0OXP2jUzBRMB0GA1UdDgQWBBTuwFVjdgvT
+        MhZCwQSZDOpebmu7TTAfBgNVHSMEGDAWgBTuwFVjdgvTMhZCwQSZDOpebmu7TTAP
+        BgNVHRMBAf8EBTADAQH/MAoGCCqGSM49BAMCA0gAMEUCIGz5Q2MZDHmwQ8zMtOcF
+        SSYFtuUYVli6dvcQMfX4zhqEAiEA2CwbvGLer5B2QCJeNMsoyn/UdilEMU59o0w2
+        4MzxUKw=
+        -----END CERTIFICATE-----
+    "})?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig")
+        .arg("--ssl-ca-bundle")
+        .arg("bundle.pem"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Error when the CA bundle passed to `--ssl-ca-bundle` can't be read, or is invalid.
+#[test]
+#[cfg(unix)]
+fn install_ssl_ca_bundle_invalid() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig")
+        .arg("--ssl-ca-bundle")
+        .arg("missing.pem"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to read CA bundle: `missing.pem`
+      Caused by: failed to open file `missing.pem`: No such file or directory (os error 2)
+    "###
+    );
+
+    // The bundle can also be provided via `UV_SSL_CA_BUNDLE`.
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig")
+        .env("UV_SSL_CA_BUNDLE", "missing.pem"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to read CA bundle: `missing.pem`
+      Caused by: failed to open file `missing.pem`: No such file or directory (os error 2)
+    "###
+    );
+
+    // A file without any certificates is rejected, too.
+    let bundle = context.temp_dir.child("bundle.pem");
+    bundle.write_str("not a certificate")?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig")
+        .arg("--ssl-ca-bundle")
+        .arg("bundle.pem"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to read CA bundle: `bundle.pem`
+      Caused by: No certificates found in PEM bundle
+    "###
+    );
+
+    // Nothing should have been installed.
+    context.assert_command("import iniconfig").failure();
+
+    Ok(())
+}
+
+/// Skip requirements that are already installed at the version in the lockfile with
+/// `--skip-locked`.
+#[test]
+fn install_skip_locked() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // Install httpx.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("httpx")?;
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==1.1.1"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv lock` is experimental and may change without warning.
+    Resolved 2 packages in [TIME]
+    "###);
 
     uv_snapshot!(context.pip_install()
-        .arg("-r")
-        .arg("requirements.txt")
-        .arg("--strict"), @r###"
+        .arg("iniconfig==1.1.1"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 7 packages in [TIME]
-    Prepared 7 packages in [TIME]
-    Installed 7 packages in [TIME]
-     + anyio==4.3.0
-     + certifi==2024.2.2
-     + h11==0.14.0
-     + httpcore==1.0.4
-     + httpx==0.27.0
-     + idna==3.6
-     + sniffio==1.3.1
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==1.1.1
     "###
     );
 
-    context.assert_command("import httpx").success();
-
-    // Re-install httpx, with an extra.
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("httpx[http2]")?;
-
+    // `iniconfig` is installed at the locked version, so it should be left as-is, even with
+    // `--upgrade`.
     uv_snapshot!(context.pip_install()
-        .arg("-r")
-        .arg("requirements.txt")
-        .arg("--strict"), @r###"
+        .arg("iniconfig")
+        .arg("typing-extensions")
+        .arg("--upgrade")
+        .arg("--skip-locked"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 10 packages in [TIME]
-    Prepared 3 packages in [TIME]
-    Installed 3 packages in [TIME]
-     + h2==4.1.0
-     + hpack==4.0.0
-     + hyperframe==6.0.1
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + typing-extensions==4.10.0
     "###
     );
 
-    context.assert_command("import httpx").success();
-
     Ok(())
 }
 
@@ -1246,6 +2411,7 @@ fn install_no_index() {
     ----- stdout -----
 
     ----- stderr -----
+    warning: Index lookups are disabled (`--no-index`), but no additional package locations were provided (`--find-links`)
       × No solution found when resolving dependencies:
       ╰─▶ Because flask was not found in the provided package locations and you require flask, we can conclude that the requirements are unsatisfiable.
 
@@ -1270,6 +2436,7 @@ fn install_no_index_version() {
     ----- stdout -----
 
     ----- stderr -----
+    warning: Index lookups are disabled (`--no-index`), but no additional package locations were provided (`--find-links`)
       × No solution found when resolving dependencies:
       ╰─▶ Because flask was not found in the provided package locations and you require flask==3.0.0, we can conclude that the requirements are unsatisfiable.
 
@@ -1329,6 +2496,69 @@ fn install_extra_index_url_has_priority() {
     context.assert_command("import flask").failure();
 }
 
+/// Install a package via `--extra-index-url`.
+///
+/// If the package exists on the "extra" index, but at an incompatible version, the installation
+/// should fail by default (even though a compatible version exists on the "primary" index).
+#[test]
+fn install_index_strategy_first_index() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("--index-url")
+        .arg("https://pypi.org/simple")
+        .arg("--extra-index-url")
+        .arg("https://download.pytorch.org/whl/cpu")
+        .arg("jinja2==3.1.0")
+        .arg("--no-deps"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because there is no version of jinja2==3.1.0 and you require jinja2==3.1.0, we can conclude that the requirements are unsatisfiable.
+    "###
+    );
+
+    context.assert_command("import jinja2").failure();
+}
+
+/// Install a package via `--extra-index-url`.
+///
+/// With `unsafe-best-match`, the highest compatible version should be installed, regardless of
+/// which index it comes from.
+///
+/// In this case, anyio 3.5.0 is hosted on the "extra" index, but newer versions are available on
+/// the "primary" index, from which the distribution should be downloaded.
+#[test]
+fn install_index_strategy_unsafe_best_match() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("--index-strategy")
+        .arg("unsafe-best-match")
+        .arg("--index-url")
+        .arg("https://pypi.org/simple")
+        .arg("--extra-index-url")
+        .arg("https://test.pypi.org/simple")
+        .arg("anyio")
+        .arg("--no-deps"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.3.0
+    "###
+    );
+
+    context.assert_installed("anyio", "4.3.0");
+}
+
 /// Install a package from a public GitHub repository
 #[test]
 #[cfg(feature = "git")]
@@ -2429,6 +3659,86 @@ fn install_constraints_txt() -> Result<()> {
     Ok(())
 }
 
+/// Install a package with a constraint passed on the command line via `--constraint-package`.
+#[test]
+fn install_constraint_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==3.7.0")?;
+
+    uv_snapshot!(context.pip_install()
+            .arg("-r")
+            .arg("requirements.txt")
+            .arg("--constraint-package")
+            .arg("idna<3.4"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.3
+     + sniffio==1.3.1
+    "###
+    );
+
+    Ok(())
+}
+
+/// Install a package with the constraints from a `tool.uv.constraint-groups` entry passed via
+/// `--constraint-group`.
+#[test]
+fn install_constraint_group() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "example"
+        version = "0.0.0"
+
+        [tool.uv.constraint-groups]
+        ci-strict = ["anyio==3.7.0", "idna<3.4"]
+        development = ["anyio>=4"]
+    "#})?;
+
+    uv_snapshot!(context.pip_install()
+            .arg("anyio")
+            .arg("--constraint-group")
+            .arg("ci-strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.3
+     + sniffio==1.3.1
+    "###
+    );
+
+    // Requesting an undefined group is an error.
+    uv_snapshot!(context.pip_install()
+            .arg("anyio")
+            .arg("--constraint-group")
+            .arg("release"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Constraint group `release` is not defined in `tool.uv.constraint-groups`
+    "###
+    );
+
+    Ok(())
+}
+
 /// Install a package from a `requirements.txt` file, with an inline constraint.
 #[test]
 fn install_constraints_inline() -> Result<()> {
@@ -2507,6 +3817,120 @@ fn install_constraints_inline_remote() -> Result<()> {
     Ok(())
 }
 
+/// Reuse a cached copy of a remote `constraints.txt` file, along with the files it includes.
+#[test]
+fn install_constraints_remote_cached() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let (url, requests) = serve_files(vec![
+        ("constraints.txt", "-c nested/pins.txt\n"),
+        ("nested/pins.txt", "iniconfig==1.1.1\n"),
+    ])?;
+    let constraints = url.join("constraints.txt")?;
+
+    uv_snapshot!(context.pip_install()
+            .arg("iniconfig")
+            .arg("-c")
+            .arg(constraints.as_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==1.1.1
+    "###
+    );
+
+    // Both the constraints file and the included file should have been downloaded.
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    // A repeated invocation should reuse the cached files.
+    uv_snapshot!(context.pip_install()
+            .arg("iniconfig")
+            .arg("-c")
+            .arg(constraints.as_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###
+    );
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    // As should an offline invocation.
+    context
+        .pip_install()
+        .arg("iniconfig")
+        .arg("-c")
+        .arg(constraints.as_str())
+        .arg("--offline")
+        .assert()
+        .success();
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    // With `--refresh`, both files should be downloaded again.
+    context
+        .pip_install()
+        .arg("iniconfig")
+        .arg("-c")
+        .arg(constraints.as_str())
+        .arg("--refresh")
+        .assert()
+        .success();
+    assert_eq!(requests.load(Ordering::SeqCst), 4);
+
+    Ok(())
+}
+
+/// Serve the given files over HTTP on a local port, returning the base URL of the server and a
+/// counter of the requests that it receives.
+fn serve_files(files: Vec<(&'static str, &'static str)>) -> Result<(Url, Arc<AtomicUsize>)> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let url = Url::parse(&format!("http://{}/", listener.local_addr()?))?;
+    let requests = Arc::new(AtomicUsize::new(0));
+    {
+        let requests = requests.clone();
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                requests.fetch_add(1, Ordering::SeqCst);
+
+                // Read the request, up to the end of the headers.
+                let mut request = Vec::new();
+                let mut buffer = [0; 1024];
+                while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                    match stream.read(&mut buffer) {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => request.extend_from_slice(&buffer[..n]),
+                    }
+                }
+                let request = String::from_utf8_lossy(&request);
+                let path = request.split_whitespace().nth(1).unwrap_or_default();
+
+                let response = match files
+                    .iter()
+                    .find(|(name, _)| path.strip_prefix('/') == Some(*name))
+                {
+                    Some((_, content)) => format!(
+                        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{content}",
+                        content.len()
+                    ),
+                    None => {
+                        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                            .to_string()
+                    }
+                };
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+    }
+    Ok((url, requests))
+}
+
 /// Constrain a package that's included via an extra.
 #[test]
 fn install_constraints_extra() -> Result<()> {
@@ -2800,13 +4224,44 @@ fn config_settings() {
         .join("__editable___setuptools_editable_0_1_0_finder.py");
     assert!(finder.exists());
 
-    // Install the editable package with `--editable_mode=compat`.
+    // Install the editable package with `--editable_mode=compat`.
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-e")
+        .arg(context.workspace_root.join("scripts/packages/setuptools_editable"))
+        .arg("-C")
+        .arg("editable_mode=compat")
+        , @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + setuptools-editable==0.1.0 (from file://[WORKSPACE]/scripts/packages/setuptools_editable)
+    "###
+    );
+
+    // When installed without `--editable_mode=compat`, the `finder.py` file should _not_ be present.
+    let finder = context
+        .site_packages()
+        .join("__editable___setuptools_editable_0_1_0_finder.py");
+    assert!(!finder.exists());
+}
+
+/// Pass settings to the build backend with `--config-settings`, for compatibility with pip.
+#[test]
+fn config_settings_long_alias() {
     let context = TestContext::new("3.12");
 
     uv_snapshot!(context.filters(), context.pip_install()
         .arg("-e")
         .arg(context.workspace_root.join("scripts/packages/setuptools_editable"))
-        .arg("-C")
+        .arg("--config-settings")
         .arg("editable_mode=compat")
         , @r###"
     success: true
@@ -2822,7 +4277,7 @@ fn config_settings() {
     "###
     );
 
-    // When installed without `--editable_mode=compat`, the `finder.py` file should _not_ be present.
+    // When installed with `--editable_mode=compat`, the `finder.py` file should _not_ be present.
     let finder = context
         .site_packages()
         .join("__editable___setuptools_editable_0_1_0_finder.py");
@@ -3857,6 +5312,60 @@ fn dry_run_install_then_upgrade() -> std::result::Result<(), Box<dyn std::error:
     Ok(())
 }
 
+/// Print the plan of a dry run as JSON.
+#[test]
+fn dry_run_install_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig==1.1.1"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==1.1.1
+    "###
+    );
+
+    // An upgrade reports both the installed and the new version.
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--dry-run")
+        .arg("--format")
+        .arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"op":"upgrade","name":"iniconfig","from_version":"1.1.1","to_version":"2.0.0","wheel_url":"https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl"}]
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // If the requirements are already satisfied, the plan is empty.
+    uv_snapshot!(context.pip_install()
+        .arg("iniconfig==1.1.1")
+        .arg("--dry-run")
+        .arg("--format")
+        .arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    []
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Raise an error when a direct URL's `Requires-Python` constraint is not met.
 #[test]
 fn requires_python_direct_url() -> Result<()> {
@@ -4336,6 +5845,7 @@ fn reinstall_no_index() {
     ----- stdout -----
 
     ----- stderr -----
+    warning: Index lookups are disabled (`--no-index`), but no additional package locations were provided (`--find-links`)
       × No solution found when resolving dependencies:
       ╰─▶ Because anyio was not found in the provided package locations and you require anyio, we can conclude that the requirements are unsatisfiable.
 
@@ -4671,6 +6181,7 @@ fn already_installed_local_version_of_remote_package() {
     ----- stdout -----
 
     ----- stderr -----
+    warning: Index lookups are disabled (`--no-index`), but no additional package locations were provided (`--find-links`)
       × No solution found when resolving dependencies:
       ╰─▶ Because anyio was not found in the provided package locations and you require anyio==4.2.0, we can conclude that the requirements are unsatisfiable.
 
@@ -4932,6 +6443,7 @@ fn already_installed_remote_url() {
     ----- stdout -----
 
     ----- stderr -----
+    warning: Index lookups are disabled (`--no-index`), but no additional package locations were provided (`--find-links`)
       × No solution found when resolving dependencies:
       ╰─▶ Because uv-public-pypackage was not found in the provided package locations and you require uv-public-pypackage, we can conclude that the requirements are unsatisfiable.
 
@@ -4978,6 +6490,7 @@ fn already_installed_remote_url() {
     ----- stdout -----
 
     ----- stderr -----
+    warning: Index lookups are disabled (`--no-index`), but no additional package locations were provided (`--find-links`)
       × No solution found when resolving dependencies:
       ╰─▶ Because uv-public-pypackage was not found in the provided package locations and you require uv-public-pypackage==0.2.0, we can conclude that the requirements are unsatisfiable.
 
@@ -5045,6 +6558,142 @@ fn find_links_no_binary() -> Result<()> {
     Ok(())
 }
 
+/// Prefer an older version with a wheel over a newer source distribution with
+/// `--prefer-binary`.
+#[test]
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+fn find_links_prefer_binary() {
+    let context = TestContext::new("3.12");
+
+    // Without `--prefer-binary`, the newest version is selected, even though it's a source
+    // distribution.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm<1000")
+        .arg("--dry-run")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links/")), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + tqdm==999.0.0
+    "###
+    );
+
+    // With `--prefer-binary`, the newest version with a compatible wheel is selected instead.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm<1000")
+        .arg("--prefer-binary")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links/")), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==4.66.1
+    "###
+    );
+}
+
+/// Override a marker variable with `--marker-env`.
+#[test]
+fn install_marker_env() {
+    let context = TestContext::new("3.12");
+
+    // The requirement only applies to Windows, but markers are evaluated as if on Windows.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0 ; sys_platform == 'win32'")
+        .arg("--marker-env")
+        .arg("sys_platform=win32")
+        .arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // Unknown marker variables are rejected.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--marker-env")
+        .arg("sys_platfrom=win32")
+        .arg("--dry-run"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unknown marker variable: `sys_platfrom`
+    "###
+    );
+
+    // Overriding marker variables requires `--dry-run`, since the resolution may not be
+    // installable into the environment.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0 ; sys_platform == 'win32'")
+        .arg("--marker-env")
+        .arg("sys_platform=win32"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --dry-run
+
+    Usage: uv pip install --cache-dir [CACHE_DIR] --marker-env <KEY=VALUE> --exclude-newer <EXCLUDE_NEWER> --dry-run <PACKAGE|--requirement <REQUIREMENT>|--editable <EDITABLE>>
+
+    For more information, try '--help'.
+    "###
+    );
+}
+
+/// Build from the source distribution, rather than using the wheel, with `--prefer-sdist`.
+#[test]
+fn install_prefer_sdist() {
+    let context = TestContext::new("3.12");
+
+    // `iniconfig==2.0.0` publishes both a wheel and a source distribution.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--prefer-sdist"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    // The source distribution was built.
+    context
+        .cache_dir
+        .child("built-wheels-v3")
+        .child("pypi")
+        .child("iniconfig")
+        .assert(predicates::path::is_dir());
+}
+
 /// Provide valid hashes for all dependencies with `--require-hashes`.
 #[test]
 fn require_hashes() -> Result<()> {
@@ -5298,6 +6947,15 @@ fn require_hashes_unnamed() -> Result<()> {
     "###
     );
 
+    // The verified hash should be recorded in the `direct_url.json`, per PEP 610.
+    let direct_url = fs_err::read_to_string(
+        context
+            .site_packages()
+            .join("anyio-4.0.0.dist-info")
+            .join("direct_url.json"),
+    )?;
+    insta::assert_snapshot!(direct_url, @r###"{"url":"https://files.pythonhosted.org/packages/36/55/ad4de788d84a630656ece71059665e01ca793c04294c463fd84132f40fe6/anyio-4.0.0-py3-none-any.whl","archive_info":{"hash":"sha256=cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f","hashes":{"sha256":"cfdb2b588b9fc25ede96d8db56ed50848b0b649dca3dd1df0b11f683bb9e0b5f"}}}"###);
+
     Ok(())
 }
 
@@ -5570,6 +7228,128 @@ fn recursive_extra_transitive_url() -> Result<()> {
     Ok(())
 }
 
+/// Install a local project with all of its extras via `--all-extras`.
+#[test]
+fn install_local_project_all_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.0.0"
+        dependencies = []
+
+        [project.optional-dependencies]
+        async = ["sniffio"]
+        test = ["iniconfig"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(".")
+        .arg("--all-extras"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.0.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+    "###);
+
+    Ok(())
+}
+
+/// Install a local project with a specific extra via `--extra`, without the `.[extra]` syntax.
+#[test]
+fn install_local_project_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.0.0"
+        dependencies = []
+
+        [project.optional-dependencies]
+        async = ["sniffio"]
+        test = ["iniconfig"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(".")
+        .arg("--extra")
+        .arg("test"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.0.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    Ok(())
+}
+
+/// Install an editable local project in a subdirectory with a specific extra via `--extra`.
+#[test]
+fn install_local_editable_project_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("lib").child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.0.0"
+        dependencies = []
+
+        [project.optional-dependencies]
+        async = ["sniffio"]
+        test = ["iniconfig"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-e")
+        .arg("./lib")
+        .arg("--extra")
+        .arg("test"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.0.0 (from file://[TEMP_DIR]/lib)
+    "###);
+
+    // Requirements that already specify extras are left as-is, so `--extra` has nothing to apply to.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./lib[async]")
+        .arg("--extra")
+        .arg("test"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Requesting extras requires a `pyproject.toml`, `setup.cfg`, or `setup.py` file, or a path to a local project.
+    "###);
+
+    Ok(())
+}
+
 /// If a package is requested as both editable and non-editable, always install it as editable.
 #[test]
 fn prefer_editable() -> Result<()> {
@@ -5887,3 +7667,24 @@ fn local_index_fallback() -> Result<()> {
 
     Ok(())
 }
+
+/// Reject a `--platform` tag that has no equivalent target triple.
+#[test]
+fn install_unsupported_platform_tag() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.pip_install()
+        .arg("anyio")
+        .arg("--platform")
+        .arg("manylinux1_i686"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'manylinux1_i686' for '--platform <PLATFORM>': unsupported platform tag: `manylinux1_i686`
+
+    For more information, try '--help'.
+    "###
+    );
+}
@@ -468,3 +468,50 @@ fn show_required_by_multiple() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn show_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0")?;
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    // Paths are escaped in JSON on Windows.
+    if cfg!(unix) {
+        uv_snapshot!(context.filters(), show_command(&context)
+            .arg("requests")
+            .arg("idna")
+            .arg("--json"), @r###"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        [{"name":"idna","version":"3.6","location":"[SITE_PACKAGES]/","requires":[],"required_by":["requests"]},{"name":"requests","version":"2.31.0","location":"[SITE_PACKAGES]/","requires":["certifi","charset-normalizer","idna","urllib3"],"required_by":[]}]
+
+        ----- stderr -----
+        "###
+        );
+    }
+
+    Ok(())
+}
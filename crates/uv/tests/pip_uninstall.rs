@@ -502,3 +502,50 @@ Version: 0.22.0
 
     Ok(())
 }
+
+/// Uninstall packages with `--yes`, which skips the confirmation prompt.
+#[test]
+fn uninstall_yes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.pip_uninstall()
+        .arg("MarkupSafe")
+        .arg("--yes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - markupsafe==2.1.3
+    "###
+    );
+
+    // `-y` is accepted as a short alias.
+    uv_snapshot!(context.pip_uninstall()
+        .arg("tomli")
+        .arg("-y"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - tomli==2.0.1
+    "###
+    );
+
+    context.assert_command("import markupsafe").failure();
+    context.assert_command("import tomli").failure();
+
+    Ok(())
+}
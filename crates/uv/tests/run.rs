@@ -3,6 +3,7 @@
 use anyhow::Result;
 use assert_fs::prelude::*;
 use indoc::indoc;
+use predicates::prelude::predicate;
 
 use common::{uv_snapshot, TestContext};
 
@@ -102,6 +103,7 @@ fn run_with_python_version() -> Result<()> {
     3.6.0
 
     ----- stderr -----
+    warning: The requested Python version (`3.11`) differs from that of the project environment (3.12.[X])
     Using Python 3.11.[X] interpreter at: [PYTHON-3.11]
     Removed virtual environment at: .venv
     Creating virtualenv at: .venv
@@ -131,6 +133,7 @@ fn run_with_python_version() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
+    warning: The requested Python version (`3.8`) differs from that of the project environment (3.11.[X])
     Using Python 3.8.[X] interpreter at: [PYTHON-3.8]
     error: The requested Python interpreter (3.8.[X]) is incompatible with the project Python requirement: `>=3.11, <4`
     "###);
@@ -315,3 +318,249 @@ fn run_managed_false() -> Result<()> {
 
     Ok(())
 }
+
+/// Set environment variables for the command with `--env`.
+#[test]
+fn run_env() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = []
+
+        [tool.uv]
+        managed = false
+        "#
+    })?;
+
+    // `--env` takes precedence over the parent environment.
+    uv_snapshot!(context.filters(), context.run()
+        .env("GREETING", "hello")
+        .arg("--env")
+        .arg("GREETING=howdy")
+        .arg("--env")
+        .arg("NAME=world")
+        .arg("python")
+        .arg("-c")
+        .arg("import os; print(os.environ['GREETING'], os.environ['NAME'])"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    howdy world
+
+    ----- stderr -----
+    warning: `uv run` is experimental and may change without warning.
+    "###);
+
+    // Reject malformed assignments.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--env")
+        .arg("GREETING")
+        .arg("python"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'GREETING' for '--env <ENV>': expected `KEY=VALUE`, found: `GREETING`
+
+    For more information, try '--help'.
+    "###);
+
+    Ok(())
+}
+
+/// Run the command with a minimal environment via `--clean-env`.
+#[test]
+fn run_clean_env() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = []
+
+        [tool.uv]
+        managed = false
+        "#
+    })?;
+
+    // By default, the parent environment is inherited.
+    uv_snapshot!(context.filters(), context.run()
+        .env("GREETING", "hello")
+        .arg("python")
+        .arg("-c")
+        .arg("import os; print(os.environ.get('GREETING'))"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+
+    ----- stderr -----
+    warning: `uv run` is experimental and may change without warning.
+    "###);
+
+    // With `--clean-env`, only the minimal environment (and `--env`) is passed through.
+    uv_snapshot!(context.filters(), context.run()
+        .env("GREETING", "hello")
+        .arg("--clean-env")
+        .arg("--env")
+        .arg("NAME=world")
+        .arg("python")
+        .arg("-c")
+        .arg("import os; print(os.environ.get('GREETING'), os.environ['NAME'], 'PATH' in os.environ)"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    None world True
+
+    ----- stderr -----
+    warning: `uv run` is experimental and may change without warning.
+    "###);
+
+    // `--inherit-env` overrides `--clean-env`.
+    uv_snapshot!(context.filters(), context.run()
+        .env("GREETING", "hello")
+        .arg("--clean-env")
+        .arg("--inherit-env")
+        .arg("python")
+        .arg("-c")
+        .arg("import os; print(os.environ.get('GREETING'))"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+
+    ----- stderr -----
+    warning: `uv run` is experimental and may change without warning.
+    "###);
+
+    Ok(())
+}
+
+/// Run a command without discovering the project via `--no-project`.
+#[test]
+fn run_no_project() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = ["anyio"]
+        "#
+    })?;
+
+    // The project is neither locked nor synced.
+    uv_snapshot!(context.filters(), context.run().arg("--no-project").arg("python").arg("--version"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Python 3.12.[X]
+
+    ----- stderr -----
+    warning: `uv run` is experimental and may change without warning.
+    "###);
+
+    context
+        .temp_dir
+        .child("uv.lock")
+        .assert(predicate::path::missing());
+
+    // Without an active virtual environment, the project's `.venv` isn't used.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("python")
+        .arg("-c")
+        .arg("import sys; print(sys.prefix == sys.base_prefix)")
+        .env_remove("VIRTUAL_ENV"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    True
+
+    ----- stderr -----
+    warning: `uv run` is experimental and may change without warning.
+    "###);
+
+    // With an active virtual environment, it's used instead.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("python")
+        .arg("-c")
+        .arg("import sys; print(sys.prefix == sys.base_prefix)"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    False
+
+    ----- stderr -----
+    warning: `uv run` is experimental and may change without warning.
+    "###);
+
+    Ok(())
+}
+
+/// List the scripts defined by the project with `--list`.
+#[test]
+fn run_list() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = []
+
+        [project.scripts]
+        foo = "foo.cli:main"
+        foo-server = "foo.server:run"
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("--list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    foo        foo.cli:main
+    foo-server foo.server:run
+
+    ----- stderr -----
+    warning: `uv run` is experimental and may change without warning.
+    "###);
+
+    // Without `--list`, a command is required.
+    uv_snapshot!(context.filters(), context.run(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv run` is experimental and may change without warning.
+    error: A command is required (use `uv run --list` to see the project's scripts)
+    "###);
+
+    // With `--list`, a command is rejected, rather than ignored.
+    uv_snapshot!(context.filters(), context.run().arg("--list").arg("foo"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv run` is experimental and may change without warning.
+    error: `--list` cannot be used with a command
+    "###);
+
+    Ok(())
+}
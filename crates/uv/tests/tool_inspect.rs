@@ -0,0 +1,96 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::PathChild;
+use common::{uv_snapshot, TestContext};
+
+mod common;
+
+#[test]
+fn tool_inspect() {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_inspect().arg("black")
+    .env("UV_TOOL_DIR", tool_dir.as_os_str())
+    .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black
+    Python: [TEMP_DIR]/tools/black/bin/python (3.12.[X])
+    Packages:
+     - black==24.2.0
+     - click==8.1.7
+     - mypy-extensions==1.0.0
+     - packaging==24.0
+     - pathspec==0.12.1
+     - platformdirs==4.2.0
+    Entry points:
+     - black ([TEMP_DIR]/bin/black)
+     - blackd ([TEMP_DIR]/bin/blackd)
+    Install command: uv tool install black==24.2.0
+
+    ----- stderr -----
+    warning: `uv tool inspect` is experimental and may change without warning.
+    "###);
+}
+
+#[test]
+fn tool_inspect_json() {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black` with an additional requirement.
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .arg("--with")
+        .arg("iniconfig>=2")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_inspect().arg("black").arg("--json")
+    .env("UV_TOOL_DIR", tool_dir.as_os_str())
+    .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"name":"black","python":{"request":null,"executable":"[TEMP_DIR]/tools/black/bin/python","version":"3.12.[X]"},"packages":[{"name":"black","version":"24.2.0"},{"name":"click","version":"8.1.7"},{"name":"iniconfig","version":"2.0.0"},{"name":"mypy-extensions","version":"1.0.0"},{"name":"packaging","version":"24.0"},{"name":"pathspec","version":"0.12.1"},{"name":"platformdirs","version":"4.2.0"}],"entrypoints":[{"name":"black","path":"[TEMP_DIR]/bin/black"},{"name":"blackd","path":"[TEMP_DIR]/bin/blackd"}],"command":"uv tool install black==24.2.0 --with 'iniconfig>=2'"}
+
+    ----- stderr -----
+    warning: `uv tool inspect` is experimental and may change without warning.
+    "###);
+}
+
+#[test]
+fn tool_inspect_missing() {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    uv_snapshot!(context.filters(), context.tool_inspect().arg("black")
+    .env("UV_TOOL_DIR", tool_dir.as_os_str())
+    .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool inspect` is experimental and may change without warning.
+    error: Tool `black` is not installed
+    "###);
+}
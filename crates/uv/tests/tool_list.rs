@@ -34,6 +34,62 @@ fn tool_list() {
     "###);
 }
 
+#[test]
+fn tool_list_versions() {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_list().arg("--versions")
+    .env("UV_TOOL_DIR", tool_dir.as_os_str())
+    .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black v24.2.0
+
+    ----- stderr -----
+    warning: `uv tool list` is experimental and may change without warning.
+    "###);
+}
+
+#[test]
+fn tool_list_json() {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_list().arg("--json")
+    .env("UV_TOOL_DIR", tool_dir.as_os_str())
+    .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"name":"black","version":"24.2.0","entrypoints":["[TEMP_DIR]/bin/black","[TEMP_DIR]/bin/blackd"]}]
+
+    ----- stderr -----
+    warning: `uv tool list` is experimental and may change without warning.
+    "###);
+}
+
 #[test]
 fn tool_list_empty() {
     let context = TestContext::new("3.12");
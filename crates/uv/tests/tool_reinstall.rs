@@ -0,0 +1,136 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::PathChild;
+use common::{uv_snapshot, TestContext};
+use insta::assert_snapshot;
+
+mod common;
+
+/// Reinstall a tool with `uv tool reinstall`, retaining the installed versions even if newer
+/// versions are available.
+#[test]
+fn tool_reinstall() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black` before the release of `black==24.3.0`.
+    context
+        .tool_install_without_exclude_newer()
+        .arg("black")
+        .arg("--exclude-newer")
+        .arg("2024-03-01T00:00:00Z")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Every package should be reinstalled at its current version.
+    uv_snapshot!(context.filters(), context.tool_reinstall().arg("black")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool reinstall` is experimental and may change without warning.
+    Resolved 6 packages in [TIME]
+    Prepared 6 packages in [TIME]
+    Uninstalled 6 packages in [TIME]
+    Installed 6 packages in [TIME]
+     - black==24.2.0
+     + black==24.2.0
+     - click==8.1.7
+     + click==8.1.7
+     - mypy-extensions==1.0.0
+     + mypy-extensions==1.0.0
+     - packaging==23.2
+     + packaging==23.2
+     - pathspec==0.12.1
+     + pathspec==0.12.1
+     - platformdirs==4.2.0
+     + platformdirs==4.2.0
+    Reinstalled `black` v24.2.0
+    "###);
+
+    // The receipt should be unchanged.
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        [tool]
+        requirements = ["black"]
+        entrypoints = [
+            { name = "black", install-path = "[TEMP_DIR]/bin/black" },
+            { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
+        ]
+        "###);
+    });
+}
+
+/// Reinstall a tool installed with `--entrypoint-link-mode`, retaining the link mode.
+#[test]
+fn tool_reinstall_entrypoint_link_mode() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    context
+        .tool_install()
+        .arg("black")
+        .arg("--entrypoint-link-mode")
+        .arg("hardlink")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    context
+        .tool_reinstall()
+        .arg("black")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // The entry point should still be a regular file, rather than a symlink.
+    let executable = bin_dir.child(format!("black{}", std::env::consts::EXE_SUFFIX));
+    assert!(!fs_err::symlink_metadata(&executable).unwrap().is_symlink());
+
+    // The receipt should retain the link mode.
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        [tool]
+        requirements = ["black"]
+        entrypoint-link-mode = "hardlink"
+        entrypoints = [
+            { name = "black", install-path = "[TEMP_DIR]/bin/black" },
+            { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
+        ]
+        "###);
+    });
+}
+
+/// Attempt to reinstall a tool that isn't installed.
+#[test]
+fn tool_reinstall_not_installed() {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    uv_snapshot!(context.filters(), context.tool_reinstall().arg("black")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool reinstall` is experimental and may change without warning.
+    error: Tool `black` is not installed
+    "###);
+}
@@ -1,5 +1,8 @@
 #![cfg(all(feature = "python", feature = "pypi"))]
 
+use anyhow::Result;
+use assert_fs::prelude::*;
+
 use common::{uv_snapshot, TestContext};
 
 mod common;
@@ -151,3 +154,37 @@ fn tool_run_from_version() {
      + pytest==8.0.0
     "###);
 }
+
+#[test]
+fn tool_run_with_requirements() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.3.0")?;
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--with-requirements")
+        .arg("requirements.txt")
+        .arg("pytest")
+        .arg("--version"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.1.1
+
+    ----- stderr -----
+    warning: `uv tool run` is experimental and may change without warning.
+    Resolved 7 packages in [TIME]
+    Prepared 7 packages in [TIME]
+    Installed 7 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.1.1
+     + sniffio==1.3.1
+    "###);
+
+    Ok(())
+}
@@ -1,8 +1,10 @@
 #![cfg(all(feature = "python", feature = "pypi"))]
 
 use assert_cmd::assert::OutputAssertExt;
+use assert_fs::assert::PathAssert;
 use assert_fs::fixture::PathChild;
 use common::{uv_snapshot, TestContext};
+use predicates::prelude::predicate;
 
 mod common;
 
@@ -69,6 +71,65 @@ fn tool_uninstall() {
     "###);
 }
 
+#[test]
+fn tool_uninstall_all() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black` and `flask`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .assert()
+        .success();
+    context
+        .tool_install()
+        .arg("flask")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_uninstall().arg("--all")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool uninstall` is experimental and may change without warning.
+    Uninstalled: black, blackd
+    Uninstalled: flask
+    "###);
+
+    // The environments and entrypoints should be removed.
+    tool_dir.child("black").assert(predicate::path::missing());
+    tool_dir.child("flask").assert(predicate::path::missing());
+    bin_dir
+        .child(format!("black{}", std::env::consts::EXE_SUFFIX))
+        .assert(predicate::path::missing());
+    bin_dir
+        .child(format!("flask{}", std::env::consts::EXE_SUFFIX))
+        .assert(predicate::path::missing());
+
+    // Uninstalling all tools again should be a no-op.
+    uv_snapshot!(context.filters(), context.tool_uninstall().arg("--all")
+        .env("UV_TOOL_DIR", tool_dir.as_os_str())
+        .env("XDG_BIN_HOME", bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv tool uninstall` is experimental and may change without warning.
+    No tools installed
+    "###);
+}
+
 #[test]
 fn tool_uninstall_not_installed() {
     let context = TestContext::new("3.12").with_filtered_exe_suffix();
@@ -0,0 +1,167 @@
+#![cfg(all(feature = "python", feature = "pypi", unix))]
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+use predicates::prelude::predicate;
+
+use common::{uv_snapshot, TestContext};
+
+mod common;
+
+/// A stub of the `cpython-3.12.3-linux-x86_64-gnu` archive, containing only a `bin/python3`
+/// script and an empty standard library.
+const ARCHIVE: &str = "cpython-3.12.3+20240415-x86_64-unknown-linux-gnu-debug-full.tar.zst";
+
+/// Install a toolchain from a local archive with `--from-file`.
+#[test]
+fn toolchain_install_from_file() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let toolchain_dir = context.temp_dir.child("toolchains");
+    let archive = context.temp_dir.child(ARCHIVE);
+    archive.write_file(
+        &context
+            .workspace_root
+            .join("scripts")
+            .join("toolchains")
+            .join(ARCHIVE),
+    )?;
+
+    uv_snapshot!(context.filters(), context.toolchain_install()
+        .arg("--preview")
+        .arg("--from-file")
+        .arg(ARCHIVE)
+        .env("UV_TOOLCHAIN_DIR", toolchain_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installing cpython-3.12.3-linux-x86_64-gnu from cpython-3.12.3+20240415-x86_64-unknown-linux-gnu-debug-full.tar.zst
+    Installed Python 3.12.3 to toolchains/cpython-3.12.3-linux-x86_64-gnu
+    Installed 1 toolchain in [TIME]
+    "###);
+
+    let installed = toolchain_dir.child("cpython-3.12.3-linux-x86_64-gnu");
+    installed
+        .child("install")
+        .child("bin")
+        .child("python3")
+        .assert(predicate::path::is_file());
+    installed
+        .child("install")
+        .child("lib")
+        .child("python3.12")
+        .child("EXTERNALLY-MANAGED")
+        .assert(predicate::path::is_file());
+
+    // Installing the same toolchain again is a no-op.
+    let marker = installed.child("marker");
+    marker.touch()?;
+
+    uv_snapshot!(context.filters(), context.toolchain_install()
+        .arg("--preview")
+        .arg("--from-file")
+        .arg(ARCHIVE)
+        .env("UV_TOOLCHAIN_DIR", toolchain_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installing cpython-3.12.3-linux-x86_64-gnu from cpython-3.12.3+20240415-x86_64-unknown-linux-gnu-debug-full.tar.zst
+    Requested toolchain already installed.
+    "###);
+
+    marker.assert(predicate::path::is_file());
+
+    // With `--force`, the existing installation is replaced.
+    uv_snapshot!(context.filters(), context.toolchain_install()
+        .arg("--preview")
+        .arg("--from-file")
+        .arg(ARCHIVE)
+        .arg("--force")
+        .env("UV_TOOLCHAIN_DIR", toolchain_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installing cpython-3.12.3-linux-x86_64-gnu from cpython-3.12.3+20240415-x86_64-unknown-linux-gnu-debug-full.tar.zst
+    Installed Python 3.12.3 to toolchains/cpython-3.12.3-linux-x86_64-gnu
+    Installed 1 toolchain in [TIME]
+    "###);
+
+    marker.assert(predicate::path::missing());
+    installed
+        .child("install")
+        .child("bin")
+        .child("python3")
+        .assert(predicate::path::is_file());
+
+    Ok(())
+}
+
+/// Reject archives that don't match the download manifest.
+#[test]
+fn toolchain_install_from_file_invalid() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let toolchain_dir = context.temp_dir.child("toolchains");
+
+    // The filename doesn't match any of the available downloads.
+    let unknown = context
+        .temp_dir
+        .child("cpython-3.12.3+20240415-x86_64-unknown-linux-gnu-install_only.tar.gz");
+    unknown.touch()?;
+
+    uv_snapshot!(context.filters(), context.toolchain_install()
+        .arg("--preview")
+        .arg("--from-file")
+        .arg("cpython-3.12.3+20240415-x86_64-unknown-linux-gnu-install_only.tar.gz")
+        .env("UV_TOOLCHAIN_DIR", toolchain_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `cpython-3.12.3+20240415-x86_64-unknown-linux-gnu-install_only.tar.gz` is not a known toolchain archive (the filename must match that of one of the available downloads)
+    "###);
+
+    // The filename matches, but the contents don't match the embedded checksum.
+    let archive = context.temp_dir.child(ARCHIVE);
+    archive.write_file(
+        &context
+            .workspace_root
+            .join("scripts")
+            .join("toolchains")
+            .join(ARCHIVE),
+    )?;
+
+    uv_snapshot!(context.filters(), context.toolchain_install()
+        .arg("--preview")
+        .arg("--from-file")
+        .arg(ARCHIVE)
+        .arg("--verify")
+        .env("UV_TOOLCHAIN_DIR", toolchain_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Installing cpython-3.12.3-linux-x86_64-gnu from cpython-3.12.3+20240415-x86_64-unknown-linux-gnu-debug-full.tar.zst
+    error: Hash mismatch for `cpython-3.12.3-linux-x86_64-gnu`
+
+    Expected:
+      ded92cd034b33df953c490d3343ef187ac065d1fcd78e8cee894be197c5f977e
+
+    Computed:
+      f0c344984ff8699091ed8c9622d15ab5ea23325d193263b41e14b38b0b1ef300
+    "###);
+
+    toolchain_dir
+        .child("cpython-3.12.3-linux-x86_64-gnu")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
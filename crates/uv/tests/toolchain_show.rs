@@ -0,0 +1,88 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use common::{uv_snapshot, TestContext};
+
+mod common;
+
+/// Show the toolchain selected by the project's `requires-python`.
+#[test]
+fn toolchain_show() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+
+    // The virtual environment uses Python 3.11, so it's skipped in favor of Python 3.12.
+    uv_snapshot!(context.filters(), context.toolchain_show(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Path: [PYTHON-3.12]
+    Version: 3.12.[X]
+    Managed: no
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PYTHON-3.12]
+    "###);
+
+    Ok(())
+}
+
+/// Show the toolchain selected by the `.python-version` file.
+#[test]
+fn toolchain_show_python_version_file() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = []
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child(".python-version")
+        .write_str("3.12")?;
+
+    uv_snapshot!(context.filters(), context.toolchain_show(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Path: [PYTHON-3.12]
+    Version: 3.12.[X]
+    Managed: no
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PYTHON-3.12]
+    "###);
+
+    // Display the toolchain as JSON.
+    if cfg!(unix) {
+        uv_snapshot!(context.filters(), context.toolchain_show().arg("--json"), @r###"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        {"path":"[PYTHON-3.12]","version":"3.12.[X]","managed":false}
+
+        ----- stderr -----
+        Using Python 3.12.[X] interpreter at: [PYTHON-3.12]
+        "###);
+    }
+
+    Ok(())
+}
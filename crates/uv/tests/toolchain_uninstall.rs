@@ -0,0 +1,83 @@
+#![cfg(all(feature = "python", feature = "pypi"))]
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+use predicates::prelude::predicate;
+
+use common::{uv_snapshot, TestContext};
+
+mod common;
+
+#[test]
+fn toolchain_uninstall_all_empty() {
+    let context = TestContext::new("3.12");
+
+    let toolchain_dir = context.temp_dir.child("toolchains");
+    uv_snapshot!(context.filters(), context.toolchain_uninstall()
+    .arg("--all")
+    .env("UV_TOOLCHAIN_DIR", toolchain_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv toolchain uninstall` is experimental and may change without warning.
+    No toolchains to uninstall
+    "###);
+}
+
+/// Uninstall the toolchains matching a request, then the remaining toolchains with `--all`.
+#[test]
+fn toolchain_uninstall() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a pair of (stub) managed toolchains.
+    let toolchain_dir = context.temp_dir.child("toolchains");
+    let cpython_312 = toolchain_dir.child("cpython-3.12.3-linux-x86_64-gnu");
+    cpython_312
+        .child("install")
+        .child("bin")
+        .child("python3")
+        .write_str("#!/bin/sh\n")?;
+    let cpython_311 = toolchain_dir.child("cpython-3.11.9-linux-x86_64-gnu");
+    cpython_311
+        .child("install")
+        .child("bin")
+        .child("python3")
+        .write_str("#!/bin/sh\n")?;
+
+    uv_snapshot!(context.filters(), context.toolchain_uninstall()
+    .arg("3.12")
+    .arg("3.10")
+    .env("UV_TOOLCHAIN_DIR", toolchain_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv toolchain uninstall` is experimental and may change without warning.
+    warning: No installed toolchain found matching `Python 3.10`
+    Uninstalled cpython-3.12.3-linux-x86_64-gnu
+    Uninstalled 1 toolchain in [TIME] (freed [SIZE])
+    "###);
+
+    cpython_312.assert(predicate::path::missing());
+    cpython_311.assert(predicate::path::is_dir());
+
+    uv_snapshot!(context.filters(), context.toolchain_uninstall()
+    .arg("--all")
+    .env("UV_TOOLCHAIN_DIR", toolchain_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv toolchain uninstall` is experimental and may change without warning.
+    Uninstalled cpython-3.11.9-linux-x86_64-gnu
+    Uninstalled 1 toolchain in [TIME] (freed [SIZE])
+    "###);
+
+    cpython_311.assert(predicate::path::missing());
+
+    Ok(())
+}
@@ -69,6 +69,27 @@ fn create_venv_defaults_to_cwd() {
     context.venv.assert(predicates::path::is_dir());
 }
 
+/// Print the path to the interpreter that would be used, without creating the environment.
+#[test]
+fn venv_python_path() {
+    let context = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--python")
+        .arg("3.12")
+        .arg("--python-path"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    "###
+    );
+
+    context.venv.assert(predicates::path::missing());
+}
+
 #[test]
 fn create_venv_ignores_virtual_env_variable() {
     let context = TestContext::new_with_versions(&["3.12"]);
@@ -560,6 +581,26 @@ fn virtualenv_compatibility() {
     );
 
     context.venv.assert(predicates::path::is_dir());
+
+    // Pass the redundant `--without-pip` flag from `python -m venv`.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--without-pip")
+        .arg("--python")
+        .arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: venv's `--without-pip` has no effect (uv omits `pip` by default).
+    Using Python 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtualenv at: .venv
+    Activate with: source .venv/bin/activate
+    "###
+    );
+
+    context.venv.assert(predicates::path::is_dir());
 }
 
 #[test]
@@ -630,6 +671,94 @@ fn verify_nested_pyvenv_cfg() -> Result<()> {
     Ok(())
 }
 
+/// Create a relocatable virtual environment, which should not refer to its own location.
+#[test]
+#[cfg(unix)]
+fn create_venv_relocatable() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    context
+        .venv()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .arg("--relocatable")
+        .assert()
+        .success();
+
+    let pyvenv_cfg = context.venv.child("pyvenv.cfg");
+    pyvenv_cfg.assert(predicates::str::contains("relocatable = true"));
+
+    // The `activate` script should compute the location of the environment from its own path.
+    let location = context.venv.canonicalize()?;
+    let activate = fs_err::read_to_string(context.venv.child("bin").child("activate"))?;
+    assert!(!activate.contains(location.to_str().unwrap()));
+
+    // Moving the environment should preserve the activated location.
+    let moved = context.temp_dir.child("moved");
+    fs_err::rename(&context.venv, &moved)?;
+    let output = std::process::Command::new("bash")
+        .arg("-c")
+        .arg("source bin/activate && echo \"$VIRTUAL_ENV\"")
+        .current_dir(&moved)
+        .output()?;
+    let activated = String::from_utf8(output.stdout)?;
+    assert_eq!(
+        std::path::Path::new(activated.trim()).canonicalize()?,
+        moved.canonicalize()?
+    );
+
+    Ok(())
+}
+
+/// Scripts installed into a relocatable virtual environment should continue to work after the
+/// environment is moved.
+#[test]
+#[cfg(unix)]
+fn create_venv_relocatable_scripts() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+    let project_root = fs_err::canonicalize(std::env::current_dir()?.join("../.."))?;
+
+    context
+        .venv()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .arg("--relocatable")
+        .assert()
+        .success();
+
+    context
+        .pip_install()
+        .arg(format!(
+            "simple_launcher@{}",
+            project_root
+                .join("scripts/links/simple_launcher-0.1.0-py3-none-any.whl")
+                .display()
+        ))
+        .assert()
+        .success();
+
+    // The script should not refer to the environment's original location.
+    let location = context.venv.canonicalize()?;
+    let script = fs_err::read_to_string(context.venv.child("bin").child("simple_launcher"))?;
+    assert!(!script.contains(location.to_str().unwrap()));
+
+    // Moving the environment should preserve the script's interpreter.
+    let moved = context.temp_dir.child("moved");
+    fs_err::rename(&context.venv, &moved)?;
+    uv_snapshot!(std::process::Command::new(moved.child("bin").child("simple_launcher").path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hi from the simple launcher!
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
 /// See <https://github.com/astral-sh/uv/issues/3280>
 #[test]
 #[cfg(windows)]
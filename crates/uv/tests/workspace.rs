@@ -4,6 +4,9 @@ use std::process::Command;
 
 use anyhow::Result;
 use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use insta::assert_snapshot;
 
 use crate::common::{copy_dir_ignore, uv_snapshot, TestContext};
 
@@ -565,3 +568,401 @@ fn workspace_lock_idempotence_virtual_workspace() -> Result<()> {
     )?;
     Ok(())
 }
+
+/// Print information about a workspace with `uv workspace info`.
+#[test]
+fn workspace_info() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let work_dir = context.temp_dir.join("albatross-root-workspace");
+
+    copy_dir_ignore(workspaces_dir().join("albatross-root-workspace"), &work_dir)?;
+
+    uv_snapshot!(context.filters(), context
+        .workspace_info()
+        .arg("--preview")
+        .current_dir(work_dir.join("packages").join("seeds")), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Workspace root: [TEMP_DIR]/albatross-root-workspace
+    Members:
+      albatross v0.1.0 (.)
+      bird-feeder v1.0.0 (packages/bird-feeder)
+      seeds v1.0.0 (packages/seeds)
+    Requires-Python: >=3.12
+    Lockfile: [TEMP_DIR]/albatross-root-workspace/uv.lock
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context
+        .workspace_info()
+        .arg("--preview")
+        .arg("--json")
+        .current_dir(&work_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"root":"[TEMP_DIR]/albatross-root-workspace","members":[{"name":"albatross","version":"0.1.0","path":"."},{"name":"bird-feeder","version":"1.0.0","path":"packages/bird-feeder"},{"name":"seeds","version":"1.0.0","path":"packages/seeds"}],"requires_python":">=3.12","lockfile":"[TEMP_DIR]/albatross-root-workspace/uv.lock"}
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// Print information about a workspace in which a member has a version that isn't PEP 440-compliant.
+#[test]
+fn workspace_info_invalid_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "unreleased"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context
+        .workspace_info()
+        .arg("--preview"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Workspace root: [TEMP_DIR]/
+    Members:
+      project vunreleased (.)
+    Requires-Python: >=3.12
+    Lockfile: [TEMP_DIR]/uv.lock
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// Display the dependencies between workspace members, as text and in the DOT format.
+#[test]
+fn workspace_graph() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let work_dir = context.temp_dir.join("albatross-root-workspace");
+
+    copy_dir_ignore(workspaces_dir().join("albatross-root-workspace"), &work_dir)?;
+
+    uv_snapshot!(context.filters(), context
+        .workspace_graph()
+        .arg("--preview")
+        .current_dir(&work_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    albatross
+    └── bird-feeder
+    bird-feeder
+    └── seeds
+    seeds
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context
+        .workspace_graph()
+        .arg("--preview")
+        .arg("--output-format")
+        .arg("dot")
+        .current_dir(&work_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    digraph workspace {
+        "albatross";
+        "bird-feeder";
+        "seeds";
+        "albatross" -> "bird-feeder";
+        "bird-feeder" -> "seeds";
+    }
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// Display the order in which the workspace members would be published.
+#[test]
+fn workspace_publish_dry_run() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let work_dir = context.temp_dir.join("albatross-root-workspace");
+
+    copy_dir_ignore(workspaces_dir().join("albatross-root-workspace"), &work_dir)?;
+
+    uv_snapshot!(context.filters(), context
+        .workspace_publish()
+        .arg("--preview")
+        .arg("--dry-run")
+        .current_dir(&work_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    seeds==1.0.0
+    bird-feeder==1.0.0
+    albatross==0.1.0
+
+    ----- stderr -----
+    Would publish 3 packages in order:
+    "###
+    );
+
+    // Uploading isn't supported yet.
+    uv_snapshot!(context.filters(), context
+        .workspace_publish()
+        .arg("--preview")
+        .current_dir(&work_dir), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Uploading to a package index is not yet supported; use `--dry-run` to display the publication order
+    "###
+    );
+
+    Ok(())
+}
+
+/// Skip the workspace members that are marked as private when publishing.
+#[test]
+fn workspace_publish_private() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let work_dir = context.temp_dir.join("albatross-root-workspace");
+
+    copy_dir_ignore(workspaces_dir().join("albatross-root-workspace"), &work_dir)?;
+
+    // Mark the workspace root as private.
+    fs_err::write(
+        work_dir.join("pyproject.toml"),
+        indoc! {r#"
+            [project]
+            name = "albatross"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = ["bird-feeder", "tqdm>=4,<5"]
+            classifiers = ["Private :: Do Not Upload"]
+
+            [tool.uv.sources]
+            bird-feeder = { workspace = true }
+
+            [tool.uv.workspace]
+            members = ["packages/*"]
+
+            [build-system]
+            requires = ["hatchling"]
+            build-backend = "hatchling.build"
+        "#},
+    )?;
+
+    uv_snapshot!(context.filters(), context
+        .workspace_publish()
+        .arg("--preview")
+        .arg("--dry-run")
+        .current_dir(&work_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    seeds==1.0.0
+    bird-feeder==1.0.0
+
+    ----- stderr -----
+    Skipping 1 package marked as private (`Private :: Do Not Upload`): albatross
+    Would publish 2 packages in order:
+    "###
+    );
+
+    Ok(())
+}
+
+/// Remove generated artifacts from the workspace members with `uv workspace clean`.
+#[test]
+fn workspace_clean() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["child"]
+    "#})?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    // Create some artifacts, alongside the virtual environment created by the test context.
+    child
+        .child("dist")
+        .child("child-0.1.0.tar.gz")
+        .write_str("")?;
+    child
+        .child("src")
+        .child("child.egg-info")
+        .child("PKG-INFO")
+        .write_str("")?;
+    child
+        .child("src")
+        .child("child")
+        .child("__pycache__")
+        .child("__init__.cpython-312.pyc")
+        .write_str("")?;
+    child
+        .child("src")
+        .child("child")
+        .child("__init__.py")
+        .write_str("")?;
+
+    // With `--keep-venv`, the virtual environment is preserved.
+    uv_snapshot!(context.filters(), context
+        .workspace_clean()
+        .arg("--preview")
+        .arg("--keep-venv")
+        .current_dir(&context.temp_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed `child/dist`
+    Removed `child/src/child/__pycache__`
+    Removed `child/src/child.egg-info`
+    Removed 3 directories (freed [SIZE])
+    "###
+    );
+
+    child
+        .child("src")
+        .child("child")
+        .child("__init__.py")
+        .assert(predicates::path::is_file());
+    context.venv.assert(predicates::path::is_dir());
+
+    // Otherwise, the virtual environment is removed too.
+    uv_snapshot!(context.filters(), context
+        .workspace_clean()
+        .arg("--preview")
+        .current_dir(&context.temp_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed `.venv`
+    Removed 1 directory (freed [SIZE])
+    "###
+    );
+
+    context.venv.assert(predicates::path::missing());
+
+    // Once clean, there's nothing left to remove.
+    uv_snapshot!(context.filters(), context
+        .workspace_clean()
+        .arg("--preview")
+        .current_dir(&context.temp_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No artifacts to remove
+    "###
+    );
+
+    Ok(())
+}
+
+/// Add a new project to the workspace members with `uv workspace member add`.
+#[test]
+fn workspace_member_add() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context
+        .workspace_member_add()
+        .arg("child")
+        .arg("--preview")
+        .current_dir(&context.temp_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `child` at `child`
+    Added `child` to the workspace members
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    assert_snapshot!(fs_err::read_to_string(pyproject_toml.path())?, @r###"
+    [project]
+    name = "root"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = []
+
+    [tool.uv.workspace]
+    members = [
+        "child",
+    ]
+    "###
+    );
+
+    context
+        .temp_dir
+        .child("child")
+        .child("pyproject.toml")
+        .assert(predicates::path::is_file());
+    context
+        .temp_dir
+        .child("uv.lock")
+        .assert(predicates::path::is_file());
+
+    // Adding the project again is a no-op.
+    uv_snapshot!(context.filters(), context
+        .workspace_member_add()
+        .arg("child")
+        .arg("--preview")
+        .current_dir(&context.temp_dir), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Project `child` is already a member of the workspace
+    "###
+    );
+
+    Ok(())
+}